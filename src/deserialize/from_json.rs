@@ -5,11 +5,13 @@ use araucaria::{
 use serde::de::DeserializeOwned;
 
 use crate::{
-    serialize::{SchemaErrLocale, to_schema_err_locale},
-    validate::validate,
+    localize::{self, LocaleFormat, LocalizeCtx},
+    rule::Rules,
+    serialize::{SchemaErrLocale, to_schema_err, to_schema_err_locale},
+    validate::{ValidateCtx, validate, validate_with_rules},
 };
 
-use super::value_from_json_and_schema;
+use super::{normalize_json, normalize_json_temporal, value_from_json_and_schema};
 
 pub fn deserialize_from_json<T>(json: serde_json::Value, schema: &Schema, locale: &Locale) -> Result<T, SchemaErrLocale>
 where
@@ -19,24 +21,80 @@ where
     match validate(schema, &internal_value) {
         Ok(()) => Ok(serde_json::from_value(json).unwrap()),
         Err(schema_err) => {
-            let schema_err_locale = localize_schema_err(&schema_err, &locale);
+            let schema_err_locale = localize_schema_err(&schema_err, locale);
             let schema_err_locale_serializable = to_schema_err_locale(schema_err_locale);
             Err(schema_err_locale_serializable)
         }
     }
 }
 
+pub fn deserialize_from_json_lenient<T>(
+    mut json: serde_json::Value,
+    schema: &Schema,
+    locale: &Locale,
+    format: &LocaleFormat,
+) -> Result<T, SchemaErrLocale>
+where
+    T: DeserializeOwned,
+{
+    normalize_json_temporal(&mut json, schema, format);
+    deserialize_from_json(json, schema, locale)
+}
+
+pub fn deserialize_from_json_with_ctx<T>(json: serde_json::Value, schema: &Schema, ctx: &LocalizeCtx) -> Result<T, SchemaErrLocale>
+where
+    T: DeserializeOwned,
+{
+    let internal_value = value_from_json_and_schema(&json, schema);
+    match validate(schema, &internal_value) {
+        Ok(()) => Ok(serde_json::from_value(json).unwrap()),
+        Err(schema_err) => Err(localize::localize_schema_err(&to_schema_err(schema_err), ctx)),
+    }
+}
+
+pub fn deserialize_from_json_with_rules<T>(
+    mut json: serde_json::Value,
+    schema: &Schema,
+    rules: &Rules,
+    validate_ctx: &ValidateCtx,
+    localize_ctx: &LocalizeCtx,
+) -> Result<T, SchemaErrLocale>
+where
+    T: DeserializeOwned,
+{
+    if let Some(format) = validate_ctx.lenient {
+        normalize_json_temporal(&mut json, schema, format);
+    }
+    let internal_value = value_from_json_and_schema(&json, schema);
+    match validate_with_rules(schema, rules, &internal_value, validate_ctx) {
+        Ok(()) => {
+            normalize_json(&mut json, rules);
+            Ok(serde_json::from_value(json).unwrap())
+        }
+        Err(schema_err) => Err(localize::localize_schema_err(&schema_err, localize_ctx)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::BTreeMap, sync::LazyLock};
 
-    use araucaria::schema::{BoolSchema, ObjSchema, Schema, StrSchema, U64Schema};
+    use araucaria::schema::{BoolSchema, DateSchema, ObjSchema, Schema, StrSchema, TimeSchema, U64Schema};
     use serde::Deserialize;
     use serde_json::json;
 
-    use crate::{locale::locale_pt_long, serialize::SchemaErrLocale};
+    use araucaria::locale::Locale;
+
+    use crate::{
+        locale::{locale_en_long, locale_pt_long},
+        localize::{FieldLabels, LocalizeCtx, locale_format_en},
+        plugin_locale::{plugin_locale_en_long, plugin_locale_pt_long},
+        rule::{PatternRule, Rule, Rules},
+        serialize::SchemaErrLocale,
+        validate::ValidateCtx,
+    };
 
-    use super::deserialize_from_json;
+    use super::{deserialize_from_json, deserialize_from_json_lenient, deserialize_from_json_with_ctx, deserialize_from_json_with_rules};
 
     #[derive(Debug, PartialEq, Deserialize)]
     struct User {
@@ -79,4 +137,117 @@ mod tests {
             )])))
         );
     }
+
+    #[test]
+    fn deserialize_struct_with_ctx() {
+        let locale = Locale { required: "%field% é obrigatório".into(), ..locale_pt_long() };
+        let labels = FieldLabels::from([("score".into(), "Pontuação".into())]);
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&locale, &plugin).labels(&labels);
+        assert_eq!(
+            deserialize_from_json_with_ctx(json!({ "name": "John Lennon", "score": 92 }), &USER_SCHEMA, &ctx),
+            Ok(User { name: "John Lennon".into(), score: 92, is_active: None })
+        );
+        assert_eq!(
+            deserialize_from_json_with_ctx::<User>(json!({ "name": "John Lennon", "is_active": true }), &USER_SCHEMA, &ctx),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([(
+                "score".into(),
+                SchemaErrLocale::Validation(vec!["Pontuação é obrigatório".into(), "Deve ser um número inteiro sem sinal de 64 bits".into()])
+            )])))
+        );
+    }
+
+    #[test]
+    fn deserialize_struct_with_rules() {
+        let locale = locale_pt_long();
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&locale, &plugin);
+        let rules = Rules::default().add("name", Rule::Pattern(PatternRule::new("[A-Z][a-z]+ [A-Z][a-z]+").unwrap()));
+        assert_eq!(
+            deserialize_from_json_with_rules(json!({ "name": "John Lennon", "score": 92 }), &USER_SCHEMA, &rules, &ValidateCtx::new(), &ctx),
+            Ok(User { name: "John Lennon".into(), score: 92, is_active: None })
+        );
+        assert_eq!(
+            deserialize_from_json_with_rules::<User>(json!({ "name": "john lennon", "score": 92 }), &USER_SCHEMA, &rules, &ValidateCtx::new(), &ctx),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([(
+                "name".into(),
+                SchemaErrLocale::Validation(vec!["Deve corresponder ao padrão [A-Z][a-z]+ [A-Z][a-z]+".into()])
+            )])))
+        );
+    }
+
+    #[test]
+    fn deserialize_struct_with_rules_normalized() {
+        let locale = locale_pt_long();
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&locale, &plugin);
+        let rules = Rules::default().add("name", Rule::Cpf);
+        assert_eq!(
+            deserialize_from_json_with_rules(json!({ "name": "529.982.247-25", "score": 92 }), &USER_SCHEMA, &rules, &ValidateCtx::new(), &ctx),
+            Ok(User { name: "52998224725".into(), score: 92, is_active: None })
+        );
+        assert_eq!(
+            deserialize_from_json_with_rules::<User>(
+                json!({ "name": "529.982.247-24", "score": 92 }),
+                &USER_SCHEMA,
+                &rules,
+                &ValidateCtx::new(),
+                &ctx
+            ),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([("name".into(), SchemaErrLocale::Validation(vec!["Deve ser um CPF válido".into()]))])))
+        );
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Booking {
+        checkin: String,
+        opens_at: String,
+    }
+
+    #[test]
+    fn deserialize_struct_lenient() {
+        let schema = Schema::from(ObjSchema::from([
+            ("checkin".into(), Schema::from(DateSchema::default())),
+            ("opens_at".into(), Schema::from(TimeSchema::default())),
+        ]));
+        let locale = locale_en_long();
+        let format = locale_format_en();
+        assert_eq!(
+            deserialize_from_json_lenient(json!({ "checkin": "10/17/2026", "opens_at": "9:30 PM" }), &schema, &locale, &format),
+            Ok(Booking { checkin: "2026-10-17".into(), opens_at: "21:30".into() })
+        );
+        assert_eq!(
+            deserialize_from_json_lenient::<Booking>(json!({ "checkin": "17/10/2026", "opens_at": "9:30" }), &schema, &locale, &format),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([
+                ("checkin".into(), SchemaErrLocale::Validation(vec![locale.date.clone()])),
+                ("opens_at".into(), SchemaErrLocale::Validation(vec![locale.time.clone()])),
+            ])))
+        );
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&locale, &plugin).format(&format);
+        assert_eq!(
+            deserialize_from_json_with_rules(
+                json!({ "checkin": "10/17/2026", "opens_at": "9:30 PM" }),
+                &schema,
+                &Rules::default(),
+                &ValidateCtx::new().lenient(&format),
+                &ctx
+            ),
+            Ok(Booking { checkin: "2026-10-17".into(), opens_at: "21:30".into() })
+        );
+        assert_eq!(
+            deserialize_from_json_with_rules::<Booking>(
+                json!({ "checkin": "10/17/2026", "opens_at": "21:30" }),
+                &schema,
+                &Rules::default(),
+                &ValidateCtx::new(),
+                &ctx
+            ),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([("checkin".into(), SchemaErrLocale::Validation(vec![locale.date.clone()]))])))
+        );
+        assert_eq!(
+            deserialize_from_json_with_ctx::<Booking>(json!({ "checkin": "10/17/2026", "opens_at": "21:30" }), &schema, &ctx),
+            Err(SchemaErrLocale::Obj(BTreeMap::from([("checkin".into(), SchemaErrLocale::Validation(vec![locale.date.clone()]))])))
+        );
+    }
 }
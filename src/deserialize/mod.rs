@@ -1,7 +1,9 @@
-pub use from_json::deserialize_from_json;
+pub use from_json::{deserialize_from_json, deserialize_from_json_lenient, deserialize_from_json_with_ctx, deserialize_from_json_with_rules};
+pub use normalize_json::{normalize_json, normalize_json_temporal};
 pub use value_from_json::value_from_json;
 pub use value_from_json_and_schema::value_from_json_and_schema;
 
 mod from_json;
+mod normalize_json;
 mod value_from_json;
 mod value_from_json_and_schema;
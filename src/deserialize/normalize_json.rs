@@ -0,0 +1,143 @@
+use araucaria::schema::Schema;
+
+use crate::{
+    localize::{LocaleFormat, normalize_local_date, normalize_local_date_time, normalize_local_time},
+    rule::Rules,
+};
+
+fn json_at_mut<'a>(json: &'a mut serde_json::Value, path: &str) -> Option<&'a mut serde_json::Value> {
+    if path.is_empty() {
+        return Some(json);
+    }
+    path.split('.').try_fold(json, |current, segment| match current {
+        serde_json::Value::Object(obj) => obj.get_mut(segment),
+        serde_json::Value::Array(arr) => arr.get_mut(segment.parse::<usize>().ok()?),
+        _ => None,
+    })
+}
+
+pub fn normalize_json(json: &mut serde_json::Value, rules: &Rules) {
+    for (path, path_rules) in &rules.0 {
+        let Some(serde_json::Value::String(value)) = json_at_mut(json, path) else { continue };
+        if let Some(normalized) = path_rules.iter().find_map(|rule| rule.normalize(value)) {
+            *value = normalized;
+        }
+    }
+}
+
+/// Arrays are left as typed: the schema has no array variant, so their items have no temporal schema to normalize against.
+pub fn normalize_json_temporal(json: &mut serde_json::Value, schema: &Schema, format: &LocaleFormat) {
+    match (schema, json) {
+        (Schema::Date(_), serde_json::Value::String(value)) => {
+            if let Some(normalized) = normalize_local_date(value, format) {
+                *value = normalized;
+            }
+        }
+        (Schema::Time(_), serde_json::Value::String(value)) => {
+            if let Some(normalized) = normalize_local_time(value, format) {
+                *value = normalized;
+            }
+        }
+        (Schema::DateTime(_), serde_json::Value::String(value)) => {
+            if let Some(normalized) = normalize_local_date_time(value, format) {
+                *value = normalized;
+            }
+        }
+        (Schema::Obj(schema), serde_json::Value::Object(obj)) => {
+            for (key, item_schema) in &schema.validation {
+                if let Some(item) = obj.get_mut(key) {
+                    normalize_json_temporal(item, item_schema, format);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::schema::{DateSchema, DateTimeSchema, ObjSchema, Schema, StrSchema, TimeSchema};
+    use serde_json::json;
+
+    use crate::{
+        localize::{locale_format_en, locale_format_pt},
+        rule::{Rule, Rules},
+    };
+
+    use super::{normalize_json, normalize_json_temporal};
+
+    #[test]
+    fn normalize_json_paths() {
+        let rules = Rules::default().add("cpf", Rule::Cpf).add("company.cnpj", Rule::Cnpj).add("addresses.1.cep", Rule::Cep);
+        let mut json = json!({
+            "cpf": "529.982.247-25",
+            "company": { "cnpj": "12.abc.345/01de-35" },
+            "addresses": [{ "cep": "01310-100" }, { "cep": "20040-020" }]
+        });
+        normalize_json(&mut json, &rules);
+        assert_eq!(
+            json,
+            json!({
+                "cpf": "52998224725",
+                "company": { "cnpj": "12ABC34501DE35" },
+                "addresses": [{ "cep": "01310-100" }, { "cep": "20040020" }]
+            })
+        );
+    }
+
+    #[test]
+    fn normalize_json_unchanged() {
+        let rules = Rules::default().add("cpf", Rule::Cpf).add("cep", Rule::Cep).add("missing.cep", Rule::Cep);
+        let mut json = json!({ "cpf": "529.982.247-24", "cep": 1310100 });
+        normalize_json(&mut json, &rules);
+        assert_eq!(json, json!({ "cpf": "529.982.247-24", "cep": 1310100 }));
+    }
+
+    #[test]
+    fn normalize_json_temporal_locale() {
+        let schema = Schema::from(ObjSchema::from([
+            ("birthdate".into(), Schema::from(DateSchema::default())),
+            ("opens_at".into(), Schema::from(TimeSchema::default())),
+            ("name".into(), Schema::from(StrSchema::default())),
+            ("booking".into(), Schema::from(ObjSchema::from([("checkin".into(), Schema::from(DateSchema::default()))]))),
+        ]));
+        let mut json = json!({
+            "birthdate": "17/10/2026",
+            "opens_at": "8:30",
+            "name": "17/10/2026",
+            "booking": { "checkin": "1/2/2027" }
+        });
+        normalize_json_temporal(&mut json, &schema, &locale_format_pt());
+        assert_eq!(
+            json,
+            json!({
+                "birthdate": "2026-10-17",
+                "opens_at": "08:30",
+                "name": "17/10/2026",
+                "booking": { "checkin": "2027-02-01" }
+            })
+        );
+        let mut json = json!({ "birthdate": "17/10/2026", "opens_at": "8:30", "booking": { "checkin": "1/2/2027" } });
+        normalize_json_temporal(&mut json, &schema, &locale_format_en());
+        assert_eq!(json, json!({ "birthdate": "17/10/2026", "opens_at": "8:30", "booking": { "checkin": "2027-01-02" } }));
+    }
+
+    #[test]
+    fn normalize_json_temporal_date_time() {
+        let schema = Schema::from(ObjSchema::from([
+            ("starts_at".into(), Schema::from(DateTimeSchema::default())),
+            ("ends_at".into(), Schema::from(DateTimeSchema::default())),
+        ]));
+        let mut json = json!({ "starts_at": "17/10/2026 21:30 -03:00", "ends_at": "17/10/2026 23:00" });
+        normalize_json_temporal(&mut json, &schema, &locale_format_pt());
+        assert_eq!(json, json!({ "starts_at": "2026-10-17T21:30-03:00", "ends_at": "17/10/2026 23:00" }));
+    }
+
+    #[test]
+    fn normalize_json_temporal_arr() {
+        let schema = Schema::from(ObjSchema::from([("dates".into(), Schema::from(DateSchema::default()))]));
+        let mut json = json!({ "dates": ["17/10/2026", "18/10/2026"] });
+        normalize_json_temporal(&mut json, &schema, &locale_format_pt());
+        assert_eq!(json, json!({ "dates": ["17/10/2026", "18/10/2026"] }));
+    }
+}
@@ -1,5 +1,11 @@
 pub mod deserialize;
 pub mod locale;
+pub mod locale_check;
+pub mod locale_file;
+pub mod locale_registry;
+pub mod localize;
+pub mod plugin_locale;
+pub mod rule;
 pub mod serialize;
 mod utils;
 pub mod validate;
@@ -1,4 +1,7 @@
+use std::sync::LazyLock;
+
 use araucaria::locale::Locale;
+use regex::Regex;
 
 pub fn locale_pt_long() -> Locale {
     Locale {
@@ -240,6 +243,608 @@ pub fn locale_en_long() -> Locale {
     }
 }
 
+pub fn locale_fr_long() -> Locale {
+    Locale {
+        required: "Est obligatoire".into(),
+        u64: "Doit être un entier non signé de 64 bits".into(),
+        i64: "Doit être un entier de 64 bits".into(),
+        f64: "Doit être un nombre à virgule flottante de 64 bits".into(),
+        usize: "Doit être un entier non signé".into(),
+        isize: "Doit être un entier".into(),
+        bool: "Doit être un booléen".into(),
+        str: "Doit être une chaîne de caractères".into(),
+        email: "Doit être un e-mail".into(),
+        date: "Doit être une date".into(),
+        time: "Doit être une heure".into(),
+        date_time: "Doit être une date et une heure".into(),
+        eq: "Doit être égal à %value%".into(),
+        ne: "Doit être différent de %value%".into(),
+        gt: "Doit être supérieur à %value%".into(),
+        ge: "Doit être supérieur ou égal à %value%".into(),
+        lt: "Doit être inférieur à %value%".into(),
+        le: "Doit être inférieur ou égal à %value%".into(),
+        btwn: "Doit être compris entre %value_a% et %value_b%".into(),
+        eq_field: "Doit être égal au champ %value%".into(),
+        ne_field: "Doit être différent du champ %value%".into(),
+        gt_field: "Doit être supérieur au champ %value%".into(),
+        ge_field: "Doit être supérieur ou égal au champ %value%".into(),
+        lt_field: "Doit être inférieur au champ %value%".into(),
+        le_field: "Doit être inférieur ou égal au champ %value%".into(),
+        bytes_len_eq: "Le nombre d'octets doit être égal à %value%".into(),
+        bytes_len_ne: "Le nombre d'octets doit être différent de %value%".into(),
+        bytes_len_gt: "Le nombre d'octets doit être supérieur à %value%".into(),
+        bytes_len_ge: "Le nombre d'octets doit être supérieur ou égal à %value%".into(),
+        bytes_len_lt: "Le nombre d'octets doit être inférieur à %value%".into(),
+        bytes_len_le: "Le nombre d'octets doit être inférieur ou égal à %value%".into(),
+        bytes_len_btwn: "Le nombre d'octets doit être compris entre %value_a% et %value_b%".into(),
+        chars_len_eq: "Le nombre de caractères doit être égal à %value%".into(),
+        chars_len_ne: "Le nombre de caractères doit être différent de %value%".into(),
+        chars_len_gt: "Le nombre de caractères doit être supérieur à %value%".into(),
+        chars_len_ge: "Le nombre de caractères doit être supérieur ou égal à %value%".into(),
+        chars_len_lt: "Le nombre de caractères doit être inférieur à %value%".into(),
+        chars_len_le: "Le nombre de caractères doit être inférieur ou égal à %value%".into(),
+        chars_len_btwn: "Le nombre de caractères doit être compris entre %value_a% et %value_b%".into(),
+        graphemes_len_eq: "Le nombre de graphèmes doit être égal à %value%".into(),
+        graphemes_len_ne: "Le nombre de graphèmes doit être différent de %value%".into(),
+        graphemes_len_gt: "Le nombre de graphèmes doit être supérieur à %value%".into(),
+        graphemes_len_ge: "Le nombre de graphèmes doit être supérieur ou égal à %value%".into(),
+        graphemes_len_lt: "Le nombre de graphèmes doit être inférieur à %value%".into(),
+        graphemes_len_le: "Le nombre de graphèmes doit être inférieur ou égal à %value%".into(),
+        graphemes_len_btwn: "Le nombre de graphèmes doit être compris entre %value_a% et %value_b%".into(),
+        lowercase_len_eq: "Le nombre de caractères minuscules doit être égal à %value%".into(),
+        lowercase_len_ne: "Le nombre de caractères minuscules doit être différent de %value%".into(),
+        lowercase_len_gt: "Le nombre de caractères minuscules doit être supérieur à %value%".into(),
+        lowercase_len_ge: "Le nombre de caractères minuscules doit être supérieur ou égal à %value%".into(),
+        lowercase_len_lt: "Le nombre de caractères minuscules doit être inférieur à %value%".into(),
+        lowercase_len_le: "Le nombre de caractères minuscules doit être inférieur ou égal à %value%".into(),
+        lowercase_len_btwn: "Le nombre de caractères minuscules doit être compris entre %value_a% et %value_b%".into(),
+        uppercase_len_eq: "Le nombre de caractères majuscules doit être égal à %value%".into(),
+        uppercase_len_ne: "Le nombre de caractères majuscules doit être différent de %value%".into(),
+        uppercase_len_gt: "Le nombre de caractères majuscules doit être supérieur à %value%".into(),
+        uppercase_len_ge: "Le nombre de caractères majuscules doit être supérieur ou égal à %value%".into(),
+        uppercase_len_lt: "Le nombre de caractères majuscules doit être inférieur à %value%".into(),
+        uppercase_len_le: "Le nombre de caractères majuscules doit être inférieur ou égal à %value%".into(),
+        uppercase_len_btwn: "Le nombre de caractères majuscules doit être compris entre %value_a% et %value_b%".into(),
+        number_len_eq: "Le nombre de chiffres doit être égal à %value%".into(),
+        number_len_ne: "Le nombre de chiffres doit être différent de %value%".into(),
+        number_len_gt: "Le nombre de chiffres doit être supérieur à %value%".into(),
+        number_len_ge: "Le nombre de chiffres doit être supérieur ou égal à %value%".into(),
+        number_len_lt: "Le nombre de chiffres doit être inférieur à %value%".into(),
+        number_len_le: "Le nombre de chiffres doit être inférieur ou égal à %value%".into(),
+        number_len_btwn: "Le nombre de chiffres doit être compris entre %value_a% et %value_b%".into(),
+        symbols_eq: "Le nombre de symboles doit être égal à %value%".into(),
+        symbols_ne: "Le nombre de symboles doit être différent de %value%".into(),
+        symbols_gt: "Le nombre de symboles doit être supérieur à %value%".into(),
+        symbols_ge: "Le nombre de symboles doit être supérieur ou égal à %value%".into(),
+        symbols_lt: "Le nombre de symboles doit être inférieur à %value%".into(),
+        symbols_le: "Le nombre de symboles doit être inférieur ou égal à %value%".into(),
+        symbols_btwn: "Le nombre de symboles doit être compris entre %value_a% et %value_b%".into(),
+        enumerated: "Doit être l'une des valeurs %value%".into(),
+    }
+}
+
+pub fn locale_de_long() -> Locale {
+    Locale {
+        required: "Ist erforderlich".into(),
+        u64: "Muss eine vorzeichenlose 64-Bit-Ganzzahl sein".into(),
+        i64: "Muss eine 64-Bit-Ganzzahl sein".into(),
+        f64: "Muss eine 64-Bit-Gleitkommazahl sein".into(),
+        usize: "Muss eine vorzeichenlose Ganzzahl sein".into(),
+        isize: "Muss eine Ganzzahl sein".into(),
+        bool: "Muss ein boolescher Wert sein".into(),
+        str: "Muss eine Zeichenkette sein".into(),
+        email: "Muss eine E-Mail-Adresse sein".into(),
+        date: "Muss ein Datum sein".into(),
+        time: "Muss eine Uhrzeit sein".into(),
+        date_time: "Muss ein Datum mit Uhrzeit sein".into(),
+        eq: "Muss gleich %value% sein".into(),
+        ne: "Muss ungleich %value% sein".into(),
+        gt: "Muss größer als %value% sein".into(),
+        ge: "Muss größer als oder gleich %value% sein".into(),
+        lt: "Muss kleiner als %value% sein".into(),
+        le: "Muss kleiner als oder gleich %value% sein".into(),
+        btwn: "Muss zwischen %value_a% und %value_b% liegen".into(),
+        eq_field: "Muss gleich dem Feld %value% sein".into(),
+        ne_field: "Muss ungleich dem Feld %value% sein".into(),
+        gt_field: "Muss größer als das Feld %value% sein".into(),
+        ge_field: "Muss größer als oder gleich dem Feld %value% sein".into(),
+        lt_field: "Muss kleiner als das Feld %value% sein".into(),
+        le_field: "Muss kleiner als oder gleich dem Feld %value% sein".into(),
+        bytes_len_eq: "Die Anzahl der Bytes muss gleich %value% sein".into(),
+        bytes_len_ne: "Die Anzahl der Bytes muss ungleich %value% sein".into(),
+        bytes_len_gt: "Die Anzahl der Bytes muss größer als %value% sein".into(),
+        bytes_len_ge: "Die Anzahl der Bytes muss größer als oder gleich %value% sein".into(),
+        bytes_len_lt: "Die Anzahl der Bytes muss kleiner als %value% sein".into(),
+        bytes_len_le: "Die Anzahl der Bytes muss kleiner als oder gleich %value% sein".into(),
+        bytes_len_btwn: "Die Anzahl der Bytes muss zwischen %value_a% und %value_b% liegen".into(),
+        chars_len_eq: "Die Anzahl der Zeichen muss gleich %value% sein".into(),
+        chars_len_ne: "Die Anzahl der Zeichen muss ungleich %value% sein".into(),
+        chars_len_gt: "Die Anzahl der Zeichen muss größer als %value% sein".into(),
+        chars_len_ge: "Die Anzahl der Zeichen muss größer als oder gleich %value% sein".into(),
+        chars_len_lt: "Die Anzahl der Zeichen muss kleiner als %value% sein".into(),
+        chars_len_le: "Die Anzahl der Zeichen muss kleiner als oder gleich %value% sein".into(),
+        chars_len_btwn: "Die Anzahl der Zeichen muss zwischen %value_a% und %value_b% liegen".into(),
+        graphemes_len_eq: "Die Anzahl der Grapheme muss gleich %value% sein".into(),
+        graphemes_len_ne: "Die Anzahl der Grapheme muss ungleich %value% sein".into(),
+        graphemes_len_gt: "Die Anzahl der Grapheme muss größer als %value% sein".into(),
+        graphemes_len_ge: "Die Anzahl der Grapheme muss größer als oder gleich %value% sein".into(),
+        graphemes_len_lt: "Die Anzahl der Grapheme muss kleiner als %value% sein".into(),
+        graphemes_len_le: "Die Anzahl der Grapheme muss kleiner als oder gleich %value% sein".into(),
+        graphemes_len_btwn: "Die Anzahl der Grapheme muss zwischen %value_a% und %value_b% liegen".into(),
+        lowercase_len_eq: "Die Anzahl der Kleinbuchstaben muss gleich %value% sein".into(),
+        lowercase_len_ne: "Die Anzahl der Kleinbuchstaben muss ungleich %value% sein".into(),
+        lowercase_len_gt: "Die Anzahl der Kleinbuchstaben muss größer als %value% sein".into(),
+        lowercase_len_ge: "Die Anzahl der Kleinbuchstaben muss größer als oder gleich %value% sein".into(),
+        lowercase_len_lt: "Die Anzahl der Kleinbuchstaben muss kleiner als %value% sein".into(),
+        lowercase_len_le: "Die Anzahl der Kleinbuchstaben muss kleiner als oder gleich %value% sein".into(),
+        lowercase_len_btwn: "Die Anzahl der Kleinbuchstaben muss zwischen %value_a% und %value_b% liegen".into(),
+        uppercase_len_eq: "Die Anzahl der Großbuchstaben muss gleich %value% sein".into(),
+        uppercase_len_ne: "Die Anzahl der Großbuchstaben muss ungleich %value% sein".into(),
+        uppercase_len_gt: "Die Anzahl der Großbuchstaben muss größer als %value% sein".into(),
+        uppercase_len_ge: "Die Anzahl der Großbuchstaben muss größer als oder gleich %value% sein".into(),
+        uppercase_len_lt: "Die Anzahl der Großbuchstaben muss kleiner als %value% sein".into(),
+        uppercase_len_le: "Die Anzahl der Großbuchstaben muss kleiner als oder gleich %value% sein".into(),
+        uppercase_len_btwn: "Die Anzahl der Großbuchstaben muss zwischen %value_a% und %value_b% liegen".into(),
+        number_len_eq: "Die Anzahl der Ziffern muss gleich %value% sein".into(),
+        number_len_ne: "Die Anzahl der Ziffern muss ungleich %value% sein".into(),
+        number_len_gt: "Die Anzahl der Ziffern muss größer als %value% sein".into(),
+        number_len_ge: "Die Anzahl der Ziffern muss größer als oder gleich %value% sein".into(),
+        number_len_lt: "Die Anzahl der Ziffern muss kleiner als %value% sein".into(),
+        number_len_le: "Die Anzahl der Ziffern muss kleiner als oder gleich %value% sein".into(),
+        number_len_btwn: "Die Anzahl der Ziffern muss zwischen %value_a% und %value_b% liegen".into(),
+        symbols_eq: "Die Anzahl der Symbole muss gleich %value% sein".into(),
+        symbols_ne: "Die Anzahl der Symbole muss ungleich %value% sein".into(),
+        symbols_gt: "Die Anzahl der Symbole muss größer als %value% sein".into(),
+        symbols_ge: "Die Anzahl der Symbole muss größer als oder gleich %value% sein".into(),
+        symbols_lt: "Die Anzahl der Symbole muss kleiner als %value% sein".into(),
+        symbols_le: "Die Anzahl der Symbole muss kleiner als oder gleich %value% sein".into(),
+        symbols_btwn: "Die Anzahl der Symbole muss zwischen %value_a% und %value_b% liegen".into(),
+        enumerated: "Muss einer der Werte %value% sein".into(),
+    }
+}
+
+pub fn locale_it_long() -> Locale {
+    Locale {
+        required: "È obbligatorio".into(),
+        u64: "Deve essere un intero senza segno a 64 bit".into(),
+        i64: "Deve essere un intero a 64 bit".into(),
+        f64: "Deve essere un numero in virgola mobile a 64 bit".into(),
+        usize: "Deve essere un intero senza segno".into(),
+        isize: "Deve essere un intero".into(),
+        bool: "Deve essere un booleano".into(),
+        str: "Deve essere una stringa".into(),
+        email: "Deve essere un'e-mail".into(),
+        date: "Deve essere una data".into(),
+        time: "Deve essere un orario".into(),
+        date_time: "Deve essere una data e un orario".into(),
+        eq: "Deve essere uguale a %value%".into(),
+        ne: "Deve essere diverso da %value%".into(),
+        gt: "Deve essere maggiore di %value%".into(),
+        ge: "Deve essere maggiore o uguale a %value%".into(),
+        lt: "Deve essere minore di %value%".into(),
+        le: "Deve essere minore o uguale a %value%".into(),
+        btwn: "Deve essere compreso tra %value_a% e %value_b%".into(),
+        eq_field: "Deve essere uguale al campo %value%".into(),
+        ne_field: "Deve essere diverso dal campo %value%".into(),
+        gt_field: "Deve essere maggiore del campo %value%".into(),
+        ge_field: "Deve essere maggiore o uguale al campo %value%".into(),
+        lt_field: "Deve essere minore del campo %value%".into(),
+        le_field: "Deve essere minore o uguale al campo %value%".into(),
+        bytes_len_eq: "La quantità di byte deve essere uguale a %value%".into(),
+        bytes_len_ne: "La quantità di byte deve essere diversa da %value%".into(),
+        bytes_len_gt: "La quantità di byte deve essere maggiore di %value%".into(),
+        bytes_len_ge: "La quantità di byte deve essere maggiore o uguale a %value%".into(),
+        bytes_len_lt: "La quantità di byte deve essere minore di %value%".into(),
+        bytes_len_le: "La quantità di byte deve essere minore o uguale a %value%".into(),
+        bytes_len_btwn: "La quantità di byte deve essere compresa tra %value_a% e %value_b%".into(),
+        chars_len_eq: "La quantità di caratteri deve essere uguale a %value%".into(),
+        chars_len_ne: "La quantità di caratteri deve essere diversa da %value%".into(),
+        chars_len_gt: "La quantità di caratteri deve essere maggiore di %value%".into(),
+        chars_len_ge: "La quantità di caratteri deve essere maggiore o uguale a %value%".into(),
+        chars_len_lt: "La quantità di caratteri deve essere minore di %value%".into(),
+        chars_len_le: "La quantità di caratteri deve essere minore o uguale a %value%".into(),
+        chars_len_btwn: "La quantità di caratteri deve essere compresa tra %value_a% e %value_b%".into(),
+        graphemes_len_eq: "La quantità di grafemi deve essere uguale a %value%".into(),
+        graphemes_len_ne: "La quantità di grafemi deve essere diversa da %value%".into(),
+        graphemes_len_gt: "La quantità di grafemi deve essere maggiore di %value%".into(),
+        graphemes_len_ge: "La quantità di grafemi deve essere maggiore o uguale a %value%".into(),
+        graphemes_len_lt: "La quantità di grafemi deve essere minore di %value%".into(),
+        graphemes_len_le: "La quantità di grafemi deve essere minore o uguale a %value%".into(),
+        graphemes_len_btwn: "La quantità di grafemi deve essere compresa tra %value_a% e %value_b%".into(),
+        lowercase_len_eq: "La quantità di caratteri minuscoli deve essere uguale a %value%".into(),
+        lowercase_len_ne: "La quantità di caratteri minuscoli deve essere diversa da %value%".into(),
+        lowercase_len_gt: "La quantità di caratteri minuscoli deve essere maggiore di %value%".into(),
+        lowercase_len_ge: "La quantità di caratteri minuscoli deve essere maggiore o uguale a %value%".into(),
+        lowercase_len_lt: "La quantità di caratteri minuscoli deve essere minore di %value%".into(),
+        lowercase_len_le: "La quantità di caratteri minuscoli deve essere minore o uguale a %value%".into(),
+        lowercase_len_btwn: "La quantità di caratteri minuscoli deve essere compresa tra %value_a% e %value_b%".into(),
+        uppercase_len_eq: "La quantità di caratteri maiuscoli deve essere uguale a %value%".into(),
+        uppercase_len_ne: "La quantità di caratteri maiuscoli deve essere diversa da %value%".into(),
+        uppercase_len_gt: "La quantità di caratteri maiuscoli deve essere maggiore di %value%".into(),
+        uppercase_len_ge: "La quantità di caratteri maiuscoli deve essere maggiore o uguale a %value%".into(),
+        uppercase_len_lt: "La quantità di caratteri maiuscoli deve essere minore di %value%".into(),
+        uppercase_len_le: "La quantità di caratteri maiuscoli deve essere minore o uguale a %value%".into(),
+        uppercase_len_btwn: "La quantità di caratteri maiuscoli deve essere compresa tra %value_a% e %value_b%".into(),
+        number_len_eq: "La quantità di numeri deve essere uguale a %value%".into(),
+        number_len_ne: "La quantità di numeri deve essere diversa da %value%".into(),
+        number_len_gt: "La quantità di numeri deve essere maggiore di %value%".into(),
+        number_len_ge: "La quantità di numeri deve essere maggiore o uguale a %value%".into(),
+        number_len_lt: "La quantità di numeri deve essere minore di %value%".into(),
+        number_len_le: "La quantità di numeri deve essere minore o uguale a %value%".into(),
+        number_len_btwn: "La quantità di numeri deve essere compresa tra %value_a% e %value_b%".into(),
+        symbols_eq: "La quantità di simboli deve essere uguale a %value%".into(),
+        symbols_ne: "La quantità di simboli deve essere diversa da %value%".into(),
+        symbols_gt: "La quantità di simboli deve essere maggiore di %value%".into(),
+        symbols_ge: "La quantità di simboli deve essere maggiore o uguale a %value%".into(),
+        symbols_lt: "La quantità di simboli deve essere minore di %value%".into(),
+        symbols_le: "La quantità di simboli deve essere minore o uguale a %value%".into(),
+        symbols_btwn: "La quantità di simboli deve essere compresa tra %value_a% e %value_b%".into(),
+        enumerated: "Deve essere uno dei valori %value%".into(),
+    }
+}
+
+pub fn locale_pt_short() -> Locale {
+    Locale {
+        required: "Obrigatório".into(),
+        u64: "Inteiro sem sinal (64 bits)".into(),
+        i64: "Inteiro (64 bits)".into(),
+        f64: "Número decimal (64 bits)".into(),
+        usize: "Inteiro sem sinal".into(),
+        isize: "Inteiro".into(),
+        bool: "Booleano".into(),
+        str: "Texto".into(),
+        email: "E-mail inválido".into(),
+        date: "Data inválida".into(),
+        time: "Hora inválida".into(),
+        date_time: "Data e hora inválidas".into(),
+        eq: "Igual a %value%".into(),
+        ne: "Diferente de %value%".into(),
+        gt: "Maior que %value%".into(),
+        ge: "Mín. %value%".into(),
+        lt: "Menor que %value%".into(),
+        le: "Máx. %value%".into(),
+        btwn: "Entre %value_a% e %value_b%".into(),
+        eq_field: "Igual ao campo %value%".into(),
+        ne_field: "Diferente do campo %value%".into(),
+        gt_field: "Maior que o campo %value%".into(),
+        ge_field: "Maior ou igual ao campo %value%".into(),
+        lt_field: "Menor que o campo %value%".into(),
+        le_field: "Menor ou igual ao campo %value%".into(),
+        bytes_len_eq: "Exatamente %value% bytes".into(),
+        bytes_len_ne: "Diferente de %value% bytes".into(),
+        bytes_len_gt: "Mais de %value% bytes".into(),
+        bytes_len_ge: "Mín. %value% bytes".into(),
+        bytes_len_lt: "Menos de %value% bytes".into(),
+        bytes_len_le: "Máx. %value% bytes".into(),
+        bytes_len_btwn: "%value_a%–%value_b% bytes".into(),
+        chars_len_eq: "Exatamente %value% caracteres".into(),
+        chars_len_ne: "Diferente de %value% caracteres".into(),
+        chars_len_gt: "Mais de %value% caracteres".into(),
+        chars_len_ge: "Mín. %value% caracteres".into(),
+        chars_len_lt: "Menos de %value% caracteres".into(),
+        chars_len_le: "Máx. %value% caracteres".into(),
+        chars_len_btwn: "%value_a%–%value_b% caracteres".into(),
+        graphemes_len_eq: "Exatamente %value% grafemas".into(),
+        graphemes_len_ne: "Diferente de %value% grafemas".into(),
+        graphemes_len_gt: "Mais de %value% grafemas".into(),
+        graphemes_len_ge: "Mín. %value% grafemas".into(),
+        graphemes_len_lt: "Menos de %value% grafemas".into(),
+        graphemes_len_le: "Máx. %value% grafemas".into(),
+        graphemes_len_btwn: "%value_a%–%value_b% grafemas".into(),
+        lowercase_len_eq: "Exatamente %value% minúsculas".into(),
+        lowercase_len_ne: "Diferente de %value% minúsculas".into(),
+        lowercase_len_gt: "Mais de %value% minúsculas".into(),
+        lowercase_len_ge: "Mín. %value% minúsculas".into(),
+        lowercase_len_lt: "Menos de %value% minúsculas".into(),
+        lowercase_len_le: "Máx. %value% minúsculas".into(),
+        lowercase_len_btwn: "%value_a%–%value_b% minúsculas".into(),
+        uppercase_len_eq: "Exatamente %value% maiúsculas".into(),
+        uppercase_len_ne: "Diferente de %value% maiúsculas".into(),
+        uppercase_len_gt: "Mais de %value% maiúsculas".into(),
+        uppercase_len_ge: "Mín. %value% maiúsculas".into(),
+        uppercase_len_lt: "Menos de %value% maiúsculas".into(),
+        uppercase_len_le: "Máx. %value% maiúsculas".into(),
+        uppercase_len_btwn: "%value_a%–%value_b% maiúsculas".into(),
+        number_len_eq: "Exatamente %value% números".into(),
+        number_len_ne: "Diferente de %value% números".into(),
+        number_len_gt: "Mais de %value% números".into(),
+        number_len_ge: "Mín. %value% números".into(),
+        number_len_lt: "Menos de %value% números".into(),
+        number_len_le: "Máx. %value% números".into(),
+        number_len_btwn: "%value_a%–%value_b% números".into(),
+        symbols_eq: "Exatamente %value% símbolos".into(),
+        symbols_ne: "Diferente de %value% símbolos".into(),
+        symbols_gt: "Mais de %value% símbolos".into(),
+        symbols_ge: "Mín. %value% símbolos".into(),
+        symbols_lt: "Menos de %value% símbolos".into(),
+        symbols_le: "Máx. %value% símbolos".into(),
+        symbols_btwn: "%value_a%–%value_b% símbolos".into(),
+        enumerated: "Um de %value%".into(),
+    }
+}
+
+pub fn locale_es_short() -> Locale {
+    Locale {
+        required: "Obligatorio".into(),
+        u64: "Entero sin signo (64 bits)".into(),
+        i64: "Entero (64 bits)".into(),
+        f64: "Número decimal (64 bits)".into(),
+        usize: "Entero sin signo".into(),
+        isize: "Entero".into(),
+        bool: "Booleano".into(),
+        str: "Texto".into(),
+        email: "Correo inválido".into(),
+        date: "Fecha inválida".into(),
+        time: "Hora inválida".into(),
+        date_time: "Fecha y hora inválidas".into(),
+        eq: "Igual a %value%".into(),
+        ne: "Distinto de %value%".into(),
+        gt: "Mayor que %value%".into(),
+        ge: "Mín. %value%".into(),
+        lt: "Menor que %value%".into(),
+        le: "Máx. %value%".into(),
+        btwn: "Entre %value_a% y %value_b%".into(),
+        eq_field: "Igual al campo %value%".into(),
+        ne_field: "Distinto del campo %value%".into(),
+        gt_field: "Mayor que el campo %value%".into(),
+        ge_field: "Mayor o igual al campo %value%".into(),
+        lt_field: "Menor que el campo %value%".into(),
+        le_field: "Menor o igual al campo %value%".into(),
+        bytes_len_eq: "Exactamente %value% bytes".into(),
+        bytes_len_ne: "Distinto de %value% bytes".into(),
+        bytes_len_gt: "Más de %value% bytes".into(),
+        bytes_len_ge: "Mín. %value% bytes".into(),
+        bytes_len_lt: "Menos de %value% bytes".into(),
+        bytes_len_le: "Máx. %value% bytes".into(),
+        bytes_len_btwn: "%value_a%–%value_b% bytes".into(),
+        chars_len_eq: "Exactamente %value% caracteres".into(),
+        chars_len_ne: "Distinto de %value% caracteres".into(),
+        chars_len_gt: "Más de %value% caracteres".into(),
+        chars_len_ge: "Mín. %value% caracteres".into(),
+        chars_len_lt: "Menos de %value% caracteres".into(),
+        chars_len_le: "Máx. %value% caracteres".into(),
+        chars_len_btwn: "%value_a%–%value_b% caracteres".into(),
+        graphemes_len_eq: "Exactamente %value% grafemas".into(),
+        graphemes_len_ne: "Distinto de %value% grafemas".into(),
+        graphemes_len_gt: "Más de %value% grafemas".into(),
+        graphemes_len_ge: "Mín. %value% grafemas".into(),
+        graphemes_len_lt: "Menos de %value% grafemas".into(),
+        graphemes_len_le: "Máx. %value% grafemas".into(),
+        graphemes_len_btwn: "%value_a%–%value_b% grafemas".into(),
+        lowercase_len_eq: "Exactamente %value% minúsculas".into(),
+        lowercase_len_ne: "Distinto de %value% minúsculas".into(),
+        lowercase_len_gt: "Más de %value% minúsculas".into(),
+        lowercase_len_ge: "Mín. %value% minúsculas".into(),
+        lowercase_len_lt: "Menos de %value% minúsculas".into(),
+        lowercase_len_le: "Máx. %value% minúsculas".into(),
+        lowercase_len_btwn: "%value_a%–%value_b% minúsculas".into(),
+        uppercase_len_eq: "Exactamente %value% mayúsculas".into(),
+        uppercase_len_ne: "Distinto de %value% mayúsculas".into(),
+        uppercase_len_gt: "Más de %value% mayúsculas".into(),
+        uppercase_len_ge: "Mín. %value% mayúsculas".into(),
+        uppercase_len_lt: "Menos de %value% mayúsculas".into(),
+        uppercase_len_le: "Máx. %value% mayúsculas".into(),
+        uppercase_len_btwn: "%value_a%–%value_b% mayúsculas".into(),
+        number_len_eq: "Exactamente %value% números".into(),
+        number_len_ne: "Distinto de %value% números".into(),
+        number_len_gt: "Más de %value% números".into(),
+        number_len_ge: "Mín. %value% números".into(),
+        number_len_lt: "Menos de %value% números".into(),
+        number_len_le: "Máx. %value% números".into(),
+        number_len_btwn: "%value_a%–%value_b% números".into(),
+        symbols_eq: "Exactamente %value% símbolos".into(),
+        symbols_ne: "Distinto de %value% símbolos".into(),
+        symbols_gt: "Más de %value% símbolos".into(),
+        symbols_ge: "Mín. %value% símbolos".into(),
+        symbols_lt: "Menos de %value% símbolos".into(),
+        symbols_le: "Máx. %value% símbolos".into(),
+        symbols_btwn: "%value_a%–%value_b% símbolos".into(),
+        enumerated: "Uno de %value%".into(),
+    }
+}
+
+pub fn locale_en_short() -> Locale {
+    Locale {
+        required: "Required".into(),
+        u64: "Unsigned 64-bit integer".into(),
+        i64: "64-bit integer".into(),
+        f64: "64-bit float".into(),
+        usize: "Unsigned integer".into(),
+        isize: "Integer".into(),
+        bool: "Boolean".into(),
+        str: "Text".into(),
+        email: "Invalid e-mail".into(),
+        date: "Invalid date".into(),
+        time: "Invalid time".into(),
+        date_time: "Invalid date and time".into(),
+        eq: "Equal to %value%".into(),
+        ne: "Not %value%".into(),
+        gt: "Over %value%".into(),
+        ge: "Min. %value%".into(),
+        lt: "Under %value%".into(),
+        le: "Max. %value%".into(),
+        btwn: "Between %value_a% and %value_b%".into(),
+        eq_field: "Equal to field %value%".into(),
+        ne_field: "Different from field %value%".into(),
+        gt_field: "Greater than field %value%".into(),
+        ge_field: "At least field %value%".into(),
+        lt_field: "Less than field %value%".into(),
+        le_field: "At most field %value%".into(),
+        bytes_len_eq: "Exactly %value% bytes".into(),
+        bytes_len_ne: "Not %value% bytes".into(),
+        bytes_len_gt: "Over %value% bytes".into(),
+        bytes_len_ge: "Min. %value% bytes".into(),
+        bytes_len_lt: "Under %value% bytes".into(),
+        bytes_len_le: "Max. %value% bytes".into(),
+        bytes_len_btwn: "%value_a%–%value_b% bytes".into(),
+        chars_len_eq: "Exactly %value% characters".into(),
+        chars_len_ne: "Not %value% characters".into(),
+        chars_len_gt: "Over %value% characters".into(),
+        chars_len_ge: "Min. %value% characters".into(),
+        chars_len_lt: "Under %value% characters".into(),
+        chars_len_le: "Max. %value% characters".into(),
+        chars_len_btwn: "%value_a%–%value_b% characters".into(),
+        graphemes_len_eq: "Exactly %value% graphemes".into(),
+        graphemes_len_ne: "Not %value% graphemes".into(),
+        graphemes_len_gt: "Over %value% graphemes".into(),
+        graphemes_len_ge: "Min. %value% graphemes".into(),
+        graphemes_len_lt: "Under %value% graphemes".into(),
+        graphemes_len_le: "Max. %value% graphemes".into(),
+        graphemes_len_btwn: "%value_a%–%value_b% graphemes".into(),
+        lowercase_len_eq: "Exactly %value% lowercase".into(),
+        lowercase_len_ne: "Not %value% lowercase".into(),
+        lowercase_len_gt: "Over %value% lowercase".into(),
+        lowercase_len_ge: "Min. %value% lowercase".into(),
+        lowercase_len_lt: "Under %value% lowercase".into(),
+        lowercase_len_le: "Max. %value% lowercase".into(),
+        lowercase_len_btwn: "%value_a%–%value_b% lowercase".into(),
+        uppercase_len_eq: "Exactly %value% uppercase".into(),
+        uppercase_len_ne: "Not %value% uppercase".into(),
+        uppercase_len_gt: "Over %value% uppercase".into(),
+        uppercase_len_ge: "Min. %value% uppercase".into(),
+        uppercase_len_lt: "Under %value% uppercase".into(),
+        uppercase_len_le: "Max. %value% uppercase".into(),
+        uppercase_len_btwn: "%value_a%–%value_b% uppercase".into(),
+        number_len_eq: "Exactly %value% numbers".into(),
+        number_len_ne: "Not %value% numbers".into(),
+        number_len_gt: "Over %value% numbers".into(),
+        number_len_ge: "Min. %value% numbers".into(),
+        number_len_lt: "Under %value% numbers".into(),
+        number_len_le: "Max. %value% numbers".into(),
+        number_len_btwn: "%value_a%–%value_b% numbers".into(),
+        symbols_eq: "Exactly %value% symbols".into(),
+        symbols_ne: "Not %value% symbols".into(),
+        symbols_gt: "Over %value% symbols".into(),
+        symbols_ge: "Min. %value% symbols".into(),
+        symbols_lt: "Under %value% symbols".into(),
+        symbols_le: "Max. %value% symbols".into(),
+        symbols_btwn: "%value_a%–%value_b% symbols".into(),
+        enumerated: "One of %value%".into(),
+    }
+}
+
+pub(crate) static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"%[a-z_]+%").unwrap());
+
+macro_rules! locale_keys {
+    ($($key:ident),* $(,)?) => {
+        pub const LOCALE_KEYS: [&str; 75] = [$(stringify!($key)),*];
+
+        pub fn locale_fields(locale: &Locale) -> [(&'static str, &String); 75] {
+            [$((stringify!($key), &locale.$key)),*]
+        }
+
+        pub fn locale_field_mut<'a>(locale: &'a mut Locale, key: &str) -> Option<&'a mut String> {
+            match key {
+                $(stringify!($key) => Some(&mut locale.$key),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+locale_keys!(
+    required,
+    u64,
+    i64,
+    f64,
+    usize,
+    isize,
+    bool,
+    str,
+    email,
+    date,
+    time,
+    date_time,
+    eq,
+    ne,
+    gt,
+    ge,
+    lt,
+    le,
+    btwn,
+    eq_field,
+    ne_field,
+    gt_field,
+    ge_field,
+    lt_field,
+    le_field,
+    bytes_len_eq,
+    bytes_len_ne,
+    bytes_len_gt,
+    bytes_len_ge,
+    bytes_len_lt,
+    bytes_len_le,
+    bytes_len_btwn,
+    chars_len_eq,
+    chars_len_ne,
+    chars_len_gt,
+    chars_len_ge,
+    chars_len_lt,
+    chars_len_le,
+    chars_len_btwn,
+    graphemes_len_eq,
+    graphemes_len_ne,
+    graphemes_len_gt,
+    graphemes_len_ge,
+    graphemes_len_lt,
+    graphemes_len_le,
+    graphemes_len_btwn,
+    lowercase_len_eq,
+    lowercase_len_ne,
+    lowercase_len_gt,
+    lowercase_len_ge,
+    lowercase_len_lt,
+    lowercase_len_le,
+    lowercase_len_btwn,
+    uppercase_len_eq,
+    uppercase_len_ne,
+    uppercase_len_gt,
+    uppercase_len_ge,
+    uppercase_len_lt,
+    uppercase_len_le,
+    uppercase_len_btwn,
+    number_len_eq,
+    number_len_ne,
+    number_len_gt,
+    number_len_ge,
+    number_len_lt,
+    number_len_le,
+    number_len_btwn,
+    symbols_eq,
+    symbols_ne,
+    symbols_gt,
+    symbols_ge,
+    symbols_lt,
+    symbols_le,
+    symbols_btwn,
+    enumerated,
+);
+
+pub fn locale_field<'a>(locale: &'a Locale, key: &str) -> Option<&'a String> {
+    locale_fields(locale).into_iter().find(|(field, _)| *field == key).map(|(_, message)| message)
+}
+
+pub fn locale_placeholders(key: &str) -> &'static [&'static str] {
+    if key.ends_with("btwn") {
+        &["%value_a%", "%value_b%"]
+    } else if ["eq", "ne", "gt", "ge", "lt", "le", "field", "enumerated"].iter().any(|suffix| key.ends_with(suffix)) {
+        &["%value%"]
+    } else {
+        &[]
+    }
+}
+
+pub fn message_placeholders(message: &str) -> Vec<&str> {
+    let mut result: Vec<&str> = PLACEHOLDER_REGEX.find_iter(message).map(|m| m.as_str()).collect();
+    result.sort();
+    result.dedup();
+    result
+}
+
+pub fn placeholders_match(key: &str, message: &str) -> bool {
+    message_placeholders(message).into_iter().filter(|placeholder| *placeholder != "%field%").eq(locale_placeholders(key).iter().copied())
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::LazyLock;
@@ -251,7 +856,10 @@ mod tests {
         schema::EnumValues,
     };
 
-    use super::{locale_en_long, locale_es_long, locale_pt_long};
+    use super::{
+        LOCALE_KEYS, locale_de_long, locale_en_long, locale_en_short, locale_es_long, locale_es_short, locale_field, locale_field_mut, locale_fields,
+        locale_fr_long, locale_it_long, locale_placeholders, locale_pt_long, locale_pt_short, message_placeholders, placeholders_match,
+    };
 
     const USIZE_VALUES: [usize; 6] = [0, 1, 2, 3, 4, 5];
     const ISIZE_VALUES: [isize; 5] = [-2, -1, 0, 1, 2];
@@ -605,4 +1213,630 @@ mod tests {
         assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Must be one of the values [ -2, -1, 0, 1, 2 ]".to_string());
         assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Must be one of the values [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
     }
+
+    #[test]
+    fn validation_err_to_locale_locale_fr_long() {
+        let l = locale_fr_long();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "Est obligatoire".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Doit être un entier non signé de 64 bits".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "Doit être un entier de 64 bits".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "Doit être un nombre à virgule flottante de 64 bits".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Doit être un entier non signé".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Doit être un entier".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Doit être un booléen".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Doit être une chaîne de caractères".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "Doit être un e-mail".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Doit être une date".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Doit être une heure".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Doit être une date et une heure".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Doit être égal à 34".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Doit être différent de -4".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Doit être supérieur à -4.6".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Doit être inférieur à -93".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Doit être inférieur ou égal à false".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Doit être compris entre "aurorae" et "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "Le nombre d'octets doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "Le nombre d'octets doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "Le nombre d'octets doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "Le nombre d'octets doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "Le nombre d'octets doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "Le nombre d'octets doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "Le nombre d'octets doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "Le nombre de caractères doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "Le nombre de caractères doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "Le nombre de caractères doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "Le nombre de caractères doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "Le nombre de caractères doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "Le nombre de caractères doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "Le nombre de caractères doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "Le nombre de graphèmes doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "Le nombre de graphèmes doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "Le nombre de graphèmes doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "Le nombre de graphèmes doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "Le nombre de graphèmes doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "Le nombre de graphèmes doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "Le nombre de graphèmes doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "Le nombre de caractères minuscules doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "Le nombre de caractères minuscules doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "Le nombre de caractères minuscules doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "Le nombre de caractères minuscules doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "Le nombre de caractères minuscules doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "Le nombre de caractères minuscules doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "Le nombre de caractères minuscules doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "Le nombre de caractères majuscules doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "Le nombre de caractères majuscules doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "Le nombre de caractères majuscules doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "Le nombre de caractères majuscules doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "Le nombre de caractères majuscules doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "Le nombre de caractères majuscules doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "Le nombre de caractères majuscules doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "Le nombre de chiffres doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "Le nombre de chiffres doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "Le nombre de chiffres doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "Le nombre de chiffres doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "Le nombre de chiffres doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "Le nombre de chiffres doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "Le nombre de chiffres doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "Le nombre de symboles doit être égal à 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "Le nombre de symboles doit être différent de 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "Le nombre de symboles doit être supérieur à 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "Le nombre de symboles doit être supérieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "Le nombre de symboles doit être inférieur à 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "Le nombre de symboles doit être inférieur ou égal à 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "Le nombre de symboles doit être compris entre 27 et 39".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "Doit être l'une des valeurs [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Doit être l'une des valeurs [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Doit être l'une des valeurs [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn validation_err_to_locale_locale_de_long() {
+        let l = locale_de_long();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "Ist erforderlich".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Muss eine vorzeichenlose 64-Bit-Ganzzahl sein".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "Muss eine 64-Bit-Ganzzahl sein".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "Muss eine 64-Bit-Gleitkommazahl sein".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Muss eine vorzeichenlose Ganzzahl sein".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Muss eine Ganzzahl sein".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Muss ein boolescher Wert sein".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Muss eine Zeichenkette sein".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "Muss eine E-Mail-Adresse sein".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Muss ein Datum sein".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Muss eine Uhrzeit sein".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Muss ein Datum mit Uhrzeit sein".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Muss gleich 34 sein".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Muss ungleich -4 sein".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Muss größer als -4.6 sein".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Muss kleiner als -93 sein".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Muss kleiner als oder gleich false sein".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Muss zwischen "aurorae" und "crespúculum" liegen"#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "Die Anzahl der Bytes muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "Die Anzahl der Bytes muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "Die Anzahl der Bytes muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "Die Anzahl der Bytes muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "Die Anzahl der Bytes muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "Die Anzahl der Bytes muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "Die Anzahl der Bytes muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "Die Anzahl der Zeichen muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "Die Anzahl der Zeichen muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "Die Anzahl der Zeichen muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "Die Anzahl der Zeichen muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "Die Anzahl der Zeichen muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "Die Anzahl der Zeichen muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "Die Anzahl der Zeichen muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "Die Anzahl der Grapheme muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "Die Anzahl der Grapheme muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "Die Anzahl der Grapheme muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "Die Anzahl der Grapheme muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "Die Anzahl der Grapheme muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "Die Anzahl der Grapheme muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "Die Anzahl der Grapheme muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "Die Anzahl der Kleinbuchstaben muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "Die Anzahl der Kleinbuchstaben muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "Die Anzahl der Kleinbuchstaben muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "Die Anzahl der Kleinbuchstaben muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "Die Anzahl der Kleinbuchstaben muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "Die Anzahl der Kleinbuchstaben muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "Die Anzahl der Kleinbuchstaben muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "Die Anzahl der Großbuchstaben muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "Die Anzahl der Großbuchstaben muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "Die Anzahl der Großbuchstaben muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "Die Anzahl der Großbuchstaben muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "Die Anzahl der Großbuchstaben muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "Die Anzahl der Großbuchstaben muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "Die Anzahl der Großbuchstaben muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "Die Anzahl der Ziffern muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "Die Anzahl der Ziffern muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "Die Anzahl der Ziffern muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "Die Anzahl der Ziffern muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "Die Anzahl der Ziffern muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "Die Anzahl der Ziffern muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "Die Anzahl der Ziffern muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "Die Anzahl der Symbole muss gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "Die Anzahl der Symbole muss ungleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "Die Anzahl der Symbole muss größer als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "Die Anzahl der Symbole muss größer als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "Die Anzahl der Symbole muss kleiner als 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "Die Anzahl der Symbole muss kleiner als oder gleich 27 sein".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "Die Anzahl der Symbole muss zwischen 27 und 39 liegen".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "Muss einer der Werte [ 0, 1, 2, 3, 4, 5 ] sein".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Muss einer der Werte [ -2, -1, 0, 1, 2 ] sein".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Muss einer der Werte [ "APPLE", "GRAPE", "PEAR" ] sein"#.to_string());
+    }
+
+    #[test]
+    fn validation_err_to_locale_locale_it_long() {
+        let l = locale_it_long();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "È obbligatorio".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Deve essere un intero senza segno a 64 bit".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "Deve essere un intero a 64 bit".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "Deve essere un numero in virgola mobile a 64 bit".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Deve essere un intero senza segno".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Deve essere un intero".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Deve essere un booleano".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Deve essere una stringa".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "Deve essere un'e-mail".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Deve essere una data".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Deve essere un orario".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Deve essere una data e un orario".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Deve essere uguale a 34".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Deve essere diverso da -4".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Deve essere maggiore di -4.6".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Deve essere minore di -93".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Deve essere minore o uguale a false".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Deve essere compreso tra "aurorae" e "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "La quantità di byte deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "La quantità di byte deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "La quantità di byte deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "La quantità di byte deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "La quantità di byte deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "La quantità di byte deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "La quantità di byte deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "La quantità di caratteri deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "La quantità di caratteri deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "La quantità di caratteri deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "La quantità di caratteri deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "La quantità di caratteri deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "La quantità di caratteri deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "La quantità di caratteri deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "La quantità di grafemi deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "La quantità di grafemi deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "La quantità di grafemi deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "La quantità di grafemi deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "La quantità di grafemi deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "La quantità di grafemi deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "La quantità di grafemi deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "La quantità di caratteri minuscoli deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "La quantità di caratteri minuscoli deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "La quantità di caratteri minuscoli deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "La quantità di caratteri minuscoli deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "La quantità di caratteri minuscoli deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "La quantità di caratteri minuscoli deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "La quantità di caratteri minuscoli deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "La quantità di caratteri maiuscoli deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "La quantità di caratteri maiuscoli deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "La quantità di caratteri maiuscoli deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "La quantità di caratteri maiuscoli deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "La quantità di caratteri maiuscoli deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "La quantità di caratteri maiuscoli deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "La quantità di caratteri maiuscoli deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "La quantità di numeri deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "La quantità di numeri deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "La quantità di numeri deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "La quantità di numeri deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "La quantità di numeri deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "La quantità di numeri deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "La quantità di numeri deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "La quantità di simboli deve essere uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "La quantità di simboli deve essere diversa da 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "La quantità di simboli deve essere maggiore di 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "La quantità di simboli deve essere maggiore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "La quantità di simboli deve essere minore di 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "La quantità di simboli deve essere minore o uguale a 27".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "La quantità di simboli deve essere compresa tra 27 e 39".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "Deve essere uno dei valori [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Deve essere uno dei valori [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Deve essere uno dei valori [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn validation_err_to_locale_locale_pt_short() {
+        let l = locale_pt_short();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "Obrigatório".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Inteiro sem sinal (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "Inteiro (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "Número decimal (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Inteiro sem sinal".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Inteiro".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Booleano".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Texto".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "E-mail inválido".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Data inválida".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Hora inválida".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Data e hora inválidas".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Igual a 34".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Diferente de -4".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Maior que -4.6".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Mín. 27".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Menor que -93".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Máx. false".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Entre "aurorae" e "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "Exatamente 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "Diferente de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "Mais de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "Mín. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "Menos de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "Máx. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "27–39 bytes".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "Exatamente 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "Diferente de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "Mais de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "Mín. 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "Menos de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "Máx. 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "27–39 caracteres".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "Exatamente 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "Diferente de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "Mais de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "Mín. 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "Menos de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "Máx. 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "27–39 grafemas".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "Exatamente 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "Diferente de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "Mais de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "Mín. 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "Menos de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "Máx. 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "27–39 minúsculas".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "Exatamente 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "Diferente de 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "Mais de 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "Mín. 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "Menos de 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "Máx. 27 maiúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "27–39 maiúsculas".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "Exatamente 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "Diferente de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "Mais de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "Mín. 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "Menos de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "Máx. 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "27–39 números".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "Exatamente 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "Diferente de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "Mais de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "Mín. 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "Menos de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "Máx. 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "27–39 símbolos".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "Um de [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Um de [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Um de [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn validation_err_to_locale_locale_es_short() {
+        let l = locale_es_short();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "Obligatorio".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Entero sin signo (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "Entero (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "Número decimal (64 bits)".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Entero sin signo".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Entero".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Booleano".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Texto".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "Correo inválido".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Fecha inválida".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Hora inválida".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Fecha y hora inválidas".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Igual a 34".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Distinto de -4".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Mayor que -4.6".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Mín. 27".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Menor que -93".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Máx. false".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Entre "aurorae" y "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "Exactamente 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "Distinto de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "Más de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "Mín. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "Menos de 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "Máx. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "27–39 bytes".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "Exactamente 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "Distinto de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "Más de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "Mín. 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "Menos de 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "Máx. 27 caracteres".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "27–39 caracteres".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "Exactamente 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "Distinto de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "Más de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "Mín. 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "Menos de 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "Máx. 27 grafemas".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "27–39 grafemas".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "Exactamente 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "Distinto de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "Más de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "Mín. 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "Menos de 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "Máx. 27 minúsculas".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "27–39 minúsculas".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "Exactamente 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "Distinto de 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "Más de 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "Mín. 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "Menos de 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "Máx. 27 mayúsculas".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "27–39 mayúsculas".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "Exactamente 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "Distinto de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "Más de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "Mín. 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "Menos de 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "Máx. 27 números".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "27–39 números".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "Exactamente 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "Distinto de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "Más de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "Mín. 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "Menos de 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "Máx. 27 símbolos".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "27–39 símbolos".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "Uno de [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "Uno de [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"Uno de [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn validation_err_to_locale_locale_en_short() {
+        let l = locale_en_short();
+
+        assert_eq!(localize_validation_err(&REQUIRED, &l), "Required".to_string());
+        assert_eq!(localize_validation_err(&U64, &l), "Unsigned 64-bit integer".to_string());
+        assert_eq!(localize_validation_err(&I64, &l), "64-bit integer".to_string());
+        assert_eq!(localize_validation_err(&F64, &l), "64-bit float".to_string());
+        assert_eq!(localize_validation_err(&USIZE, &l), "Unsigned integer".to_string());
+        assert_eq!(localize_validation_err(&ISIZE, &l), "Integer".to_string());
+        assert_eq!(localize_validation_err(&BOOL, &l), "Boolean".to_string());
+        assert_eq!(localize_validation_err(&STR, &l), "Text".to_string());
+        assert_eq!(localize_validation_err(&EMAIL, &l), "Invalid e-mail".to_string());
+        assert_eq!(localize_validation_err(&DATE, &l), "Invalid date".to_string());
+        assert_eq!(localize_validation_err(&TIME, &l), "Invalid time".to_string());
+        assert_eq!(localize_validation_err(&DATE_TIME, &l), "Invalid date and time".to_string());
+
+        assert_eq!(localize_validation_err(&OP_U64, &l), "Equal to 34".to_string());
+        assert_eq!(localize_validation_err(&OP_I64, &l), "Not -4".to_string());
+        assert_eq!(localize_validation_err(&OP_F64, &l), "Over -4.6".to_string());
+        assert_eq!(localize_validation_err(&OP_USIZE, &l), "Min. 27".to_string());
+        assert_eq!(localize_validation_err(&OP_ISIZE, &l), "Under -93".to_string());
+        assert_eq!(localize_validation_err(&OP_BOOL, &l), "Max. false".to_string());
+        assert_eq!(localize_validation_err(&OP_STR, &l), r#"Between "aurorae" and "crespúculum""#.to_string());
+
+        assert_eq!(localize_validation_err(&BYTES_LEN_EQ, &l), "Exactly 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_NE, &l), "Not 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GT, &l), "Over 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_GE, &l), "Min. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LT, &l), "Under 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_LE, &l), "Max. 27 bytes".to_string());
+        assert_eq!(localize_validation_err(&BYTES_LEN_BTWN, &l), "27–39 bytes".to_string());
+
+        assert_eq!(localize_validation_err(&CHARS_LEN_EQ, &l), "Exactly 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_NE, &l), "Not 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GT, &l), "Over 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_GE, &l), "Min. 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LT, &l), "Under 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_LE, &l), "Max. 27 characters".to_string());
+        assert_eq!(localize_validation_err(&CHARS_LEN_BTWN, &l), "27–39 characters".to_string());
+
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_EQ, &l), "Exactly 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_NE, &l), "Not 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GT, &l), "Over 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_GE, &l), "Min. 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LT, &l), "Under 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_LE, &l), "Max. 27 graphemes".to_string());
+        assert_eq!(localize_validation_err(&GRAPHEMES_LEN_BTWN, &l), "27–39 graphemes".to_string());
+
+        assert_eq!(localize_validation_err(&LOWER_LEN_EQ, &l), "Exactly 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_NE, &l), "Not 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GT, &l), "Over 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_GE, &l), "Min. 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LT, &l), "Under 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_LE, &l), "Max. 27 lowercase".to_string());
+        assert_eq!(localize_validation_err(&LOWER_LEN_BTWN, &l), "27–39 lowercase".to_string());
+
+        assert_eq!(localize_validation_err(&UPPER_LEN_EQ, &l), "Exactly 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_NE, &l), "Not 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GT, &l), "Over 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_GE, &l), "Min. 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LT, &l), "Under 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_LE, &l), "Max. 27 uppercase".to_string());
+        assert_eq!(localize_validation_err(&UPPER_LEN_BTWN, &l), "27–39 uppercase".to_string());
+
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_EQ, &l), "Exactly 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_NE, &l), "Not 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GT, &l), "Over 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_GE, &l), "Min. 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LT, &l), "Under 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_LE, &l), "Max. 27 numbers".to_string());
+        assert_eq!(localize_validation_err(&NUMBERS_LEN_BTWN, &l), "27–39 numbers".to_string());
+
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_EQ, &l), "Exactly 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_NE, &l), "Not 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GT, &l), "Over 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_GE, &l), "Min. 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LT, &l), "Under 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_LE, &l), "Max. 27 symbols".to_string());
+        assert_eq!(localize_validation_err(&SYMBOLS_LEN_BTWN, &l), "27–39 symbols".to_string());
+
+        assert_eq!(localize_validation_err(&ENUM_USIZE, &l), "One of [ 0, 1, 2, 3, 4, 5 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_ISIZE, &l), "One of [ -2, -1, 0, 1, 2 ]".to_string());
+        assert_eq!(localize_validation_err(&ENUM_STR, &l), r#"One of [ "APPLE", "GRAPE", "PEAR" ]"#.to_string());
+    }
+
+    #[test]
+    fn locale_short_fields_filled() {
+        for l in [locale_pt_short(), locale_es_short(), locale_en_short()] {
+            for (field, message) in locale_fields(&l) {
+                assert!(!message.is_empty(), "{field}");
+            }
+        }
+    }
+
+    #[test]
+    fn locale_short_placeholders_match_long() {
+        for (short, long) in [(locale_pt_short(), locale_pt_long()), (locale_es_short(), locale_es_long()), (locale_en_short(), locale_en_long())] {
+            for ((field, short_message), (_, long_message)) in locale_fields(&short).into_iter().zip(locale_fields(&long)) {
+                assert_eq!(message_placeholders(short_message), message_placeholders(long_message), "{field}");
+            }
+        }
+    }
+
+    #[test]
+    fn locale_long_placeholders_match() {
+        for l in [locale_pt_long(), locale_es_long(), locale_fr_long(), locale_de_long(), locale_it_long()] {
+            for ((field, message), (_, en_message)) in locale_fields(&l).into_iter().zip(locale_fields(&locale_en_long())) {
+                assert!(!message.is_empty(), "{field}");
+                assert_eq!(message_placeholders(message), message_placeholders(en_message), "{field}");
+            }
+        }
+    }
+
+    #[test]
+    fn locale_fields_keys() {
+        let l = locale_en_long();
+        assert_eq!(locale_fields(&l).map(|(key, _)| key), LOCALE_KEYS);
+        assert_eq!(locale_fields(&l)[0], ("required", &"Is required".to_string()));
+        assert_eq!(locale_fields(&l)[74], ("enumerated", &"Must be one of the values %value%".to_string()));
+    }
+
+    #[test]
+    fn locale_field_keys() {
+        let l = locale_en_long();
+        for (key, message) in locale_fields(&l) {
+            assert_eq!(locale_field(&l, key), Some(message));
+        }
+        assert_eq!(locale_field(&l, "symbols_len_eq"), None);
+    }
+
+    #[test]
+    fn locale_field_mut_keys() {
+        let mut l = locale_en_long();
+        for key in LOCALE_KEYS {
+            *locale_field_mut(&mut l, key).unwrap() = key.to_uppercase();
+        }
+        for (key, message) in locale_fields(&l) {
+            assert_eq!(message, &key.to_uppercase());
+        }
+        assert_eq!(locale_field_mut(&mut l, "symbols_len_eq"), None);
+        assert_eq!(locale_field_mut(&mut l, ""), None);
+    }
+
+    #[test]
+    fn locale_placeholders_by_key() {
+        assert_eq!(locale_placeholders("required"), &[] as &[&str]);
+        assert_eq!(locale_placeholders("date_time"), &[] as &[&str]);
+        assert_eq!(locale_placeholders("eq"), &["%value%"]);
+        assert_eq!(locale_placeholders("le_field"), &["%value%"]);
+        assert_eq!(locale_placeholders("number_len_gt"), &["%value%"]);
+        assert_eq!(locale_placeholders("symbols_ne"), &["%value%"]);
+        assert_eq!(locale_placeholders("enumerated"), &["%value%"]);
+        assert_eq!(locale_placeholders("btwn"), &["%value_a%", "%value_b%"]);
+        assert_eq!(locale_placeholders("graphemes_len_btwn"), &["%value_a%", "%value_b%"]);
+    }
+
+    #[test]
+    fn message_placeholders_found() {
+        assert_eq!(message_placeholders("Is required"), Vec::<&str>::new());
+        assert_eq!(message_placeholders("Must be equals to %value%"), vec!["%value%"]);
+        assert_eq!(message_placeholders("Between %value_b% and %value_a%"), vec!["%value_a%", "%value_b%"]);
+        assert_eq!(message_placeholders("%value% or %value%, 100% sure"), vec!["%value%"]);
+    }
+
+    #[test]
+    fn placeholders_match_optional_field() {
+        assert!(placeholders_match("required", "Is required"));
+        assert!(placeholders_match("required", "%field% is required"));
+        assert!(placeholders_match("gt_field", "%field% must be greater than %value%"));
+        assert!(placeholders_match("btwn", "Between %value_b% and %value_a%"));
+        assert!(!placeholders_match("required", "%value% is required"));
+        assert!(!placeholders_match("gt", "%field% must be greater"));
+        assert!(!placeholders_match("btwn", "Between %value% and %value_b%"));
+        assert!(!placeholders_match("eq", "Must be %value% or %other%"));
+    }
+
+    #[test]
+    fn locale_bundled_placeholders_match_keys() {
+        for l in [
+            locale_pt_long(),
+            locale_es_long(),
+            locale_en_long(),
+            locale_fr_long(),
+            locale_de_long(),
+            locale_it_long(),
+            locale_pt_short(),
+            locale_es_short(),
+            locale_en_short(),
+        ] {
+            for (key, message) in locale_fields(&l) {
+                assert_eq!(message_placeholders(message), locale_placeholders(key), "{key}");
+            }
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use araucaria::locale::Locale;
+
+use crate::locale::{PLACEHOLDER_REGEX, locale_fields, placeholders_match};
+
+const UNIT_TOKENS: [&str; 5] = ["bytes", "B", "KB", "MB", "GB"];
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LocaleIssue {
+    Empty(String),
+    PlaceholderMismatch(String),
+    Untranslated(String),
+}
+
+pub fn check_locale(locale: &Locale) -> Vec<LocaleIssue> {
+    locale_fields(locale)
+        .into_iter()
+        .filter_map(|(key, message)| {
+            if message.trim().is_empty() {
+                Some(LocaleIssue::Empty(key.to_string()))
+            } else if !placeholders_match(key, message) {
+                Some(LocaleIssue::PlaceholderMismatch(key.to_string()))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn language_neutral(message: &str) -> bool {
+    PLACEHOLDER_REGEX
+        .replace_all(message, "")
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .all(|word| UNIT_TOKENS.contains(&word))
+}
+
+pub fn check_locale_translated(locale: &Locale, other: &Locale) -> Vec<LocaleIssue> {
+    locale_fields(locale)
+        .into_iter()
+        .zip(locale_fields(other))
+        .filter(|((_, message), (_, other_message))| !message.trim().is_empty() && message == other_message && !language_neutral(message))
+        .map(|((key, _), _)| LocaleIssue::Untranslated(key.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::locale::Locale;
+
+    use crate::locale::{
+        locale_de_long, locale_en_long, locale_en_short, locale_es_long, locale_es_short, locale_fr_long, locale_it_long, locale_pt_long,
+        locale_pt_short,
+    };
+
+    use super::{LocaleIssue, check_locale, check_locale_translated};
+
+    #[test]
+    fn check_locale_bundled() {
+        for l in [
+            locale_pt_long(),
+            locale_es_long(),
+            locale_en_long(),
+            locale_fr_long(),
+            locale_de_long(),
+            locale_it_long(),
+            locale_pt_short(),
+            locale_es_short(),
+            locale_en_short(),
+        ] {
+            assert_eq!(check_locale(&l), vec![]);
+        }
+    }
+
+    #[test]
+    fn check_locale_issues() {
+        let l = Locale {
+            required: "".into(),
+            symbols_ne: "  ".into(),
+            btwn: "Deve estar entre %value%".into(),
+            gt: "Deve ser maior".into(),
+            lt: "%field% deve ser menor que %value%".into(),
+            str: "Deve ser %value%".into(),
+            eq: "Deve ser igual a %valor%".into(),
+            chars_len_btwn: "".into(),
+            ..locale_pt_long()
+        };
+        assert_eq!(
+            check_locale(&l),
+            vec![
+                LocaleIssue::Empty("required".into()),
+                LocaleIssue::PlaceholderMismatch("str".into()),
+                LocaleIssue::PlaceholderMismatch("eq".into()),
+                LocaleIssue::PlaceholderMismatch("gt".into()),
+                LocaleIssue::PlaceholderMismatch("btwn".into()),
+                LocaleIssue::Empty("chars_len_btwn".into()),
+                LocaleIssue::Empty("symbols_ne".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_locale_translated_bundled() {
+        let long = [locale_pt_long(), locale_es_long(), locale_en_long(), locale_fr_long(), locale_de_long(), locale_it_long()];
+        for l in &long {
+            for other in &long {
+                if l != other {
+                    assert_eq!(check_locale_translated(l, other), vec![]);
+                }
+            }
+        }
+        for l in [locale_pt_short(), locale_es_short()] {
+            assert_eq!(check_locale_translated(&l, &locale_en_short()), vec![]);
+        }
+    }
+
+    #[test]
+    fn check_locale_translated_copy_paste() {
+        let l = Locale { required: locale_en_long().required, btwn: locale_en_long().btwn, date: "".into(), ..locale_es_long() };
+        let other = Locale { date: "".into(), ..locale_en_long() };
+        assert_eq!(check_locale_translated(&l, &other), vec![LocaleIssue::Untranslated("required".into()), LocaleIssue::Untranslated("btwn".into())]);
+        let short = Locale { chars_len_btwn: locale_en_short().chars_len_btwn, ..locale_pt_short() };
+        assert_eq!(check_locale_translated(&short, &locale_en_short()), vec![LocaleIssue::Untranslated("chars_len_btwn".into())]);
+    }
+
+    #[test]
+    fn check_locale_translated_language_neutral() {
+        let l = Locale { bytes_len_btwn: "%value_a%–%value_b% bytes".into(), eq: "= %value%".into(), gt: "> 10 MB".into(), ..locale_pt_long() };
+        let other = Locale { bytes_len_btwn: "%value_a%–%value_b% bytes".into(), eq: "= %value%".into(), gt: "> 10 MB".into(), ..locale_en_long() };
+        assert_eq!(check_locale_translated(&l, &other), vec![]);
+        let l = Locale { ne: "Not %value% bytes".into(), ..locale_pt_long() };
+        let other = Locale { ne: "Not %value% bytes".into(), ..locale_en_long() };
+        assert_eq!(check_locale_translated(&l, &other), vec![LocaleIssue::Untranslated("ne".into())]);
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::LazyLock;
+
+use araucaria::locale::Locale;
+use regex::Regex;
+
+use crate::locale::locale_fields;
+
+use super::LocaleFileErr;
+
+static IDENTIFIER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9_-]*$").unwrap());
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"%([a-z_]+)%").unwrap());
+
+fn parse_placeable(placeable: &str) -> Option<String> {
+    let placeable = placeable.trim();
+    if let Some(variable) = placeable.strip_prefix('$') {
+        return if IDENTIFIER_REGEX.is_match(variable) { Some(format!("%{variable}%")) } else { None };
+    }
+    let literal = placeable.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::new();
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '"' => result.push('"'),
+                '\\' => result.push('\\'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => result.push(c),
+        }
+    }
+    Some(result)
+}
+
+fn placeable_end(placeable: &str) -> Option<usize> {
+    let mut in_literal = false;
+    let mut escaped = false;
+    for (i, c) in placeable.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_literal => escaped = true,
+            '"' => in_literal = !in_literal,
+            '}' if !in_literal => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_pattern(pattern: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut rest = pattern;
+    while let Some(i) = rest.find(['{', '}']) {
+        if rest[i..].starts_with('}') {
+            return None;
+        }
+        result.push_str(&rest[..i]);
+        let end = placeable_end(&rest[i..])? + i;
+        result.push_str(&parse_placeable(&rest[i + 1..end])?);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Some(result)
+}
+
+pub fn parse_fluent(content: &str) -> Result<Vec<(String, String)>, LocaleFileErr> {
+    let mut entries: Vec<(String, usize, Vec<&str>)> = vec![];
+    for (i, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with([' ', '\t']) {
+            if trimmed.starts_with(['.', '[', '*']) {
+                return Err(LocaleFileErr::Syntax(i + 1));
+            }
+            match entries.last_mut() {
+                Some((_, _, lines)) => lines.push(trimmed),
+                None => return Err(LocaleFileErr::Syntax(i + 1)),
+            }
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or(LocaleFileErr::Syntax(i + 1))?;
+        let key = key.trim();
+        if !IDENTIFIER_REGEX.is_match(key) {
+            return Err(LocaleFileErr::Syntax(i + 1));
+        }
+        let value = value.trim();
+        entries.push((key.to_string(), i + 1, if value.is_empty() { vec![] } else { vec![value] }));
+    }
+    entries
+        .into_iter()
+        .map(|(key, line, lines)| {
+            if lines.is_empty() {
+                return Err(LocaleFileErr::NotString(key));
+            }
+            parse_pattern(&lines.join("\n")).map(|message| (key, message)).ok_or(LocaleFileErr::Syntax(line))
+        })
+        .collect()
+}
+
+fn escape_fluent(message: &str) -> String {
+    let escaped = message.replace('{', "\u{0}").replace('}', "{ \"}\" }").replace('\u{0}', "{ \"{\" }");
+    PLACEHOLDER_REGEX.replace_all(&escaped, "{ $$$1 }").replace('\n', "\n    ")
+}
+
+pub fn export_fluent(locale: &Locale) -> String {
+    locale_fields(locale).into_iter().map(|(key, message)| format!("{key} = {}\n", escape_fluent(message))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::locale::Locale;
+
+    use crate::locale::{LOCALE_KEYS, locale_de_long, locale_en_long, locale_fields};
+
+    use super::super::LocaleFileErr;
+    use super::{export_fluent, parse_fluent};
+
+    #[test]
+    fn parse_fluent_messages() {
+        assert_eq!(parse_fluent(""), Ok(vec![]));
+        assert_eq!(
+            parse_fluent(
+                "### validation messages\n\n# required field\nrequired = É obrigatório\neq = Deve ser igual a { $value }\nbtwn =\n    Deve estar entre { $value_a }\n    e {$value_b} { \"{\" }ok{ \"}\" }\n"
+            ),
+            Ok(vec![
+                ("required".into(), "É obrigatório".into()),
+                ("eq".into(), "Deve ser igual a %value%".into()),
+                ("btwn".into(), "Deve estar entre %value_a%\ne %value_b% {ok}".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_fluent_invalid() {
+        assert_eq!(parse_fluent("required"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_fluent("    É obrigatório"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_fluent("-term = Araucaria"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_fluent("required = É obrigatório\n    .title = Obrigatório"), Err(LocaleFileErr::Syntax(2)));
+        assert_eq!(parse_fluent("required = É obrigatório\neq = Igual a { $value"), Err(LocaleFileErr::Syntax(2)));
+        assert_eq!(parse_fluent("eq = Igual a }"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_fluent("eq = Igual a { -term }"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_fluent("required ="), Err(LocaleFileErr::NotString("required".into())));
+    }
+
+    #[test]
+    fn export_fluent_placeables() {
+        let l = Locale { required: "Is {required}".into(), btwn: "Between %value_a%\nand %value_b%".into(), ..locale_en_long() };
+        let fluent = export_fluent(&l);
+        assert!(fluent.starts_with("required = Is { \"{\" }required{ \"}\" }\n"));
+        assert!(fluent.contains("\nbtwn = Between { $value_a }\n    and { $value_b }\n"));
+        assert!(fluent.contains("\neq = Must be equals to { $value }\n"));
+        assert_eq!(parse_fluent(&fluent).unwrap(), locale_fields(&l).map(|(key, message)| (key.to_string(), message.clone())));
+    }
+
+    #[test]
+    fn export_fluent_roundtrip() {
+        let l = locale_de_long();
+        let entries = parse_fluent(&export_fluent(&l)).unwrap();
+        assert_eq!(entries.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(), LOCALE_KEYS);
+        assert_eq!(entries, locale_fields(&l).map(|(key, message)| (key.to_string(), message.clone())));
+    }
+}
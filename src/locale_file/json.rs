@@ -0,0 +1,100 @@
+use std::fmt;
+
+use araucaria::locale::Locale;
+use serde::{
+    Deserialize, Deserializer,
+    de::{MapAccess, Visitor},
+};
+
+use crate::locale::locale_fields;
+
+use super::LocaleFileErr;
+
+struct JsonEntries(Vec<(String, serde_json::Value)>);
+
+struct JsonEntriesVisitor;
+
+impl<'de> Visitor<'de> for JsonEntriesVisitor {
+    type Value = JsonEntries;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an object of messages")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = vec![];
+        while let Some(entry) = map.next_entry::<String, serde_json::Value>()? {
+            entries.push(entry);
+        }
+        Ok(JsonEntries(entries))
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(JsonEntriesVisitor)
+    }
+}
+
+pub fn parse_json(content: &str) -> Result<Vec<(String, String)>, LocaleFileErr> {
+    let JsonEntries(entries) = serde_json::from_str(content).map_err(|e| match e.classify() {
+        serde_json::error::Category::Data => LocaleFileErr::NotObject,
+        _ => LocaleFileErr::Syntax(e.line()),
+    })?;
+    entries
+        .into_iter()
+        .map(|(key, value)| match value {
+            serde_json::Value::String(message) => Ok((key, message)),
+            _ => Err(LocaleFileErr::NotString(key)),
+        })
+        .collect()
+}
+
+pub fn export_json(locale: &Locale) -> String {
+    let obj: serde_json::Map<String, serde_json::Value> =
+        locale_fields(locale).into_iter().map(|(key, message)| (key.to_string(), serde_json::Value::String(message.clone()))).collect();
+    serde_json::to_string_pretty(&serde_json::Value::Object(obj)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::{LOCALE_KEYS, locale_pt_long};
+
+    use super::super::LocaleFileErr;
+    use super::{export_json, parse_json};
+
+    #[test]
+    fn parse_json_messages() {
+        assert_eq!(parse_json("{}"), Ok(vec![]));
+        assert_eq!(
+            parse_json(r#"{ "required": "É obrigatório", "eq": "Deve ser igual a \"%value%\"" }"#),
+            Ok(vec![("required".into(), "É obrigatório".into()), ("eq".into(), "Deve ser igual a \"%value%\"".into())])
+        );
+    }
+
+    #[test]
+    fn parse_json_duplicated_key() {
+        assert_eq!(
+            parse_json(r#"{ "required": "É obrigatório", "required": "Obrigatório" }"#),
+            Ok(vec![("required".into(), "É obrigatório".into()), ("required".into(), "Obrigatório".into())])
+        );
+    }
+
+    #[test]
+    fn parse_json_invalid() {
+        assert_eq!(parse_json(r#"{ "required": "#), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_json(r#"["required"]"#), Err(LocaleFileErr::NotObject));
+        assert_eq!(parse_json(r#""required""#), Err(LocaleFileErr::NotObject));
+        assert_eq!(parse_json(r#"{ "required": 1 }"#), Err(LocaleFileErr::NotString("required".into())));
+        assert_eq!(parse_json(r#"{ "obj": { "required": "É obrigatório" } }"#), Err(LocaleFileErr::NotString("obj".into())));
+    }
+
+    #[test]
+    fn export_json_roundtrip() {
+        let json = export_json(&locale_pt_long());
+        assert!(json.contains(r#""required": "É obrigatório""#));
+        let entries = parse_json(&json).unwrap();
+        assert_eq!(entries.len(), LOCALE_KEYS.len());
+        assert!(entries.contains(&("btwn".into(), "Deve estar entre %value_a% e %value_b%".into())));
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeSet;
+
+use araucaria::locale::Locale;
+
+use crate::locale::{LOCALE_KEYS, locale_field_mut, placeholders_match};
+
+use super::{export_fluent, export_json, export_toml, parse_fluent, parse_json, parse_toml};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LocaleFileFormat {
+    Json,
+    Toml,
+    Fluent,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LocaleFileErr {
+    Syntax(usize),
+    NotObject,
+    NotString(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LocaleFileIssue {
+    MissingKey(String),
+    UnknownKey(String),
+    DuplicatedKey(String),
+    PlaceholderMismatch(String),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LoadedLocale {
+    pub locale: Locale,
+    pub issues: Vec<LocaleFileIssue>,
+}
+
+pub fn load_locale(content: &str, format: LocaleFileFormat, base: &Locale) -> Result<LoadedLocale, LocaleFileErr> {
+    let entries = match format {
+        LocaleFileFormat::Json => parse_json(content)?,
+        LocaleFileFormat::Toml => parse_toml(content)?,
+        LocaleFileFormat::Fluent => parse_fluent(content)?,
+    };
+    let mut locale = base.clone();
+    let mut issues = vec![];
+    let mut found: BTreeSet<&str> = BTreeSet::new();
+    for (key, message) in &entries {
+        match locale_field_mut(&mut locale, key) {
+            Some(field) => {
+                if !found.insert(key) {
+                    issues.push(LocaleFileIssue::DuplicatedKey(key.clone()));
+                }
+                if !placeholders_match(key, message) {
+                    issues.push(LocaleFileIssue::PlaceholderMismatch(key.clone()));
+                }
+                *field = message.clone();
+            }
+            None => issues.push(LocaleFileIssue::UnknownKey(key.clone())),
+        }
+    }
+    issues.extend(LOCALE_KEYS.iter().filter(|key| !found.contains(*key)).map(|key| LocaleFileIssue::MissingKey(key.to_string())));
+    Ok(LoadedLocale { locale, issues })
+}
+
+pub fn export_locale(locale: &Locale, format: LocaleFileFormat) -> String {
+    match format {
+        LocaleFileFormat::Json => export_json(locale),
+        LocaleFileFormat::Toml => export_toml(locale),
+        LocaleFileFormat::Fluent => export_fluent(locale),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::locale::Locale;
+
+    use crate::locale::{
+        LOCALE_KEYS, locale_de_long, locale_en_long, locale_en_short, locale_es_long, locale_fr_long, locale_it_long, locale_pt_long, locale_pt_short,
+    };
+
+    use super::{LoadedLocale, LocaleFileErr, LocaleFileFormat, LocaleFileIssue, export_locale, load_locale};
+
+    fn missing_except(keys: &[&str]) -> Vec<LocaleFileIssue> {
+        LOCALE_KEYS.iter().filter(|key| !keys.contains(key)).map(|key| LocaleFileIssue::MissingKey(key.to_string())).collect()
+    }
+
+    #[test]
+    fn load_locale_base_fallback() {
+        let expected = LoadedLocale {
+            locale: Locale { required: "Obligatoire".into(), eq: "Doit être égal à %value%".into(), ..locale_en_long() },
+            issues: missing_except(&["required", "eq"]),
+        };
+        assert_eq!(
+            load_locale(r#"{ "required": "Obligatoire", "eq": "Doit être égal à %value%" }"#, LocaleFileFormat::Json, &locale_en_long()),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            load_locale("required = \"Obligatoire\"\neq = \"Doit être égal à %value%\"", LocaleFileFormat::Toml, &locale_en_long()),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            load_locale("required = Obligatoire\neq = Doit être égal à { $value }", LocaleFileFormat::Fluent, &locale_en_long()),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn load_locale_issues() {
+        assert_eq!(
+            load_locale(
+                "required = Obligatoire\nrequired = Requis\nrequis = Obligatoire\nbtwn = Entre { $value } et { $value_b }\ngt = Supérieur à\neq = { $valeur }",
+                LocaleFileFormat::Fluent,
+                &locale_en_long()
+            ),
+            Ok(LoadedLocale {
+                locale: Locale {
+                    required: "Requis".into(),
+                    btwn: "Entre %value% et %value_b%".into(),
+                    gt: "Supérieur à".into(),
+                    eq: "%valeur%".into(),
+                    ..locale_en_long()
+                },
+                issues: [
+                    vec![
+                        LocaleFileIssue::DuplicatedKey("required".into()),
+                        LocaleFileIssue::UnknownKey("requis".into()),
+                        LocaleFileIssue::PlaceholderMismatch("btwn".into()),
+                        LocaleFileIssue::PlaceholderMismatch("gt".into()),
+                        LocaleFileIssue::PlaceholderMismatch("eq".into()),
+                    ],
+                    missing_except(&["required", "btwn", "gt", "eq"]),
+                ]
+                .concat(),
+            })
+        );
+    }
+
+    #[test]
+    fn load_locale_json_duplicated_key() {
+        assert_eq!(
+            load_locale(r#"{ "required": "Obligatoire", "required": "Requis" }"#, LocaleFileFormat::Json, &locale_en_long()),
+            Ok(LoadedLocale {
+                locale: Locale { required: "Requis".into(), ..locale_en_long() },
+                issues: [vec![LocaleFileIssue::DuplicatedKey("required".into())], missing_except(&["required"])].concat(),
+            })
+        );
+    }
+
+    #[test]
+    fn load_locale_err() {
+        assert_eq!(load_locale("[]", LocaleFileFormat::Json, &locale_en_long()), Err(LocaleFileErr::NotObject));
+        assert_eq!(load_locale("\nrequired = 1", LocaleFileFormat::Toml, &locale_en_long()), Err(LocaleFileErr::NotString("required".into())));
+        assert_eq!(load_locale("\n\nrequired", LocaleFileFormat::Fluent, &locale_en_long()), Err(LocaleFileErr::Syntax(3)));
+    }
+
+    #[test]
+    fn export_locale_roundtrip() {
+        for l in [
+            locale_pt_long(),
+            locale_es_long(),
+            locale_en_long(),
+            locale_fr_long(),
+            locale_de_long(),
+            locale_it_long(),
+            locale_pt_short(),
+            locale_en_short(),
+        ] {
+            for format in [LocaleFileFormat::Json, LocaleFileFormat::Toml, LocaleFileFormat::Fluent] {
+                assert_eq!(
+                    load_locale(&export_locale(&l, format), format, &locale_en_long()),
+                    Ok(LoadedLocale { locale: l.clone(), issues: vec![] })
+                );
+            }
+        }
+    }
+}
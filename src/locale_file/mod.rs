@@ -0,0 +1,9 @@
+pub use fluent::{export_fluent, parse_fluent};
+pub use json::{export_json, parse_json};
+pub use load::{LoadedLocale, LocaleFileErr, LocaleFileFormat, LocaleFileIssue, export_locale, load_locale};
+pub use toml::{export_toml, parse_toml};
+
+mod fluent;
+mod json;
+mod load;
+mod toml;
@@ -0,0 +1,121 @@
+use araucaria::locale::Locale;
+
+use crate::locale::locale_fields;
+
+use super::LocaleFileErr;
+
+fn flatten_table(prefix: &str, table: ::toml::Table, result: &mut Vec<(String, String)>) -> Result<(), LocaleFileErr> {
+    for (key, value) in table {
+        let key = if prefix.is_empty() { key } else { format!("{prefix}.{key}") };
+        match value {
+            ::toml::Value::String(message) => result.push((key, message)),
+            ::toml::Value::Table(table) => flatten_table(&key, table, result)?,
+            _ => return Err(LocaleFileErr::NotString(key)),
+        }
+    }
+    Ok(())
+}
+
+pub fn parse_toml(content: &str) -> Result<Vec<(String, String)>, LocaleFileErr> {
+    let table: ::toml::Table =
+        ::toml::from_str(content).map_err(|e| LocaleFileErr::Syntax(e.span().map_or(1, |span| content[..span.start].matches('\n').count() + 1)))?;
+    let mut result = vec![];
+    flatten_table("", table, &mut result)?;
+    Ok(result)
+}
+
+fn escape_toml(message: &str) -> String {
+    let mut result = String::new();
+    for c in message.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04X}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+pub fn export_toml(locale: &Locale) -> String {
+    locale_fields(locale).into_iter().map(|(key, message)| format!("{key} = \"{}\"\n", escape_toml(message))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::{LOCALE_KEYS, locale_fields, locale_fr_long};
+
+    use super::super::LocaleFileErr;
+    use super::{export_toml, parse_toml};
+
+    #[test]
+    fn parse_toml_messages() {
+        assert_eq!(parse_toml(""), Ok(vec![]));
+        assert_eq!(
+            parse_toml(
+                r#"
+# validation messages
+required = "É obrigatório"  # inline comment
+eq = 'Deve ser igual a "%value%"'
+"ne" = "Deve ser diferente de \"%value%\" \u00e9\n"
+"#
+            ),
+            Ok(vec![
+                ("required".into(), "É obrigatório".into()),
+                ("eq".into(), "Deve ser igual a \"%value%\"".into()),
+                ("ne".into(), "Deve ser diferente de \"%value%\" é\n".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_toml_empty_and_multiline() {
+        assert_eq!(
+            parse_toml("required = \"\"\neq = ''\nne = \"\"\"\nDeve ser diferente\nde %value%\"\"\"\nlt = \'\'\'%value%\'\'\'"),
+            Ok(vec![
+                ("required".into(), "".into()),
+                ("eq".into(), "".into()),
+                ("ne".into(), "Deve ser diferente\nde %value%".into()),
+                ("lt".into(), "%value%".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_toml_tables() {
+        assert_eq!(
+            parse_toml("required = \"É obrigatório\"\n\n[messages]\neq = \"Deve ser igual a %value%\"\nsub.ne = \"x\""),
+            Ok(vec![
+                ("required".into(), "É obrigatório".into()),
+                ("messages.eq".into(), "Deve ser igual a %value%".into()),
+                ("messages.sub.ne".into(), "x".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_toml_invalid() {
+        assert_eq!(parse_toml("required = \"É obrigatório"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_toml("\n[messages"), Err(LocaleFileErr::Syntax(2)));
+        assert_eq!(parse_toml("required"), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_toml("required = \"a\" \"b\""), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_toml("required = \"\\x\""), Err(LocaleFileErr::Syntax(1)));
+        assert_eq!(parse_toml("eq = \"a\"\nrequired = \"b\"\nrequired = \"c\""), Err(LocaleFileErr::Syntax(3)));
+        assert_eq!(parse_toml("required = 1"), Err(LocaleFileErr::NotString("required".into())));
+        assert_eq!(parse_toml("required = true # comment"), Err(LocaleFileErr::NotString("required".into())));
+        assert_eq!(parse_toml("[messages]\nrequired = [\"a\"]"), Err(LocaleFileErr::NotString("messages.required".into())));
+    }
+
+    #[test]
+    fn export_toml_roundtrip() {
+        let l = locale_fr_long();
+        let toml = export_toml(&l);
+        assert!(toml.starts_with("required = \"Est obligatoire\"\n"));
+        let entries = parse_toml(&toml).unwrap();
+        assert_eq!(entries.iter().map(|(key, _)| key.as_str()).collect::<Vec<_>>(), LOCALE_KEYS);
+        assert_eq!(entries, locale_fields(&l).map(|(key, message)| (key.to_string(), message.clone())));
+    }
+}
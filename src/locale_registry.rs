@@ -0,0 +1,314 @@
+use std::collections::BTreeMap;
+
+use araucaria::locale::Locale;
+
+use crate::{
+    locale::{locale_de_long, locale_en_long, locale_es_long, locale_fr_long, locale_it_long, locale_pt_long},
+    localize::LocalizeCtx,
+    plugin_locale::{
+        PluginLocale, plugin_locale_de_long, plugin_locale_en_long, plugin_locale_es_long, plugin_locale_fr_long, plugin_locale_it_long,
+        plugin_locale_pt_long,
+    },
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LanguageRange {
+    pub tag: String,
+    pub q: f32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocaleRegistry {
+    default_tag: String,
+    locales: BTreeMap<String, Locale>,
+    plugins: BTreeMap<String, PluginLocale>,
+}
+
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_lowercase()
+}
+
+pub fn fallback_tags(tag: &str) -> Vec<String> {
+    let tag = normalize_tag(tag);
+    let mut result = vec![];
+    let mut current = tag.as_str();
+    while !current.is_empty() {
+        result.push(current.to_string());
+        match current.rfind('-') {
+            Some(i) => current = &current[..i],
+            None => break,
+        }
+    }
+    result
+}
+
+fn parse_q(value: &str) -> Option<f32> {
+    let q = value.trim().parse::<f32>().ok()?;
+    if (0.0..=1.0).contains(&q) { Some(q) } else { None }
+}
+
+pub fn parse_accept_language(header: &str) -> Vec<LanguageRange> {
+    let mut result: Vec<LanguageRange> = header
+        .split(',')
+        .filter_map(|item| {
+            let mut parts = item.split(';');
+            let tag = normalize_tag(parts.next()?);
+            if tag.is_empty() {
+                return None;
+            }
+            let q = match parts.filter_map(|param| param.split_once('=')).find(|(key, _)| key.trim().eq_ignore_ascii_case("q")) {
+                Some((_, value)) => parse_q(value)?,
+                None => 1.0,
+            };
+            Some(LanguageRange { tag, q })
+        })
+        .filter(|range| range.q > 0.0)
+        .collect();
+    result.sort_by(|a, b| b.q.total_cmp(&a.q));
+    result
+}
+
+impl LocaleRegistry {
+    pub fn new(default_tag: &str, default_locale: Locale, default_plugin: PluginLocale) -> Self {
+        let default_tag = normalize_tag(default_tag);
+        LocaleRegistry {
+            locales: BTreeMap::from([(default_tag.clone(), default_locale)]),
+            plugins: BTreeMap::from([(default_tag.clone(), default_plugin)]),
+            default_tag,
+        }
+    }
+
+    pub fn register(mut self, tag: &str, locale: Locale) -> Self {
+        self.locales.insert(normalize_tag(tag), locale);
+        self
+    }
+
+    pub fn register_plugin(mut self, tag: &str, plugin: PluginLocale) -> Self {
+        self.plugins.insert(normalize_tag(tag), plugin);
+        self
+    }
+
+    pub fn default_tag(&self) -> &str {
+        &self.default_tag
+    }
+
+    pub fn default_locale(&self) -> &Locale {
+        &self.locales[&self.default_tag]
+    }
+
+    pub fn default_plugin(&self) -> &PluginLocale {
+        &self.plugins[&self.default_tag]
+    }
+
+    pub fn get(&self, tag: &str) -> Option<&Locale> {
+        self.locales.get(&normalize_tag(tag))
+    }
+
+    pub fn resolve_tag(&self, tag: &str) -> Option<&str> {
+        fallback_tags(tag).into_iter().find_map(|tag| self.locales.get_key_value(&tag).map(|(k, _)| k.as_str()))
+    }
+
+    pub fn resolve(&self, tag: &str) -> &Locale {
+        self.resolve_tag(tag).and_then(|tag| self.locales.get(tag)).unwrap_or(self.default_locale())
+    }
+
+    pub fn negotiate_tag(&self, accept_language: &str) -> &str {
+        parse_accept_language(accept_language)
+            .into_iter()
+            .find_map(|range| if range.tag == "*" { Some(self.default_tag.as_str()) } else { self.resolve_tag(&range.tag) })
+            .unwrap_or(&self.default_tag)
+    }
+
+    pub fn negotiate(&self, accept_language: &str) -> &Locale {
+        &self.locales[self.negotiate_tag(accept_language)]
+    }
+
+    pub fn get_plugin(&self, tag: &str) -> Option<&PluginLocale> {
+        self.plugins.get(&normalize_tag(tag))
+    }
+
+    pub fn resolve_plugin(&self, tag: &str) -> &PluginLocale {
+        fallback_tags(tag).into_iter().find_map(|tag| self.plugins.get(&tag)).unwrap_or(self.default_plugin())
+    }
+
+    pub fn negotiate_plugin(&self, accept_language: &str) -> &PluginLocale {
+        self.resolve_plugin(self.negotiate_tag(accept_language))
+    }
+
+    pub fn negotiate_ctx(&self, accept_language: &str) -> LocalizeCtx<'_> {
+        let tag = self.negotiate_tag(accept_language);
+        LocalizeCtx::new(&self.locales[tag], self.resolve_plugin(tag)).tag(tag)
+    }
+}
+
+impl Default for LocaleRegistry {
+    fn default() -> Self {
+        LocaleRegistry::new("en", locale_en_long(), plugin_locale_en_long())
+            .register("pt", locale_pt_long())
+            .register_plugin("pt", plugin_locale_pt_long())
+            .register("es", locale_es_long())
+            .register_plugin("es", plugin_locale_es_long())
+            .register("fr", locale_fr_long())
+            .register_plugin("fr", plugin_locale_fr_long())
+            .register("de", locale_de_long())
+            .register_plugin("de", plugin_locale_de_long())
+            .register("it", locale_it_long())
+            .register_plugin("it", plugin_locale_it_long())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::locale::Locale;
+
+    use crate::{
+        locale::{locale_de_long, locale_en_long, locale_es_long, locale_fr_long, locale_it_long, locale_pt_long},
+        plugin_locale::{plugin_locale_de_long, plugin_locale_en_long, plugin_locale_es_long, plugin_locale_fr_long, plugin_locale_pt_long},
+    };
+
+    use super::{LanguageRange, LocaleRegistry, fallback_tags, normalize_tag, parse_accept_language};
+
+    fn custom_locale() -> Locale {
+        Locale { required: "Obligatoire".into(), ..locale_en_long() }
+    }
+
+    #[test]
+    fn normalize_tag_case_and_separator() {
+        assert_eq!(normalize_tag("pt-BR"), "pt-br".to_string());
+        assert_eq!(normalize_tag("pt_BR"), "pt-br".to_string());
+        assert_eq!(normalize_tag(" EN "), "en".to_string());
+    }
+
+    #[test]
+    fn fallback_tags_region() {
+        assert_eq!(fallback_tags("pt-BR"), vec!["pt-br".to_string(), "pt".into()]);
+        assert_eq!(fallback_tags("zh-Hant-TW"), vec!["zh-hant-tw".to_string(), "zh-hant".into(), "zh".into()]);
+        assert_eq!(fallback_tags("es"), vec!["es".to_string()]);
+        assert_eq!(fallback_tags(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn parse_accept_language_q_values() {
+        assert_eq!(
+            parse_accept_language("fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5"),
+            vec![
+                LanguageRange { tag: "fr-ch".into(), q: 1.0 },
+                LanguageRange { tag: "fr".into(), q: 0.9 },
+                LanguageRange { tag: "en".into(), q: 0.8 },
+                LanguageRange { tag: "de".into(), q: 0.7 },
+                LanguageRange { tag: "*".into(), q: 0.5 },
+            ]
+        );
+        assert_eq!(
+            parse_accept_language("en;q=0.3, pt-BR"),
+            vec![LanguageRange { tag: "pt-br".into(), q: 1.0 }, LanguageRange { tag: "en".into(), q: 0.3 }]
+        );
+    }
+
+    #[test]
+    fn parse_accept_language_invalid() {
+        assert_eq!(parse_accept_language(""), vec![]);
+        assert_eq!(parse_accept_language("pt;q=0"), vec![]);
+        assert_eq!(parse_accept_language("pt;q=abc, es;q=1.5, en;q=0.1"), vec![LanguageRange { tag: "en".into(), q: 0.1 }]);
+        assert_eq!(parse_accept_language(" , ;q=0.5"), vec![]);
+    }
+
+    #[test]
+    fn parse_accept_language_unknown_params() {
+        assert_eq!(
+            parse_accept_language("pt-BR;level=1;q=0.8, en;Q=0.5;x=y, es;level=2"),
+            vec![
+                LanguageRange { tag: "es".into(), q: 1.0 },
+                LanguageRange { tag: "pt-br".into(), q: 0.8 },
+                LanguageRange { tag: "en".into(), q: 0.5 },
+            ]
+        );
+        assert_eq!(parse_accept_language("pt;level=1;q=abc, en;flag"), vec![LanguageRange { tag: "en".into(), q: 1.0 }]);
+    }
+
+    #[test]
+    fn registry_get() {
+        let registry = LocaleRegistry::default();
+        assert_eq!(registry.get("pt"), Some(&locale_pt_long()));
+        assert_eq!(registry.get("ES"), Some(&locale_es_long()));
+        assert_eq!(registry.get("fr"), Some(&locale_fr_long()));
+        assert_eq!(registry.get("de"), Some(&locale_de_long()));
+        assert_eq!(registry.get("it"), Some(&locale_it_long()));
+        assert_eq!(registry.get("pt-BR"), None);
+        assert_eq!(registry.get("ja"), None);
+    }
+
+    #[test]
+    fn registry_resolve_region_fallback() {
+        let registry = LocaleRegistry::default();
+        assert_eq!(registry.resolve_tag("pt-BR"), Some("pt"));
+        assert_eq!(registry.resolve_tag("es-AR"), Some("es"));
+        assert_eq!(registry.resolve_tag("fr-FR"), Some("fr"));
+        assert_eq!(registry.resolve_tag("ja-JP"), None);
+        assert_eq!(registry.resolve("pt-BR"), &locale_pt_long());
+        assert_eq!(registry.resolve("es_419"), &locale_es_long());
+        assert_eq!(registry.resolve("de-AT"), &locale_de_long());
+        assert_eq!(registry.resolve("ja-JP"), &locale_en_long());
+    }
+
+    #[test]
+    fn registry_register_custom() {
+        let registry = LocaleRegistry::default().register("fr", custom_locale()).register("pt-BR", locale_es_long());
+        assert_eq!(registry.resolve("fr-CA"), &custom_locale());
+        assert_eq!(registry.resolve("it-CH"), &locale_it_long());
+        assert_eq!(registry.resolve("pt-BR"), &locale_es_long());
+        assert_eq!(registry.resolve("pt-PT"), &locale_pt_long());
+    }
+
+    #[test]
+    fn registry_custom_default() {
+        let registry = LocaleRegistry::new("pt-BR", locale_pt_long(), plugin_locale_pt_long());
+        assert_eq!(registry.default_tag(), "pt-br");
+        assert_eq!(registry.default_locale(), &locale_pt_long());
+        assert_eq!(registry.default_plugin(), &plugin_locale_pt_long());
+        assert_eq!(registry.resolve_plugin("en"), &plugin_locale_pt_long());
+        assert_eq!(registry.resolve("en"), &locale_pt_long());
+    }
+
+    #[test]
+    fn registry_negotiate() {
+        let registry = LocaleRegistry::default();
+        assert_eq!(registry.negotiate_tag("pt-BR,pt;q=0.9,en-US;q=0.8,en;q=0.7"), "pt");
+        assert_eq!(registry.negotiate_tag("fr-CH, fr;q=0.9, es;q=0.8, en;q=0.7"), "fr");
+        assert_eq!(registry.negotiate_tag("ja, es;q=0.8, en;q=0.7"), "es");
+        assert_eq!(registry.negotiate_tag("en;q=0.5, es-MX;q=0.9"), "es");
+        assert_eq!(registry.negotiate_tag("ja, *;q=0.1"), "en");
+        assert_eq!(registry.negotiate_tag("ja, ko"), "en");
+        assert_eq!(registry.negotiate_tag(""), "en");
+        assert_eq!(registry.negotiate("pt-BR,pt;q=0.9"), &locale_pt_long());
+        assert_eq!(registry.negotiate("es;q=0, pt;q=0.2"), &locale_pt_long());
+    }
+
+    #[test]
+    fn registry_plugin() {
+        let registry = LocaleRegistry::default();
+        assert_eq!(registry.get_plugin("pt"), Some(&plugin_locale_pt_long()));
+        assert_eq!(registry.get_plugin("pt-BR"), None);
+        assert_eq!(registry.resolve_plugin("pt-BR"), &plugin_locale_pt_long());
+        assert_eq!(registry.resolve_plugin("de-AT"), &plugin_locale_de_long());
+        assert_eq!(registry.resolve_plugin("ja-JP"), &plugin_locale_en_long());
+        assert_eq!(registry.negotiate_plugin("fr-CH, fr;q=0.9"), &plugin_locale_fr_long());
+        let registry = LocaleRegistry::default().register("pt-BR", locale_es_long());
+        assert_eq!(registry.resolve_plugin("pt-BR"), &plugin_locale_pt_long());
+        let registry = LocaleRegistry::default().register_plugin("pt-BR", plugin_locale_es_long());
+        assert_eq!(registry.resolve_plugin("pt-BR"), &plugin_locale_es_long());
+    }
+
+    #[test]
+    fn registry_negotiate_ctx() {
+        let registry = LocaleRegistry::default();
+        let ctx = registry.negotiate_ctx("es-AR, en;q=0.5");
+        assert_eq!(ctx.locale, &locale_es_long());
+        assert_eq!(ctx.plugin, &plugin_locale_es_long());
+        assert_eq!(ctx.tag, Some("es"));
+        let registry = LocaleRegistry::default().register("pt-BR", locale_es_long());
+        let ctx = registry.negotiate_ctx("pt-BR");
+        assert_eq!(ctx.locale, &locale_es_long());
+        assert_eq!(ctx.plugin, &plugin_locale_pt_long());
+    }
+}
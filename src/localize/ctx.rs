@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use araucaria::locale::Locale;
+
+use crate::plugin_locale::PluginLocale;
+
+use super::{LocaleFormat, MessageOverrides, PluralLocale};
+
+pub type FieldLabels = BTreeMap<String, String>;
+
+pub type CustomMessages = BTreeMap<String, String>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocalizeCtx<'a> {
+    pub locale: &'a Locale,
+    pub labels: Option<&'a FieldLabels>,
+    pub format: Option<&'a LocaleFormat>,
+    pub plural: Option<&'a PluralLocale>,
+    pub overrides: Option<&'a MessageOverrides>,
+    pub tag: Option<&'a str>,
+    pub plugin: &'a PluginLocale,
+    pub custom: Option<&'a CustomMessages>,
+}
+
+pub(super) fn wildcard_path(path: &str) -> String {
+    path.split('.')
+        .map(|segment| if !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit()) { "*" } else { segment })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+impl<'a> LocalizeCtx<'a> {
+    pub fn new(locale: &'a Locale, plugin: &'a PluginLocale) -> Self {
+        LocalizeCtx { locale, plugin, labels: None, format: None, plural: None, overrides: None, tag: None, custom: None }
+    }
+
+    pub fn labels(mut self, labels: &'a FieldLabels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    pub fn format(mut self, format: &'a LocaleFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn plural(mut self, plural: &'a PluralLocale) -> Self {
+        self.plural = Some(plural);
+        self
+    }
+
+    pub fn overrides(mut self, overrides: &'a MessageOverrides) -> Self {
+        self.overrides = Some(overrides);
+        self
+    }
+
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    pub fn custom(mut self, custom: &'a CustomMessages) -> Self {
+        self.custom = Some(custom);
+        self
+    }
+
+    pub fn label(&self, path: &str) -> Option<&'a str> {
+        let labels = self.labels?;
+        labels.get(path).or_else(|| labels.get(&wildcard_path(path))).map(|label| label.as_str())
+    }
+
+    pub fn field_name(&self, path: &str) -> String {
+        self.label(path).map(|label| label.to_string()).unwrap_or(path.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use crate::{locale::locale_en_long, plugin_locale::plugin_locale_en_long};
+
+    use super::{FieldLabels, LocalizeCtx};
+
+    static LABELS: LazyLock<FieldLabels> = LazyLock::new(|| {
+        FieldLabels::from([
+            ("name".into(), "Name".into()),
+            ("period.start".into(), "Start date".into()),
+            ("items.*.price".into(), "Price".into()),
+            ("items.0.price".into(), "First price".into()),
+        ])
+    });
+
+    #[test]
+    fn localize_ctx_label() {
+        let l = locale_en_long();
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&LABELS);
+        assert_eq!(ctx.label("name"), Some("Name"));
+        assert_eq!(ctx.label("period.start"), Some("Start date"));
+        assert_eq!(ctx.label("items.0.price"), Some("First price"));
+        assert_eq!(ctx.label("items.3.price"), Some("Price"));
+        assert_eq!(ctx.label("items.3.name"), None);
+        assert_eq!(ctx.label("period.end"), None);
+        assert_eq!(LocalizeCtx::new(&l, &plugin).label("name"), None);
+    }
+
+    #[test]
+    fn localize_ctx_field_name() {
+        let l = locale_en_long();
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&LABELS);
+        assert_eq!(ctx.field_name("period.start"), "Start date".to_string());
+        assert_eq!(ctx.field_name("items.12.price"), "Price".to_string());
+        assert_eq!(ctx.field_name("period.end"), "period.end".to_string());
+        assert_eq!(LocalizeCtx::new(&l, &plugin).field_name("name"), "name".to_string());
+    }
+}
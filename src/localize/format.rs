@@ -0,0 +1,311 @@
+use chrono::{Datelike, Timelike};
+
+use crate::{
+    rule::Precision,
+    serialize::OperandValue,
+    utils::{
+        date::{Dt, parse_date},
+        date_time::{DtTm, parse_date_time},
+        time::{Tm, parse_time},
+    },
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocaleFormat {
+    pub decimal_separator: String,
+    pub grouping_separator: String,
+    pub date_order: DateOrder,
+    pub date_separator: String,
+    pub hour_12: bool,
+    pub date_time_separator: String,
+    pub list_separator: String,
+    pub list_pair_separator: String,
+    pub list_last_separator: String,
+}
+
+pub fn locale_format_pt() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ",".into(),
+        grouping_separator: ".".into(),
+        date_order: DateOrder::DayMonthYear,
+        date_separator: "/".into(),
+        hour_12: false,
+        date_time_separator: " ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " ou ".into(),
+        list_last_separator: " ou ".into(),
+    }
+}
+
+pub fn locale_format_es() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ",".into(),
+        grouping_separator: ".".into(),
+        date_order: DateOrder::DayMonthYear,
+        date_separator: "/".into(),
+        hour_12: false,
+        date_time_separator: ", ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " o ".into(),
+        list_last_separator: " o ".into(),
+    }
+}
+
+pub fn locale_format_en() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ".".into(),
+        grouping_separator: ",".into(),
+        date_order: DateOrder::MonthDayYear,
+        date_separator: "/".into(),
+        hour_12: true,
+        date_time_separator: ", ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " or ".into(),
+        list_last_separator: ", or ".into(),
+    }
+}
+
+pub fn locale_format_fr() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ",".into(),
+        grouping_separator: "\u{202f}".into(),
+        date_order: DateOrder::DayMonthYear,
+        date_separator: "/".into(),
+        hour_12: false,
+        date_time_separator: " ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " ou ".into(),
+        list_last_separator: " ou ".into(),
+    }
+}
+
+pub fn locale_format_de() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ",".into(),
+        grouping_separator: ".".into(),
+        date_order: DateOrder::DayMonthYear,
+        date_separator: ".".into(),
+        hour_12: false,
+        date_time_separator: ", ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " oder ".into(),
+        list_last_separator: " oder ".into(),
+    }
+}
+
+pub fn locale_format_it() -> LocaleFormat {
+    LocaleFormat {
+        decimal_separator: ",".into(),
+        grouping_separator: ".".into(),
+        date_order: DateOrder::DayMonthYear,
+        date_separator: "/".into(),
+        hour_12: false,
+        date_time_separator: ", ".into(),
+        list_separator: ", ".into(),
+        list_pair_separator: " o ".into(),
+        list_last_separator: " o ".into(),
+    }
+}
+
+fn group_digits(digits: &str, format: &LocaleFormat) -> String {
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push_str(&format.grouping_separator);
+        }
+        result.push(c);
+    }
+    result
+}
+
+pub fn format_number(number: &str, format: &LocaleFormat) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", number),
+    };
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+        return number.to_string();
+    }
+    match unsigned.split_once('.') {
+        Some((integer, fraction)) => format!("{sign}{}{}{fraction}", group_digits(integer, format), format.decimal_separator),
+        None => format!("{sign}{}", group_digits(unsigned, format)),
+    }
+}
+
+pub fn format_date(date: &Dt, format: &LocaleFormat) -> String {
+    let (yyyy, mm, dd) = (date.0.year(), date.0.month(), date.0.day());
+    let sep = &format.date_separator;
+    match format.date_order {
+        DateOrder::DayMonthYear => format!("{dd:02}{sep}{mm:02}{sep}{yyyy:04}"),
+        DateOrder::MonthDayYear => format!("{mm:02}{sep}{dd:02}{sep}{yyyy:04}"),
+        DateOrder::YearMonthDay => format!("{yyyy:04}{sep}{mm:02}{sep}{dd:02}"),
+    }
+}
+
+pub fn format_time(time: &Tm, format: &LocaleFormat) -> String {
+    let (h, m) = (time.time.hour(), time.time.minute());
+    let seconds = match time.precision {
+        Precision::Minutes => String::new(),
+        Precision::Seconds => format!(":{:02}", time.time.second()),
+        Precision::Millis => format!(":{:02}.{:03}", time.time.second(), time.time.nanosecond() / 1_000_000),
+        Precision::Nanos => {
+            let nanos = format!("{:09}", time.time.nanosecond());
+            format!(":{:02}.{}", time.time.second(), &nanos[..nanos.trim_end_matches('0').len().max(4)])
+        }
+    };
+    if format.hour_12 {
+        let period = if h < 12 { "AM" } else { "PM" };
+        let h = if h % 12 == 0 { 12 } else { h % 12 };
+        format!("{h}:{m:02}{seconds} {period}")
+    } else {
+        format!("{h:02}:{m:02}{seconds}")
+    }
+}
+
+pub fn format_date_time(date_time: &DtTm, format: &LocaleFormat) -> String {
+    let local = date_time.local;
+    let date = format_date(&Dt(local.date()), format);
+    let time = format_time(&Tm { time: local.time(), precision: date_time.precision }, format);
+    let offset = match date_time.offset.map(|offset| offset.local_minus_utc()) {
+        None | Some(0) => String::new(),
+        Some(seconds) => format!("{}{:02}:{:02}", if seconds < 0 { '-' } else { '+' }, seconds.abs() / 3600, seconds.abs() % 3600 / 60),
+    };
+    format!("{date}{}{time} UTC{offset}", format.date_time_separator)
+}
+
+pub fn format_list(values: Vec<String>, format: &LocaleFormat) -> String {
+    match values.as_slice() {
+        [] => String::new(),
+        [value] => value.clone(),
+        [a, b] => format!("{a}{}{b}", format.list_pair_separator),
+        [init @ .., last] => format!("{}{}{last}", init.join(&format.list_separator), format.list_last_separator),
+    }
+}
+
+pub fn format_temporal(value: &str, format: &LocaleFormat) -> Option<String> {
+    if let Ok(date_time) = parse_date_time(value) {
+        Some(format_date_time(&date_time, format))
+    } else if let Ok(date) = parse_date(value) {
+        Some(format_date(&date, format))
+    } else {
+        parse_time(value).ok().map(|time| format_time(&time, format))
+    }
+}
+
+pub fn format_operand_value(value: &OperandValue, format: &LocaleFormat) -> String {
+    match value {
+        OperandValue::U64(value) => format_number(&value.to_string(), format),
+        OperandValue::I64(value) => format_number(&value.to_string(), format),
+        OperandValue::F64(value) => format_number(&value.to_string(), format),
+        OperandValue::USize(value) => format_number(&value.to_string(), format),
+        OperandValue::ISize(value) => format_number(&value.to_string(), format),
+        OperandValue::Bool(value) => value.to_string(),
+        OperandValue::Str(value) => format_temporal(value, format).unwrap_or(format!("\"{value}\"")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        serialize::OperandValue,
+        utils::{date::parse_date, date_time::parse_date_time, time::parse_time},
+    };
+
+    use super::{
+        format_date, format_date_time, format_list, format_number, format_operand_value, format_temporal, format_time, locale_format_de,
+        locale_format_en, locale_format_es, locale_format_fr, locale_format_it, locale_format_pt,
+    };
+
+    #[test]
+    fn format_number_separators() {
+        assert_eq!(format_number("0", &locale_format_pt()), "0".to_string());
+        assert_eq!(format_number("123", &locale_format_pt()), "123".to_string());
+        assert_eq!(format_number("1234.5", &locale_format_pt()), "1.234,5".to_string());
+        assert_eq!(format_number("1234.5", &locale_format_en()), "1,234.5".to_string());
+        assert_eq!(format_number("-1234567", &locale_format_es()), "-1.234.567".to_string());
+        assert_eq!(format_number("-1234567.25", &locale_format_en()), "-1,234,567.25".to_string());
+        assert_eq!(format_number("1234567", &locale_format_fr()), "1\u{202f}234\u{202f}567".to_string());
+        assert_eq!(format_number("123456.789", &locale_format_de()), "123.456,789".to_string());
+        assert_eq!(format_number("-0.5", &locale_format_it()), "-0,5".to_string());
+        assert_eq!(format_number("NaN", &locale_format_pt()), "NaN".to_string());
+        assert_eq!(format_number("-inf", &locale_format_pt()), "-inf".to_string());
+    }
+
+    #[test]
+    fn format_date_order() {
+        assert_eq!(format_date(&parse_date("2026-10-07").unwrap(), &locale_format_pt()), "07/10/2026".to_string());
+        assert_eq!(format_date(&parse_date("2026-10-07").unwrap(), &locale_format_en()), "10/07/2026".to_string());
+        assert_eq!(format_date(&parse_date("2026-10-07").unwrap(), &locale_format_de()), "07.10.2026".to_string());
+    }
+
+    #[test]
+    fn format_time_hour_cycle() {
+        assert_eq!(format_time(&parse_time("14:05").unwrap(), &locale_format_pt()), "14:05".to_string());
+        assert_eq!(format_time(&parse_time("06:30").unwrap(), &locale_format_es()), "06:30".to_string());
+        assert_eq!(format_time(&parse_time("14:05").unwrap(), &locale_format_en()), "2:05 PM".to_string());
+        assert_eq!(format_time(&parse_time("00:00").unwrap(), &locale_format_en()), "12:00 AM".to_string());
+        assert_eq!(format_time(&parse_time("12:00").unwrap(), &locale_format_en()), "12:00 PM".to_string());
+        assert_eq!(format_time(&parse_time("11:59").unwrap(), &locale_format_en()), "11:59 AM".to_string());
+        assert_eq!(format_time(&parse_time("08:30:05").unwrap(), &locale_format_pt()), "08:30:05".to_string());
+        assert_eq!(format_time(&parse_time("20:30:05.250").unwrap(), &locale_format_en()), "8:30:05.250 PM".to_string());
+        assert_eq!(format_time(&parse_time("08:30:05.25").unwrap(), &locale_format_de()), "08:30:05.250".to_string());
+        assert_eq!(format_time(&parse_time("08:30:05.123456").unwrap(), &locale_format_pt()), "08:30:05.123456".to_string());
+        assert_eq!(format_time(&parse_time("08:30:05.0000").unwrap(), &locale_format_pt()), "08:30:05.0000".to_string());
+    }
+
+    #[test]
+    fn format_date_time_utc() {
+        assert_eq!(format_date_time(&parse_date_time("2026-10-17T14:30Z").unwrap(), &locale_format_pt()), "17/10/2026 14:30 UTC".to_string());
+        assert_eq!(format_date_time(&parse_date_time("2026-10-17T14:30Z").unwrap(), &locale_format_en()), "10/17/2026, 2:30 PM UTC".to_string());
+        assert_eq!(format_date_time(&parse_date_time("2026-10-17T14:30Z").unwrap(), &locale_format_de()), "17.10.2026, 14:30 UTC".to_string());
+        assert_eq!(
+            format_date_time(&parse_date_time("2026-10-17T14:30:15-03:00").unwrap(), &locale_format_pt()),
+            "17/10/2026 14:30:15 UTC-03:00".to_string()
+        );
+        assert_eq!(
+            format_date_time(&parse_date_time("2026-10-17T14:30+05:30").unwrap(), &locale_format_en()),
+            "10/17/2026, 2:30 PM UTC+05:30".to_string()
+        );
+    }
+
+    #[test]
+    fn format_list_conjunction() {
+        assert_eq!(format_list(vec![], &locale_format_pt()), "".to_string());
+        assert_eq!(format_list(vec!["UNIX".into()], &locale_format_pt()), "UNIX".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into()], &locale_format_pt()), "UNIX ou LINUX".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()], &locale_format_pt()), "UNIX, LINUX ou FREEBSD".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()], &locale_format_es()), "UNIX, LINUX o FREEBSD".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into()], &locale_format_en()), "UNIX or LINUX".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()], &locale_format_en()), "UNIX, LINUX, or FREEBSD".to_string());
+        assert_eq!(format_list(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()], &locale_format_de()), "UNIX, LINUX oder FREEBSD".to_string());
+    }
+
+    #[test]
+    fn format_temporal_values() {
+        assert_eq!(format_temporal("2026-10-17", &locale_format_pt()), Some("17/10/2026".into()));
+        assert_eq!(format_temporal("2026-10-17", &locale_format_en()), Some("10/17/2026".into()));
+        assert_eq!(format_temporal("18:45", &locale_format_en()), Some("6:45 PM".into()));
+        assert_eq!(format_temporal("2026-10-17T18:45Z", &locale_format_it()), Some("17/10/2026, 18:45 UTC".into()));
+        assert_eq!(format_temporal("2026-02-30", &locale_format_pt()), None);
+        assert_eq!(format_temporal("Paul McCartney", &locale_format_pt()), None);
+    }
+
+    #[test]
+    fn format_operand_value_locale() {
+        assert_eq!(format_operand_value(&OperandValue::U64(1234567), &locale_format_pt()), "1.234.567".to_string());
+        assert_eq!(format_operand_value(&OperandValue::I64(-1234), &locale_format_en()), "-1,234".to_string());
+        assert_eq!(format_operand_value(&OperandValue::F64(1234.5), &locale_format_pt()), "1.234,5".to_string());
+        assert_eq!(format_operand_value(&OperandValue::USize(1000), &locale_format_es()), "1.000".to_string());
+        assert_eq!(format_operand_value(&OperandValue::ISize(-1000), &locale_format_de()), "-1.000".to_string());
+        assert_eq!(format_operand_value(&OperandValue::Bool(true), &locale_format_pt()), "true".to_string());
+        assert_eq!(format_operand_value(&OperandValue::Str("2026-10-17".into()), &locale_format_pt()), "17/10/2026".to_string());
+        assert_eq!(format_operand_value(&OperandValue::Str("Paul".into()), &locale_format_pt()), "\"Paul\"".to_string());
+    }
+}
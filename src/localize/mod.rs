@@ -0,0 +1,21 @@
+pub use ctx::{CustomMessages, FieldLabels, LocalizeCtx};
+pub use format::{
+    DateOrder, LocaleFormat, format_date, format_date_time, format_list, format_number, format_operand_value, format_temporal, format_time,
+    locale_format_de, locale_format_en, locale_format_es, locale_format_fr, locale_format_it, locale_format_pt,
+};
+pub use overrides::{MessageOverride, MessageOverrides};
+pub use parse::{normalize_local_date, normalize_local_date_time, normalize_local_time};
+pub use plural::{
+    PluralCategory, PluralLocale, PluralRule, locale_plural_en_short, locale_plural_es_short, locale_plural_pt_short, plural_category,
+    plural_range_category, validation_err_plural_category,
+};
+pub use schema_err::localize_schema_err;
+pub use validation_err::{localize_validation_err, validation_err_key};
+
+mod ctx;
+mod format;
+mod overrides;
+mod parse;
+mod plural;
+mod schema_err;
+mod validation_err;
@@ -0,0 +1,128 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::locale_registry::{fallback_tags, normalize_tag};
+
+use super::ctx::wildcard_path;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MessageOverride {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub locales: BTreeMap<String, String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MessageOverrides(pub BTreeMap<String, BTreeMap<String, MessageOverride>>);
+
+impl MessageOverride {
+    pub fn locale(mut self, tag: &str, message: &str) -> Self {
+        self.locales.insert(normalize_tag(tag), message.into());
+        self
+    }
+
+    pub fn message(&self, tag: Option<&str>) -> &String {
+        tag.map(fallback_tags).unwrap_or_default().iter().find_map(|tag| self.locales.get(tag)).unwrap_or(&self.message)
+    }
+}
+
+impl From<&str> for MessageOverride {
+    fn from(message: &str) -> Self {
+        MessageOverride { message: message.into(), locales: BTreeMap::new() }
+    }
+}
+
+impl<const N: usize> From<[(String, String, MessageOverride); N]> for MessageOverrides {
+    fn from(overrides: [(String, String, MessageOverride); N]) -> Self {
+        overrides.into_iter().fold(MessageOverrides::default(), |result, (path, key, message)| result.add(&path, &key, message))
+    }
+}
+
+impl MessageOverrides {
+    pub fn add(mut self, path: &str, key: &str, message: MessageOverride) -> Self {
+        self.0.entry(path.into()).or_default().insert(key.into(), message);
+        self
+    }
+
+    pub fn get(&self, path: &str, key: &str) -> Option<&MessageOverride> {
+        self.0.get(path).and_then(|messages| messages.get(key)).or_else(|| self.0.get(&wildcard_path(path)).and_then(|messages| messages.get(key)))
+    }
+
+    pub fn message(&self, path: &str, key: &str, tag: Option<&str>) -> Option<&String> {
+        self.get(path, key).map(|message| message.message(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use super::{MessageOverride, MessageOverrides};
+
+    static OVERRIDES: LazyLock<MessageOverrides> = LazyLock::new(|| {
+        MessageOverrides::from([
+            (
+                "password".into(),
+                "symbols_ge".into(),
+                MessageOverride::from("Your password needs at least %value% symbols, like ! or #")
+                    .locale("pt", "Sua senha precisa de pelo menos %value% símbolos, como ! ou #")
+                    .locale("pt_PT", "A sua palavra-passe precisa de pelo menos %value% símbolos"),
+            ),
+            ("password".into(), "required".into(), MessageOverride::from("Choose a password")),
+            ("items.*.name".into(), "required".into(), MessageOverride::from("Every item needs a name")),
+        ])
+    });
+
+    #[test]
+    fn message_override_locale() {
+        let message = &OVERRIDES.get("password", "symbols_ge").unwrap();
+        assert_eq!(message.message(None), "Your password needs at least %value% symbols, like ! or #");
+        assert_eq!(message.message(Some("pt")), "Sua senha precisa de pelo menos %value% símbolos, como ! ou #");
+        assert_eq!(message.message(Some("pt-BR")), "Sua senha precisa de pelo menos %value% símbolos, como ! ou #");
+        assert_eq!(message.message(Some("PT-pt")), "A sua palavra-passe precisa de pelo menos %value% símbolos");
+        assert_eq!(message.message(Some("es")), "Your password needs at least %value% symbols, like ! or #");
+    }
+
+    #[test]
+    fn message_overrides_get() {
+        assert_eq!(OVERRIDES.message("password", "required", Some("pt")), Some(&"Choose a password".to_string()));
+        assert_eq!(OVERRIDES.message("password", "symbols_gt", None), None);
+        assert_eq!(OVERRIDES.message("username", "required", None), None);
+        assert_eq!(OVERRIDES.message("items.3.name", "required", None), Some(&"Every item needs a name".to_string()));
+        assert_eq!(OVERRIDES.message("items.name", "required", None), None);
+    }
+
+    #[test]
+    fn message_overrides_builder() {
+        let overrides = MessageOverrides::default()
+            .add("password", "required", MessageOverride::from("Choose a password"))
+            .add("password", "symbols_ge", MessageOverride::from("At least %value% symbols"))
+            .add("password", "symbols_ge", MessageOverride::from("Your password needs at least %value% symbols"));
+        assert_eq!(
+            overrides,
+            MessageOverrides(BTreeMap::from([(
+                "password".into(),
+                BTreeMap::from([
+                    ("required".into(), MessageOverride::from("Choose a password")),
+                    ("symbols_ge".into(), MessageOverride::from("Your password needs at least %value% symbols")),
+                ])
+            )]))
+        );
+    }
+
+    #[test]
+    fn message_overrides_serde() {
+        let json = serde_json::to_string(&*OVERRIDES).unwrap();
+        assert_eq!(
+            json,
+            r#"{"items.*.name":{"required":{"message":"Every item needs a name"}},"password":{"required":{"message":"Choose a password"},"symbols_ge":{"message":"Your password needs at least %value% symbols, like ! or #","locales":{"pt":"Sua senha precisa de pelo menos %value% símbolos, como ! ou #","pt-pt":"A sua palavra-passe precisa de pelo menos %value% símbolos"}}}}"#
+        );
+        assert_eq!(serde_json::from_str::<MessageOverrides>(&json).unwrap(), *OVERRIDES);
+        assert_eq!(
+            serde_json::from_str::<MessageOverrides>(r#"{ "name": { "required": { "message": "Tell us your name" } } }"#).unwrap(),
+            MessageOverrides::default().add("name", "required", MessageOverride::from("Tell us your name"))
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::sync::LazyLock;
+
+use chrono::{NaiveDate, Timelike};
+use regex::Regex;
+
+use crate::{
+    rule::Precision,
+    utils::{
+        date::parse_date,
+        date_time::parse_date_time,
+        time::{parse_time, time_from_parts},
+    },
+};
+
+use super::{DateOrder, LocaleFormat};
+
+static LOCAL_DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]{1,4})([/.-])([0-9]{1,2})([/.-])([0-9]{1,4})$").unwrap());
+
+static LOCAL_TIME_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([0-9]{1,2})[:hH]([0-9]{2})(?::([0-9]{2}))?(?:\s*([AaPp])\.?[Mm]\.?)?$").unwrap());
+
+static LOCAL_DATE_TIME_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([0-9/.-]+)(?:[Tt]|,?\s+)(.+?)\s*([Zz]|[+-][0-9]{2}:[0-9]{2})$").unwrap());
+
+pub fn normalize_local_date(value: &str, format: &LocaleFormat) -> Option<String> {
+    let value = value.trim();
+    if parse_date(value).is_ok() {
+        return Some(value.to_string());
+    }
+    let caps = LOCAL_DATE_REGEX.captures(value)?;
+    if caps[2] != caps[4] {
+        return None;
+    }
+    let (a, b, c) = (&caps[1], &caps[3], &caps[5]);
+    let (yyyy, mm, dd) = match (a.len(), c.len(), format.date_order) {
+        (4, _, _) if b.len() <= 2 && c.len() <= 2 => (a, b, c),
+        (1 | 2, 4, DateOrder::DayMonthYear) => (c, b, a),
+        (1 | 2, 4, DateOrder::MonthDayYear) => (c, a, b),
+        _ => return None,
+    };
+    let date = NaiveDate::from_ymd_opt(yyyy.parse().ok()?, mm.parse().ok()?, dd.parse().ok()?)?;
+    Some(date.to_string())
+}
+
+/// A two-digit hour (`09:30`, `12:00`) is read as ISO 24-hour time in every locale. In a 12-hour
+/// locale a one-digit hour without AM/PM (`9:30`) is ambiguous and is rejected.
+pub fn normalize_local_time(value: &str, format: &LocaleFormat) -> Option<String> {
+    let value = value.trim();
+    if parse_time(value).is_ok() {
+        return Some(value.to_string());
+    }
+    let caps = LOCAL_TIME_REGEX.captures(value)?;
+    let h = caps[1].parse::<u32>().ok()?;
+    let h = match caps.get(4).map(|m| m.as_str().to_ascii_lowercase()) {
+        Some(period) if (1..=12).contains(&h) => h % 12 + if period == "p" { 12 } else { 0 },
+        Some(_) => return None,
+        None if format.hour_12 && caps[1].len() == 1 && h > 0 => return None,
+        None => h,
+    };
+    let time = time_from_parts(&h.to_string(), &caps[2], caps.get(3).map(|m| m.as_str()), None).ok()?;
+    Some(match time.precision {
+        Precision::Minutes => format!("{:02}:{:02}", time.time.hour(), time.time.minute()),
+        _ => format!("{:02}:{:02}:{:02}", time.time.hour(), time.time.minute(), time.time.second()),
+    })
+}
+
+pub fn normalize_local_date_time(value: &str, format: &LocaleFormat) -> Option<String> {
+    let value = value.trim();
+    if parse_date_time(value).is_ok() {
+        return Some(value.to_string());
+    }
+    let caps = LOCAL_DATE_TIME_REGEX.captures(value)?;
+    let date = normalize_local_date(&caps[1], format)?;
+    let time = normalize_local_time(&caps[2], format)?;
+    Some(format!("{date}T{time}{}", caps[3].to_ascii_uppercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{locale_format_de, locale_format_en, locale_format_pt};
+    use super::{normalize_local_date, normalize_local_date_time, normalize_local_time};
+
+    #[test]
+    fn normalize_local_date_day_month_year() {
+        let f = locale_format_pt();
+        assert_eq!(normalize_local_date("17/10/2026", &f), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("7/1/2026", &f), Some("2026-01-07".into()));
+        assert_eq!(normalize_local_date(" 17-10-2026 ", &f), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("17.10.2026", &locale_format_de()), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("2026-10-17", &f), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("2026/10/17", &f), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("10/17/2026", &f), None);
+        assert_eq!(normalize_local_date("31/02/2026", &f), None);
+    }
+
+    #[test]
+    fn normalize_local_date_month_day_year() {
+        let f = locale_format_en();
+        assert_eq!(normalize_local_date("10/17/2026", &f), Some("2026-10-17".into()));
+        assert_eq!(normalize_local_date("1/7/2026", &f), Some("2026-01-07".into()));
+        assert_eq!(normalize_local_date("17/10/2026", &f), None);
+    }
+
+    #[test]
+    fn normalize_local_date_ambiguous() {
+        let f = locale_format_pt();
+        assert_eq!(normalize_local_date("17/10/26", &f), None);
+        assert_eq!(normalize_local_date("17/10-2026", &f), None);
+        assert_eq!(normalize_local_date("17/10/202", &f), None);
+        assert_eq!(normalize_local_date("2026/10/2026", &f), None);
+        assert_eq!(normalize_local_date("17102026", &f), None);
+        assert_eq!(normalize_local_date("17 out 2026", &f), None);
+    }
+
+    #[test]
+    fn normalize_local_time_24h() {
+        let f = locale_format_pt();
+        assert_eq!(normalize_local_time("08:30", &f), Some("08:30".into()));
+        assert_eq!(normalize_local_time("8:30", &f), Some("08:30".into()));
+        assert_eq!(normalize_local_time("21h30", &f), Some("21:30".into()));
+        assert_eq!(normalize_local_time("9:30:15", &f), Some("09:30:15".into()));
+        assert_eq!(normalize_local_time("9:30 PM", &f), Some("21:30".into()));
+        assert_eq!(normalize_local_time("24:00", &f), None);
+        assert_eq!(normalize_local_time("9h", &f), None);
+    }
+
+    #[test]
+    fn normalize_local_time_12h() {
+        let f = locale_format_en();
+        assert_eq!(normalize_local_time("9:30 PM", &f), Some("21:30".into()));
+        assert_eq!(normalize_local_time("9:30pm", &f), Some("21:30".into()));
+        assert_eq!(normalize_local_time("12:05 a.m.", &f), Some("00:05".into()));
+        assert_eq!(normalize_local_time("12:05 PM", &f), Some("12:05".into()));
+        assert_eq!(normalize_local_time("7:05:09 AM", &f), Some("07:05:09".into()));
+        assert_eq!(normalize_local_time("12:00 PM", &f), Some("12:00".into()));
+        assert_eq!(normalize_local_time("0:30", &f), Some("00:30".into()));
+        assert_eq!(normalize_local_time("13:30", &f), Some("13:30".into()));
+        assert_eq!(normalize_local_time("9:30", &f), None);
+        assert_eq!(normalize_local_time("1:00", &f), None);
+        assert_eq!(normalize_local_time("12:00", &f), Some("12:00".into()));
+        assert_eq!(normalize_local_time("09:30", &f), Some("09:30".into()));
+        assert_eq!(normalize_local_time("01:00", &f), Some("01:00".into()));
+        assert_eq!(normalize_local_time("21:30", &f), Some("21:30".into()));
+        assert_eq!(normalize_local_time("10h30", &f), Some("10:30".into()));
+        assert_eq!(normalize_local_time("9h30", &f), None);
+        assert_eq!(normalize_local_time("13:30 PM", &f), None);
+        assert_eq!(normalize_local_time("0:30 AM", &f), None);
+    }
+
+    #[test]
+    fn normalize_local_date_time_offset() {
+        let f = locale_format_pt();
+        assert_eq!(normalize_local_date_time("17/10/2026 21:30 -03:00", &f), Some("2026-10-17T21:30-03:00".into()));
+        assert_eq!(normalize_local_date_time("17/10/2026T8h30:15z", &f), Some("2026-10-17T08:30:15Z".into()));
+        assert_eq!(normalize_local_date_time("2026-10-17T21:30Z", &f), Some("2026-10-17T21:30Z".into()));
+        let f = locale_format_en();
+        assert_eq!(normalize_local_date_time("10/17/2026, 9:30 PM Z", &f), Some("2026-10-17T21:30Z".into()));
+        assert_eq!(normalize_local_date_time("10/17/2026 9:30 PM+01:00", &f), Some("2026-10-17T21:30+01:00".into()));
+    }
+
+    #[test]
+    fn normalize_local_date_time_invalid() {
+        let f = locale_format_en();
+        assert_eq!(normalize_local_date_time("10/17/2026 9:30 PM", &f), None);
+        assert_eq!(normalize_local_date_time("2026-10-17T21:30", &f), None);
+        assert_eq!(normalize_local_date_time("10/17/2026 9:30 Z", &f), None);
+        assert_eq!(normalize_local_date_time("17/10/2026 21:30 Z", &f), None);
+        assert_eq!(normalize_local_date_time("10/17/2026", &f), None);
+    }
+}
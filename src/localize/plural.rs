@@ -0,0 +1,335 @@
+use std::collections::BTreeMap;
+
+use crate::serialize::{Operand, OperandValue, Operation, ValidationErr};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PluralRule {
+    Pt,
+    Es,
+    En,
+    Fr,
+    De,
+    It,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PluralLocale {
+    pub rule: PluralRule,
+    pub messages: BTreeMap<String, BTreeMap<PluralCategory, String>>,
+}
+
+pub fn plural_category(rule: PluralRule, n: u64) -> PluralCategory {
+    let million = n != 0 && n.is_multiple_of(1_000_000);
+    match rule {
+        PluralRule::Pt | PluralRule::Fr if n <= 1 => PluralCategory::One,
+        PluralRule::Es | PluralRule::En | PluralRule::De | PluralRule::It if n == 1 => PluralCategory::One,
+        PluralRule::Pt | PluralRule::Fr | PluralRule::Es | PluralRule::It if million => PluralCategory::Many,
+        _ => PluralCategory::Other,
+    }
+}
+
+pub fn plural_range_category(rule: PluralRule, a: u64, b: u64) -> PluralCategory {
+    let category_a = plural_category(rule, a);
+    let category_b = plural_category(rule, b);
+    if category_b == PluralCategory::One && category_a != PluralCategory::One { PluralCategory::Other } else { category_b }
+}
+
+fn operand_count(operand: &Operand) -> Option<u64> {
+    match operand {
+        Operand::Value(OperandValue::U64(value)) => Some(*value),
+        Operand::Value(OperandValue::USize(value)) => Some(*value as u64),
+        Operand::Value(OperandValue::I64(value)) => u64::try_from(*value).ok(),
+        Operand::Value(OperandValue::ISize(value)) => u64::try_from(*value).ok(),
+        _ => None,
+    }
+}
+
+pub fn validation_err_plural_category(err: &ValidationErr, rule: PluralRule) -> PluralCategory {
+    let operation = match err {
+        ValidationErr::Operation(operation)
+        | ValidationErr::BytesLen(operation)
+        | ValidationErr::CharsLen(operation)
+        | ValidationErr::GraphemesLen(operation)
+        | ValidationErr::LowercaseLen(operation)
+        | ValidationErr::UppercaseLen(operation)
+        | ValidationErr::NumbersLen(operation)
+        | ValidationErr::SymbolsLen(operation) => operation,
+        _ => return PluralCategory::Other,
+    };
+    let category = match operation {
+        Operation::Eq(operand)
+        | Operation::Ne(operand)
+        | Operation::Gt(operand)
+        | Operation::Ge(operand)
+        | Operation::Lt(operand)
+        | Operation::Le(operand) => operand_count(operand).map(|n| plural_category(rule, n)),
+        Operation::Btwn(operand_a, operand_b) => {
+            operand_count(operand_a).zip(operand_count(operand_b)).map(|(a, b)| plural_range_category(rule, a, b))
+        }
+    };
+    category.unwrap_or(PluralCategory::Other)
+}
+
+impl PluralLocale {
+    pub fn message(&self, key: &str, category: PluralCategory) -> Option<&String> {
+        self.messages.get(key)?.get(&category)
+    }
+}
+
+pub fn locale_plural_pt_short() -> PluralLocale {
+    PluralLocale {
+        rule: PluralRule::Pt,
+        messages: BTreeMap::from([
+            ("bytes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% byte".into())])),
+            ("bytes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% byte".into())])),
+            ("bytes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% byte".into())])),
+            ("bytes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% byte".into())])),
+            ("bytes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% byte".into())])),
+            ("bytes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% byte".into())])),
+            ("bytes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% byte".into())])),
+            ("chars_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% caractere".into())])),
+            ("chars_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% caractere".into())])),
+            ("chars_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% caractere".into())])),
+            ("chars_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% caractere".into())])),
+            ("chars_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% caractere".into())])),
+            ("chars_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% caractere".into())])),
+            ("chars_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% caractere".into())])),
+            ("graphemes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% grafema".into())])),
+            ("graphemes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% grafema".into())])),
+            ("graphemes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% grafema".into())])),
+            ("graphemes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% grafema".into())])),
+            ("graphemes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% grafema".into())])),
+            ("graphemes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% grafema".into())])),
+            ("graphemes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% grafema".into())])),
+            ("lowercase_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% minúscula".into())])),
+            ("lowercase_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% minúscula".into())])),
+            ("lowercase_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% minúscula".into())])),
+            ("lowercase_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% minúscula".into())])),
+            ("lowercase_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% minúscula".into())])),
+            ("lowercase_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% minúscula".into())])),
+            ("lowercase_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% minúscula".into())])),
+            ("uppercase_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% maiúscula".into())])),
+            ("uppercase_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% maiúscula".into())])),
+            ("uppercase_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% maiúscula".into())])),
+            ("uppercase_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% maiúscula".into())])),
+            ("uppercase_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% maiúscula".into())])),
+            ("uppercase_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% maiúscula".into())])),
+            ("uppercase_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% maiúscula".into())])),
+            ("number_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% número".into())])),
+            ("number_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% número".into())])),
+            ("number_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% número".into())])),
+            ("number_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% número".into())])),
+            ("number_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% número".into())])),
+            ("number_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% número".into())])),
+            ("number_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% número".into())])),
+            ("symbols_eq".into(), BTreeMap::from([(PluralCategory::One, "Exatamente %value% símbolo".into())])),
+            ("symbols_ne".into(), BTreeMap::from([(PluralCategory::One, "Diferente de %value% símbolo".into())])),
+            ("symbols_gt".into(), BTreeMap::from([(PluralCategory::One, "Mais de %value% símbolo".into())])),
+            ("symbols_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% símbolo".into())])),
+            ("symbols_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% símbolo".into())])),
+            ("symbols_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% símbolo".into())])),
+            ("symbols_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% símbolo".into())])),
+        ]),
+    }
+}
+
+pub fn locale_plural_es_short() -> PluralLocale {
+    PluralLocale {
+        rule: PluralRule::Es,
+        messages: BTreeMap::from([
+            ("bytes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% byte".into())])),
+            ("bytes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% byte".into())])),
+            ("bytes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% byte".into())])),
+            ("bytes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% byte".into())])),
+            ("bytes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% byte".into())])),
+            ("bytes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% byte".into())])),
+            ("bytes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% byte".into())])),
+            ("chars_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% carácter".into())])),
+            ("chars_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% carácter".into())])),
+            ("chars_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% carácter".into())])),
+            ("chars_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% carácter".into())])),
+            ("chars_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% carácter".into())])),
+            ("chars_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% carácter".into())])),
+            ("chars_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% carácter".into())])),
+            ("graphemes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% grafema".into())])),
+            ("graphemes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% grafema".into())])),
+            ("graphemes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% grafema".into())])),
+            ("graphemes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% grafema".into())])),
+            ("graphemes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% grafema".into())])),
+            ("graphemes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% grafema".into())])),
+            ("graphemes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% grafema".into())])),
+            ("lowercase_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% minúscula".into())])),
+            ("lowercase_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% minúscula".into())])),
+            ("lowercase_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% minúscula".into())])),
+            ("lowercase_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% minúscula".into())])),
+            ("lowercase_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% minúscula".into())])),
+            ("lowercase_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% minúscula".into())])),
+            ("lowercase_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% minúscula".into())])),
+            ("uppercase_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% mayúscula".into())])),
+            ("uppercase_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% mayúscula".into())])),
+            ("uppercase_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% mayúscula".into())])),
+            ("uppercase_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% mayúscula".into())])),
+            ("uppercase_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% mayúscula".into())])),
+            ("uppercase_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% mayúscula".into())])),
+            ("uppercase_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% mayúscula".into())])),
+            ("number_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% número".into())])),
+            ("number_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% número".into())])),
+            ("number_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% número".into())])),
+            ("number_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% número".into())])),
+            ("number_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% número".into())])),
+            ("number_len_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% número".into())])),
+            ("number_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% número".into())])),
+            ("symbols_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactamente %value% símbolo".into())])),
+            ("symbols_ne".into(), BTreeMap::from([(PluralCategory::One, "Distinto de %value% símbolo".into())])),
+            ("symbols_gt".into(), BTreeMap::from([(PluralCategory::One, "Más de %value% símbolo".into())])),
+            ("symbols_ge".into(), BTreeMap::from([(PluralCategory::One, "Mín. %value% símbolo".into())])),
+            ("symbols_lt".into(), BTreeMap::from([(PluralCategory::One, "Menos de %value% símbolo".into())])),
+            ("symbols_le".into(), BTreeMap::from([(PluralCategory::One, "Máx. %value% símbolo".into())])),
+            ("symbols_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% símbolo".into())])),
+        ]),
+    }
+}
+
+pub fn locale_plural_en_short() -> PluralLocale {
+    PluralLocale {
+        rule: PluralRule::En,
+        messages: BTreeMap::from([
+            ("bytes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactly %value% byte".into())])),
+            ("bytes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Not %value% byte".into())])),
+            ("bytes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Over %value% byte".into())])),
+            ("bytes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Min. %value% byte".into())])),
+            ("bytes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Under %value% byte".into())])),
+            ("bytes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Max. %value% byte".into())])),
+            ("bytes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% byte".into())])),
+            ("chars_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactly %value% character".into())])),
+            ("chars_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Not %value% character".into())])),
+            ("chars_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Over %value% character".into())])),
+            ("chars_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Min. %value% character".into())])),
+            ("chars_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Under %value% character".into())])),
+            ("chars_len_le".into(), BTreeMap::from([(PluralCategory::One, "Max. %value% character".into())])),
+            ("chars_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% character".into())])),
+            ("graphemes_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactly %value% grapheme".into())])),
+            ("graphemes_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Not %value% grapheme".into())])),
+            ("graphemes_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Over %value% grapheme".into())])),
+            ("graphemes_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Min. %value% grapheme".into())])),
+            ("graphemes_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Under %value% grapheme".into())])),
+            ("graphemes_len_le".into(), BTreeMap::from([(PluralCategory::One, "Max. %value% grapheme".into())])),
+            ("graphemes_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% grapheme".into())])),
+            ("number_len_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactly %value% number".into())])),
+            ("number_len_ne".into(), BTreeMap::from([(PluralCategory::One, "Not %value% number".into())])),
+            ("number_len_gt".into(), BTreeMap::from([(PluralCategory::One, "Over %value% number".into())])),
+            ("number_len_ge".into(), BTreeMap::from([(PluralCategory::One, "Min. %value% number".into())])),
+            ("number_len_lt".into(), BTreeMap::from([(PluralCategory::One, "Under %value% number".into())])),
+            ("number_len_le".into(), BTreeMap::from([(PluralCategory::One, "Max. %value% number".into())])),
+            ("number_len_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% number".into())])),
+            ("symbols_eq".into(), BTreeMap::from([(PluralCategory::One, "Exactly %value% symbol".into())])),
+            ("symbols_ne".into(), BTreeMap::from([(PluralCategory::One, "Not %value% symbol".into())])),
+            ("symbols_gt".into(), BTreeMap::from([(PluralCategory::One, "Over %value% symbol".into())])),
+            ("symbols_ge".into(), BTreeMap::from([(PluralCategory::One, "Min. %value% symbol".into())])),
+            ("symbols_lt".into(), BTreeMap::from([(PluralCategory::One, "Under %value% symbol".into())])),
+            ("symbols_le".into(), BTreeMap::from([(PluralCategory::One, "Max. %value% symbol".into())])),
+            ("symbols_btwn".into(), BTreeMap::from([(PluralCategory::One, "%value_a%–%value_b% symbol".into())])),
+        ]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        locale::{LOCALE_KEYS, locale_en_short, locale_es_short, locale_field, locale_pt_short, placeholders_match},
+        serialize::{Operand, OperandValue, Operation, ValidationErr},
+    };
+
+    use super::{
+        PluralCategory, PluralRule, locale_plural_en_short, locale_plural_es_short, locale_plural_pt_short, plural_category, plural_range_category,
+        validation_err_plural_category,
+    };
+
+    #[test]
+    fn plural_category_rules() {
+        assert_eq!(plural_category(PluralRule::En, 0), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::En, 1), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::En, 2), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::En, 1_000_000), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::De, 1), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::Pt, 0), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::Pt, 1), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::Pt, 2), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::Pt, 1_000_000), PluralCategory::Many);
+        assert_eq!(plural_category(PluralRule::Fr, 0), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::Fr, 2_000_000), PluralCategory::Many);
+        assert_eq!(plural_category(PluralRule::Es, 0), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::Es, 1), PluralCategory::One);
+        assert_eq!(plural_category(PluralRule::Es, 1_000_000), PluralCategory::Many);
+        assert_eq!(plural_category(PluralRule::Es, 1_000_001), PluralCategory::Other);
+        assert_eq!(plural_category(PluralRule::It, 1_000_000), PluralCategory::Many);
+    }
+
+    #[test]
+    fn plural_range_category_rules() {
+        assert_eq!(plural_range_category(PluralRule::En, 0, 1), PluralCategory::Other);
+        assert_eq!(plural_range_category(PluralRule::En, 1, 5), PluralCategory::Other);
+        assert_eq!(plural_range_category(PluralRule::Pt, 0, 1), PluralCategory::One);
+        assert_eq!(plural_range_category(PluralRule::Pt, 1, 5), PluralCategory::Other);
+        assert_eq!(plural_range_category(PluralRule::Pt, 2, 1_000_000), PluralCategory::Many);
+        assert_eq!(plural_range_category(PluralRule::Es, 0, 1), PluralCategory::Other);
+    }
+
+    #[test]
+    fn validation_err_plural_category_operands() {
+        let value = |n: usize| Operand::Value(OperandValue::USize(n));
+        assert_eq!(validation_err_plural_category(&ValidationErr::CharsLen(Operation::Le(value(1))), PluralRule::En), PluralCategory::One);
+        assert_eq!(validation_err_plural_category(&ValidationErr::CharsLen(Operation::Le(value(2))), PluralRule::En), PluralCategory::Other);
+        assert_eq!(
+            validation_err_plural_category(&ValidationErr::SymbolsLen(Operation::Btwn(value(0), value(1))), PluralRule::Pt),
+            PluralCategory::One
+        );
+        assert_eq!(
+            validation_err_plural_category(&ValidationErr::BytesLen(Operation::Ge(Operand::FieldPath("min".into()))), PluralRule::En),
+            PluralCategory::Other
+        );
+        assert_eq!(
+            validation_err_plural_category(&ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::I64(-1)))), PluralRule::En),
+            PluralCategory::Other
+        );
+        assert_eq!(validation_err_plural_category(&ValidationErr::Required, PluralRule::En), PluralCategory::Other);
+    }
+
+    #[test]
+    fn plural_locale_bundled() {
+        for (plural, l) in [
+            (locale_plural_pt_short(), locale_pt_short()),
+            (locale_plural_es_short(), locale_es_short()),
+            (locale_plural_en_short(), locale_en_short()),
+        ] {
+            for (key, forms) in &plural.messages {
+                assert!(LOCALE_KEYS.contains(&key.as_str()), "{key}");
+                for message in forms.values() {
+                    assert!(placeholders_match(key, message), "{key}");
+                    assert_ne!(Some(message), locale_field(&l, key), "{key}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn plural_locale_message() {
+        let plural = locale_plural_en_short();
+        assert_eq!(plural.message("chars_len_le", PluralCategory::One), Some(&"Max. %value% character".to_string()));
+        assert_eq!(plural.message("chars_len_le", PluralCategory::Other), None);
+        assert_eq!(plural.message("lowercase_len_le", PluralCategory::One), None);
+        let plural = locale_plural_pt_short();
+        assert_eq!(plural.message("symbols_ge", PluralCategory::One), Some(&"Mín. %value% símbolo".to_string()));
+        assert_eq!(plural.message("symbols_ge", PluralCategory::Many), None);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::serialize::{SchemaErr, SchemaErrLocale};
+
+use super::{LocalizeCtx, localize_validation_err};
+
+fn join_path(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else if segment.is_empty() {
+        path.to_string()
+    } else {
+        format!("{path}.{segment}")
+    }
+}
+
+fn localize_schema_err_at(err: &SchemaErr, path: &str, ctx: &LocalizeCtx) -> SchemaErrLocale {
+    match err {
+        SchemaErr::Validation(errs) => SchemaErrLocale::Validation(errs.iter().map(|err| localize_validation_err(err, path, ctx)).collect()),
+        SchemaErr::Arr(errs) => {
+            SchemaErrLocale::Arr(errs.iter().enumerate().map(|(i, err)| localize_schema_err_at(err, &join_path(path, &i.to_string()), ctx)).collect())
+        }
+        SchemaErr::Obj(errs) => {
+            SchemaErrLocale::Obj(errs.iter().map(|(key, err)| (key.clone(), localize_schema_err_at(err, &join_path(path, key), ctx))).collect())
+        }
+    }
+}
+
+pub fn localize_schema_err(err: &SchemaErr, ctx: &LocalizeCtx) -> SchemaErrLocale {
+    localize_schema_err_at(err, "", ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use araucaria::locale::Locale;
+
+    use crate::{
+        locale::{locale_en_long, locale_pt_long},
+        plugin_locale::{plugin_locale_en_long, plugin_locale_pt_long},
+        serialize::{Operand, Operation, SchemaErr, SchemaErrLocale, ValidationErr},
+    };
+
+    use super::super::{FieldLabels, LocalizeCtx};
+    use super::localize_schema_err;
+
+    static LABELS: LazyLock<FieldLabels> = LazyLock::new(|| {
+        FieldLabels::from([
+            ("period.start".into(), "Start date".into()),
+            ("period.end".into(), "End date".into()),
+            ("items.*.name".into(), "Item name".into()),
+        ])
+    });
+
+    static ERR: LazyLock<SchemaErr> = LazyLock::new(|| {
+        SchemaErr::Obj(BTreeMap::from([
+            (
+                "period".into(),
+                SchemaErr::Obj(BTreeMap::from([
+                    ("start".into(), SchemaErr::Validation(vec![ValidationErr::Required, ValidationErr::Date])),
+                    ("end".into(), SchemaErr::Validation(vec![ValidationErr::Operation(Operation::Gt(Operand::FieldPath("period.start".into())))])),
+                ])),
+            ),
+            (
+                "items".into(),
+                SchemaErr::Arr(vec![
+                    SchemaErr::Obj(BTreeMap::from([("name".into(), SchemaErr::Validation(vec![ValidationErr::Required]))])),
+                    SchemaErr::Obj(BTreeMap::from([("name".into(), SchemaErr::Validation(vec![ValidationErr::Str]))])),
+                ]),
+            ),
+        ]))
+    });
+
+    #[test]
+    fn localize_schema_err_without_labels() {
+        let l = locale_pt_long();
+        let plugin = plugin_locale_pt_long();
+        assert_eq!(
+            localize_schema_err(&SchemaErr::Validation(vec![ValidationErr::Required, ValidationErr::Bool]), &LocalizeCtx::new(&l, &plugin)),
+            SchemaErrLocale::Validation(vec!["É obrigatório".into(), "Deve ser um booleano".into()])
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_field_labels() {
+        let l = Locale { required: "%field% is required".into(), str: "%field% must be a string".into(), ..locale_en_long() };
+        let plugin = plugin_locale_en_long();
+        assert_eq!(
+            localize_schema_err(&ERR, &LocalizeCtx::new(&l, &plugin).labels(&LABELS)),
+            SchemaErrLocale::Obj(BTreeMap::from([
+                (
+                    "period".into(),
+                    SchemaErrLocale::Obj(BTreeMap::from([
+                        ("start".into(), SchemaErrLocale::Validation(vec!["Start date is required".into(), "Must be a date".into()])),
+                        ("end".into(), SchemaErrLocale::Validation(vec!["Must be greater than the field Start date".into()])),
+                    ])),
+                ),
+                (
+                    "items".into(),
+                    SchemaErrLocale::Arr(vec![
+                        SchemaErrLocale::Obj(BTreeMap::from([("name".into(), SchemaErrLocale::Validation(vec!["Item name is required".into()]))])),
+                        SchemaErrLocale::Obj(BTreeMap::from([(
+                            "name".into(),
+                            SchemaErrLocale::Validation(vec!["Item name must be a string".into()])
+                        )])),
+                    ]),
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn localize_schema_err_field_paths() {
+        let l = Locale { required: "%field% is required".into(), ..locale_en_long() };
+        let plugin = plugin_locale_en_long();
+        assert_eq!(
+            localize_schema_err(&ERR, &LocalizeCtx::new(&l, &plugin)),
+            SchemaErrLocale::Obj(BTreeMap::from([
+                (
+                    "period".into(),
+                    SchemaErrLocale::Obj(BTreeMap::from([
+                        ("start".into(), SchemaErrLocale::Validation(vec!["period.start is required".into(), "Must be a date".into()])),
+                        ("end".into(), SchemaErrLocale::Validation(vec!["Must be greater than the field period.start".into()])),
+                    ])),
+                ),
+                (
+                    "items".into(),
+                    SchemaErrLocale::Arr(vec![
+                        SchemaErrLocale::Obj(BTreeMap::from([("name".into(), SchemaErrLocale::Validation(vec!["items.0.name is required".into()]))])),
+                        SchemaErrLocale::Obj(BTreeMap::from([("name".into(), SchemaErrLocale::Validation(vec!["Must be a string".into()]))])),
+                    ]),
+                ),
+            ]))
+        );
+    }
+    #[test]
+    fn localize_schema_err_node_errs() {
+        let l = Locale { required: "%field% is required".into(), ..locale_en_long() };
+        let plugin = plugin_locale_en_long();
+        let err = SchemaErr::Obj(BTreeMap::from([(
+            "period".into(),
+            SchemaErr::Obj(BTreeMap::from([
+                ("".into(), SchemaErr::Validation(vec![ValidationErr::Required])),
+                ("start".into(), SchemaErr::Validation(vec![ValidationErr::Required])),
+            ])),
+        )]));
+        assert_eq!(
+            localize_schema_err(&err, &LocalizeCtx::new(&l, &plugin)),
+            SchemaErrLocale::Obj(BTreeMap::from([(
+                "period".into(),
+                SchemaErrLocale::Obj(BTreeMap::from([
+                    ("".into(), SchemaErrLocale::Validation(vec!["period is required".into()])),
+                    ("start".into(), SchemaErrLocale::Validation(vec!["period.start is required".into()])),
+                ]))
+            )]))
+        );
+    }
+}
@@ -0,0 +1,917 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::{
+    locale::locale_field,
+    plugin_locale::plugin_locale_field,
+    rule::{CountryFormat, IpVersion, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeSpan, TimeUnit},
+    serialize::{EnumValues, Operand, OperandValue, Operation, ValidationErr},
+    utils::duration::parse_field_offset,
+};
+
+use super::{
+    LocalizeCtx, PluralCategory, format_list, format_number, format_operand_value, format_temporal, plural_category, validation_err_plural_category,
+};
+
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"%[a-z_]+%").unwrap());
+
+fn operation_key(prefix: &str, operation: &Operation, field_suffix: bool) -> String {
+    let (name, operand) = match operation {
+        Operation::Eq(operand) => ("eq", operand),
+        Operation::Ne(operand) => ("ne", operand),
+        Operation::Gt(operand) => ("gt", operand),
+        Operation::Ge(operand) => ("ge", operand),
+        Operation::Lt(operand) => ("lt", operand),
+        Operation::Le(operand) => ("le", operand),
+        Operation::Btwn(_, _) => return format!("{prefix}btwn"),
+    };
+    match operand {
+        Operand::FieldPath(_) if field_suffix => format!("{prefix}{name}_field"),
+        _ => format!("{prefix}{name}"),
+    }
+}
+
+pub fn validation_err_key(err: &ValidationErr) -> String {
+    match err {
+        ValidationErr::Required => "required".into(),
+        ValidationErr::U64 => "u64".into(),
+        ValidationErr::I64 => "i64".into(),
+        ValidationErr::F64 => "f64".into(),
+        ValidationErr::USize => "usize".into(),
+        ValidationErr::ISize => "isize".into(),
+        ValidationErr::Bool => "bool".into(),
+        ValidationErr::Str => "str".into(),
+        ValidationErr::Email => "email".into(),
+        ValidationErr::Date => "date".into(),
+        ValidationErr::Time => "time".into(),
+        ValidationErr::DateTime => "date_time".into(),
+        ValidationErr::Operation(operation) => operation_key("", operation, true),
+        ValidationErr::BytesLen(operation) => operation_key("bytes_len_", operation, false),
+        ValidationErr::CharsLen(operation) => operation_key("chars_len_", operation, false),
+        ValidationErr::GraphemesLen(operation) => operation_key("graphemes_len_", operation, false),
+        ValidationErr::LowercaseLen(operation) => operation_key("lowercase_len_", operation, false),
+        ValidationErr::UppercaseLen(operation) => operation_key("uppercase_len_", operation, false),
+        ValidationErr::NumbersLen(operation) => operation_key("number_len_", operation, false),
+        ValidationErr::SymbolsLen(operation) => operation_key("symbols_", operation, false),
+        ValidationErr::USizeEnum(_) | ValidationErr::ISizeEnum(_) | ValidationErr::StrEnum(_) | ValidationErr::Enumerated(_) => "enumerated".into(),
+        ValidationErr::Pattern(_, None) => "pattern".into(),
+        ValidationErr::Pattern(_, Some(_)) => "pattern_description".into(),
+        ValidationErr::Custom(_, key) => key.clone(),
+        ValidationErr::Cpf => "cpf".into(),
+        ValidationErr::Cnpj => "cnpj".into(),
+        ValidationErr::Cep => "cep".into(),
+        ValidationErr::Url => "url".into(),
+        ValidationErr::UrlScheme(_) => "url_scheme".into(),
+        ValidationErr::UrlCredentials => "url_credentials".into(),
+        ValidationErr::Ip(IpVersion::V4) => "ipv4".into(),
+        ValidationErr::Ip(IpVersion::V6) => "ipv6".into(),
+        ValidationErr::Ip(IpVersion::Any) => "ip".into(),
+        ValidationErr::Cidr(IpVersion::V4) => "cidr_ipv4".into(),
+        ValidationErr::Cidr(IpVersion::V6) => "cidr_ipv6".into(),
+        ValidationErr::Cidr(IpVersion::Any) => "cidr".into(),
+        ValidationErr::Hostname => "hostname".into(),
+        ValidationErr::Uuid => "uuid".into(),
+        ValidationErr::UuidVersion(_) => "uuid_version".into(),
+        ValidationErr::Ulid => "ulid".into(),
+        ValidationErr::NanoId(_) => "nano_id".into(),
+        ValidationErr::CardNumber => "card_number".into(),
+        ValidationErr::CardBrand(_) => "card_brand".into(),
+        ValidationErr::CardExpiry => "card_expiry".into(),
+        ValidationErr::CardExpired => "card_expired".into(),
+        ValidationErr::Iban => "iban".into(),
+        ValidationErr::IbanCountry => "iban_country".into(),
+        ValidationErr::IbanLength(_) => "iban_length".into(),
+        ValidationErr::IbanChecksum => "iban_checksum".into(),
+        ValidationErr::Bic => "bic".into(),
+        ValidationErr::Phone => "phone".into(),
+        ValidationErr::PhoneCountry(_) => "phone_country".into(),
+        ValidationErr::Country(CountryFormat::Alpha2) => "country_alpha2".into(),
+        ValidationErr::Country(CountryFormat::Alpha3) => "country_alpha3".into(),
+        ValidationErr::Currency => "currency".into(),
+        ValidationErr::Language => "language".into(),
+        ValidationErr::Timezone => "timezone".into(),
+        ValidationErr::Precision(Precision::Minutes) => "precision_minutes".into(),
+        ValidationErr::Precision(Precision::Seconds) => "precision_seconds".into(),
+        ValidationErr::Precision(Precision::Millis) => "precision_millis".into(),
+        ValidationErr::Precision(Precision::Nanos) => "precision_nanos".into(),
+        ValidationErr::DateTimeOffset(OffsetPolicy::Utc) => "date_time_offset_utc".into(),
+        ValidationErr::DateTimeOffset(OffsetPolicy::Required) => "date_time_offset_required".into(),
+        ValidationErr::DateTimeOffset(OffsetPolicy::Any) => "date_time".into(),
+        ValidationErr::TimeSeconds => "time_seconds".into(),
+        ValidationErr::DateOperand(_) => "date_operand".into(),
+        ValidationErr::TimeOperand(_) => "time_operand".into(),
+        ValidationErr::DateTimeOperand(_) => "date_time_operand".into(),
+        ValidationErr::Relative(RelativeRule::Past) => "past".into(),
+        ValidationErr::Relative(RelativeRule::Future) => "future".into(),
+        ValidationErr::Relative(RelativeRule::AtLeastAgo(_)) => "at_least_ago".into(),
+        ValidationErr::Relative(RelativeRule::WithinLast(_)) => "within_last".into(),
+        ValidationErr::Relative(RelativeRule::WithinNext(_)) => "within_next".into(),
+        ValidationErr::Relative(RelativeRule::AtLeastAhead(_)) => "at_least_ahead".into(),
+        ValidationErr::Duration => "duration".into(),
+        ValidationErr::DurationMin(_) => "duration_min".into(),
+        ValidationErr::DurationMax(_) => "duration_max".into(),
+        ValidationErr::Interval => "interval".into(),
+        ValidationErr::IntervalOrder => "interval_order".into(),
+        ValidationErr::IntervalMin(_) => "interval_min".into(),
+        ValidationErr::IntervalMax(_) => "interval_max".into(),
+    }
+}
+
+fn operand_value_to_string(value: &OperandValue, ctx: &LocalizeCtx) -> String {
+    if let Some(format) = ctx.format {
+        return format_operand_value(value, format);
+    }
+    match value {
+        OperandValue::U64(value) => value.to_string(),
+        OperandValue::I64(value) => value.to_string(),
+        OperandValue::F64(value) => value.to_string(),
+        OperandValue::USize(value) => value.to_string(),
+        OperandValue::ISize(value) => value.to_string(),
+        OperandValue::Bool(value) => value.to_string(),
+        OperandValue::Str(value) => format!("\"{value}\""),
+    }
+}
+
+fn field_path_to_string(path: &str, ctx: &LocalizeCtx) -> String {
+    match parse_field_offset(path) {
+        Ok(Some(field_offset)) => {
+            let key = if field_offset.forward { "operand_plus" } else { "operand_minus" };
+            let template = plugin_locale_field(ctx.plugin, key)
+                .or(ctx.custom.and_then(|custom| custom.get(key)))
+                .cloned()
+                .unwrap_or(format!("%field% {} %value%", if field_offset.forward { "+" } else { "-" }));
+            template.replace("%field%", &ctx.field_name(field_offset.path)).replace("%value%", &duration_to_string(&field_offset.duration, ctx))
+        }
+        Ok(None) | Err(()) => ctx.field_name(path),
+    }
+}
+
+fn operand_to_string(operand: &Operand, ctx: &LocalizeCtx) -> String {
+    match operand {
+        Operand::Value(value) => operand_value_to_string(value, ctx),
+        Operand::FieldPath(path) => field_path_to_string(path, ctx),
+    }
+}
+
+fn enumerated_to_string(values: Vec<String>, ctx: &LocalizeCtx) -> String {
+    match ctx.format {
+        Some(format) => format_list(values, format),
+        None => format!("[ {} ]", values.join(", ")),
+    }
+}
+
+fn enumerated_number(value: String, ctx: &LocalizeCtx) -> String {
+    match ctx.format {
+        Some(format) => format_number(&value, format),
+        None => value,
+    }
+}
+
+fn enumerated_str(value: &str, ctx: &LocalizeCtx) -> String {
+    match ctx.format {
+        Some(format) => format_temporal(value, format).unwrap_or(value.to_string()),
+        None => format!("\"{value}\""),
+    }
+}
+
+fn time_unit_key(unit: TimeUnit, one: bool) -> &'static str {
+    match (unit, one) {
+        (TimeUnit::Years, true) => "unit_year",
+        (TimeUnit::Years, false) => "unit_years",
+        (TimeUnit::Months, true) => "unit_month",
+        (TimeUnit::Months, false) => "unit_months",
+        (TimeUnit::Weeks, true) => "unit_week",
+        (TimeUnit::Weeks, false) => "unit_weeks",
+        (TimeUnit::Days, true) => "unit_day",
+        (TimeUnit::Days, false) => "unit_days",
+        (TimeUnit::Hours, true) => "unit_hour",
+        (TimeUnit::Hours, false) => "unit_hours",
+        (TimeUnit::Minutes, true) => "unit_minute",
+        (TimeUnit::Minutes, false) => "unit_minutes",
+        (TimeUnit::Seconds, true) => "unit_second",
+        (TimeUnit::Seconds, false) => "unit_seconds",
+    }
+}
+
+fn span_to_string(span: &TimeSpan, ctx: &LocalizeCtx) -> String {
+    let one = match ctx.plural {
+        Some(plural) => plural_category(plural.rule, span.amount.into()) == PluralCategory::One,
+        None => span.amount == 1,
+    };
+    let key = time_unit_key(span.unit, one);
+    let template =
+        plugin_locale_field(ctx.plugin, key).or(ctx.custom.and_then(|custom| custom.get(key))).cloned().unwrap_or(format!("%value% {key}"));
+    template.replace("%value%", &enumerated_number(span.amount.to_string(), ctx))
+}
+
+fn duration_to_string(duration: &IsoDuration, ctx: &LocalizeCtx) -> String {
+    let spans: Vec<String> = duration.spans().iter().map(|span| span_to_string(span, ctx)).collect();
+    if spans.is_empty() { span_to_string(&TimeSpan::seconds(0), ctx) } else { spans.join(", ") }
+}
+
+fn values(err: &ValidationErr, ctx: &LocalizeCtx) -> Vec<(&'static str, String)> {
+    let operation = match err {
+        ValidationErr::Operation(operation)
+        | ValidationErr::BytesLen(operation)
+        | ValidationErr::CharsLen(operation)
+        | ValidationErr::GraphemesLen(operation)
+        | ValidationErr::LowercaseLen(operation)
+        | ValidationErr::UppercaseLen(operation)
+        | ValidationErr::NumbersLen(operation)
+        | ValidationErr::SymbolsLen(operation) => operation,
+        ValidationErr::USizeEnum(values) | ValidationErr::Enumerated(EnumValues::USize(values)) => {
+            return vec![("%value%", enumerated_to_string(values.iter().map(|value| enumerated_number(value.to_string(), ctx)).collect(), ctx))];
+        }
+        ValidationErr::ISizeEnum(values) | ValidationErr::Enumerated(EnumValues::ISize(values)) => {
+            return vec![("%value%", enumerated_to_string(values.iter().map(|value| enumerated_number(value.to_string(), ctx)).collect(), ctx))];
+        }
+        ValidationErr::StrEnum(values) | ValidationErr::Enumerated(EnumValues::Str(values)) => {
+            return vec![("%value%", enumerated_to_string(values.iter().map(|value| enumerated_str(value, ctx)).collect(), ctx))];
+        }
+        ValidationErr::Pattern(pattern, description) => return vec![("%value%", description.as_ref().unwrap_or(pattern).clone())],
+        ValidationErr::UrlScheme(values) | ValidationErr::PhoneCountry(values) => {
+            return vec![("%value%", enumerated_to_string(values.clone(), ctx))];
+        }
+        ValidationErr::UuidVersion(versions) => {
+            return vec![(
+                "%value%",
+                enumerated_to_string(versions.iter().map(|version| enumerated_number(version.to_string(), ctx)).collect(), ctx),
+            )];
+        }
+        ValidationErr::NanoId(len) | ValidationErr::IbanLength(len) => return vec![("%value%", enumerated_number(len.to_string(), ctx))],
+        ValidationErr::DateOperand(operand) | ValidationErr::TimeOperand(operand) | ValidationErr::DateTimeOperand(operand) => {
+            return vec![("%value%", operand_to_string(operand, ctx))];
+        }
+        ValidationErr::Relative(
+            RelativeRule::AtLeastAgo(span) | RelativeRule::WithinLast(span) | RelativeRule::WithinNext(span) | RelativeRule::AtLeastAhead(span),
+        ) => return vec![("%value%", span_to_string(span, ctx))],
+        ValidationErr::DurationMin(duration)
+        | ValidationErr::DurationMax(duration)
+        | ValidationErr::IntervalMin(duration)
+        | ValidationErr::IntervalMax(duration) => return vec![("%value%", duration_to_string(duration, ctx))],
+        ValidationErr::CardBrand(brands) => {
+            return vec![("%value%", enumerated_to_string(brands.iter().map(|brand| brand.name().into()).collect(), ctx))];
+        }
+        _ => return vec![],
+    };
+    match operation {
+        Operation::Eq(operand)
+        | Operation::Ne(operand)
+        | Operation::Gt(operand)
+        | Operation::Ge(operand)
+        | Operation::Lt(operand)
+        | Operation::Le(operand) => vec![("%value%", operand_to_string(operand, ctx))],
+        Operation::Btwn(operand_a, operand_b) => {
+            vec![("%value_a%", operand_to_string(operand_a, ctx)), ("%value_b%", operand_to_string(operand_b, ctx))]
+        }
+    }
+}
+
+pub fn localize_validation_err(err: &ValidationErr, path: &str, ctx: &LocalizeCtx) -> String {
+    let key = validation_err_key(err);
+    let override_message = ctx.overrides.and_then(|overrides| overrides.message(path, &key, ctx.tag));
+    let plural_message = ctx.plural.and_then(|plural| plural.message(&key, validation_err_plural_category(err, plural.rule)));
+    let custom_message = ctx.custom.and_then(|custom| custom.get(&key));
+    let message = override_message
+        .or(plural_message)
+        .or(locale_field(ctx.locale, &key))
+        .or(plugin_locale_field(ctx.plugin, &key))
+        .or(custom_message)
+        .unwrap_or(&key);
+    let values = values(err, ctx);
+    PLACEHOLDER_REGEX
+        .replace_all(message, |caps: &Captures| match &caps[0] {
+            "%field%" => ctx.field_name(path),
+            placeholder => values.iter().find(|(name, _)| *name == placeholder).map(|(_, value)| value.clone()).unwrap_or(placeholder.to_string()),
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use araucaria::locale::Locale;
+
+    use crate::{
+        locale::{locale_en_long, locale_en_short, locale_es_long, locale_es_short, locale_pt_long, locale_pt_short},
+        plugin_locale::{
+            plugin_locale_en_long, plugin_locale_en_short, plugin_locale_es_long, plugin_locale_es_short, plugin_locale_pt_long,
+            plugin_locale_pt_short,
+        },
+        rule::{CardBrand, CountryFormat, IpVersion, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeSpan},
+        serialize::{EnumValues, Operand, OperandValue, Operation, ValidationErr},
+    };
+
+    use super::super::{
+        CustomMessages, FieldLabels, LocalizeCtx, MessageOverride, MessageOverrides, PluralCategory, PluralLocale, PluralRule, locale_format_en,
+        locale_format_es, locale_format_pt, locale_plural_en_short, locale_plural_es_short, locale_plural_pt_short,
+    };
+    use super::{localize_validation_err, validation_err_key};
+
+    static LABELS: LazyLock<FieldLabels> = LazyLock::new(|| {
+        FieldLabels::from([("period.start".into(), "Start date".into()), ("period.end".into(), "End date".into()), ("name".into(), "Name".into())])
+    });
+
+    fn field(path: &str) -> Operand {
+        Operand::FieldPath(path.into())
+    }
+
+    fn value(value: u64) -> Operand {
+        Operand::Value(OperandValue::U64(value))
+    }
+
+    #[test]
+    fn validation_err_key_types() {
+        assert_eq!(validation_err_key(&ValidationErr::Required), "required".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::USize), "usize".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::DateTime), "date_time".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::StrEnum(vec!["a".into()])), "enumerated".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Enumerated(EnumValues::USize(vec![1]))), "enumerated".to_string());
+    }
+
+    #[test]
+    fn validation_err_key_operations() {
+        assert_eq!(validation_err_key(&ValidationErr::Operation(Operation::Ge(value(1)))), "ge".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Operation(Operation::Ge(field("a")))), "ge_field".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Operation(Operation::Btwn(field("a"), field("b")))), "btwn".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::BytesLen(Operation::Eq(value(1)))), "bytes_len_eq".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::CharsLen(Operation::Lt(field("a")))), "chars_len_lt".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::NumbersLen(Operation::Le(value(1)))), "number_len_le".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::SymbolsLen(Operation::Btwn(value(1), value(2)))), "symbols_btwn".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_without_labels() {
+        let l = locale_en_long();
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin);
+        assert_eq!(localize_validation_err(&ValidationErr::Required, "name", &ctx), "Is required".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Operation(Operation::Gt(value(10))), "age", &ctx), "Must be greater than 10".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::Str("Paul".into())))), "name", &ctx),
+            "Must be equals to \"Paul\"".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Gt(field("period.start"))), "period.end", &ctx),
+            "Must be greater than the field period.start".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::CharsLen(Operation::Btwn(value(1), value(5))), "name", &ctx),
+            "The length of characters must be between 1 and 5".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Enumerated(EnumValues::Str(vec!["UNIX".into(), "LINUX".into()])), "os", &ctx),
+            "Must be one of the values [ \"UNIX\", \"LINUX\" ]".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::ISizeEnum(vec![-1, 0, 1]), "sign", &ctx),
+            "Must be one of the values [ -1, 0, 1 ]".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_field_labels() {
+        let l = locale_en_long();
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&LABELS);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Gt(field("period.start"))), "period.end", &ctx),
+            "Must be greater than the field Start date".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Btwn(field("period.start"), field("period.end"))), "deadline", &ctx),
+            "Must be between Start date and End date".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Lt(field("period.limit"))), "period.end", &ctx),
+            "Must be smaller than the field period.limit".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_field_placeholder() {
+        let l = Locale {
+            required: "%field% is required".into(),
+            gt: "%field% must be greater than %value%".into(),
+            gt_field: "%field% must be greater than %value%".into(),
+            ..locale_en_long()
+        };
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&LABELS);
+        assert_eq!(localize_validation_err(&ValidationErr::Required, "name", &ctx), "Name is required".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Required, "email", &ctx), "email is required".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Gt(value(3))), "name", &ctx),
+            "Name must be greater than 3".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Gt(field("period.start"))), "period.end", &ctx),
+            "End date must be greater than Start date".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_pt() {
+        let l = Locale { required: "%field% é obrigatório".into(), ..locale_pt_long() };
+        let labels = FieldLabels::from([("period.start".into(), "Data de início".into()), ("name".into(), "Nome".into())]);
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&labels);
+        assert_eq!(localize_validation_err(&ValidationErr::Required, "name", &ctx), "Nome é obrigatório".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Ge(field("period.start"))), "period.end", &ctx),
+            "Deve ser maior ou igual ao campo Data de início".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_format() {
+        let l = locale_pt_long();
+        let f = locale_format_pt();
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).format(&f);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Lt(Operand::Value(OperandValue::F64(1234.5)))), "price", &ctx),
+            "Deve ser menor que 1.234,5".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Ge(Operand::Value(OperandValue::Str("2026-10-17".into())))), "date", &ctx),
+            "Deve ser maior ou igual a 17/10/2026".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(
+                &ValidationErr::Operation(Operation::Btwn(
+                    Operand::Value(OperandValue::Str("08:00".into())),
+                    Operand::Value(OperandValue::Str("18:30".into()))
+                )),
+                "time",
+                &ctx
+            ),
+            "Deve estar entre 08:00 e 18:30".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::CharsLen(Operation::Le(Operand::Value(OperandValue::USize(10000)))), "bio", &ctx),
+            "A quantidade de caracteres deve ser menor ou igual a 10.000".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Enumerated(EnumValues::Str(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()])), "os", &ctx),
+            "Deve ser um dos valores UNIX, LINUX ou FREEBSD".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Enumerated(EnumValues::ISize(vec![-1000, 0, 1000])), "offset", &ctx),
+            "Deve ser um dos valores -1.000, 0 ou 1.000".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_format_en_es() {
+        let l = locale_en_long();
+        let f = locale_format_en();
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).format(&f);
+        assert_eq!(
+            localize_validation_err(
+                &ValidationErr::Operation(Operation::Lt(Operand::Value(OperandValue::Str("2026-10-17T18:45Z".into())))),
+                "at",
+                &ctx
+            ),
+            "Must be smaller than 10/17/2026, 6:45 PM UTC".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::StrEnum(vec!["UNIX".into(), "LINUX".into(), "FREEBSD".into()]), "os", &ctx),
+            "Must be one of the values UNIX, LINUX, or FREEBSD".to_string()
+        );
+        let l = locale_es_long();
+        let f = locale_format_es();
+        let plugin = plugin_locale_es_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).format(&f);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::Str("Paul".into())))), "name", &ctx),
+            "Debe ser igual a \"Paul\"".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::USizeEnum(vec![1, 2]), "n", &ctx), "Debe ser uno de los valores 1 o 2".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_plural() {
+        let count = |n: usize| Operand::Value(OperandValue::USize(n));
+        let l = locale_en_short();
+        let p = locale_plural_en_short();
+        let plugin = plugin_locale_en_short();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p);
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Le(count(1))), "name", &ctx), "Max. 1 character".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Le(count(2))), "name", &ctx), "Max. 2 characters".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Le(count(0))), "name", &ctx), "Max. 0 characters".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Btwn(count(0), count(1))), "pw", &ctx), "0–1 symbols".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::LowercaseLen(Operation::Ge(count(1))), "pw", &ctx), "Min. 1 lowercase".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Required, "name", &ctx), "Required".to_string());
+
+        let l = locale_pt_short();
+        let p = locale_plural_pt_short();
+        let plugin = plugin_locale_pt_short();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p);
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(1))), "pw", &ctx), "Mín. 1 símbolo".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(0))), "pw", &ctx), "Mín. 0 símbolo".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(2))), "pw", &ctx), "Mín. 2 símbolos".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Btwn(count(0), count(1))), "pw", &ctx), "0–1 caractere".to_string());
+
+        let l = locale_es_short();
+        let p = locale_plural_es_short();
+        let f = locale_format_es();
+        let plugin = plugin_locale_es_short();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p).format(&f);
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Eq(count(1))), "pw", &ctx), "Exactamente 1 carácter".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::CharsLen(Operation::Eq(count(1000))), "pw", &ctx),
+            "Exactamente 1.000 caracteres".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_plural_fallback() {
+        let count = |n: usize| Operand::Value(OperandValue::USize(n));
+        let l = locale_en_long();
+        let p = PluralLocale { rule: PluralRule::En, messages: BTreeMap::new() };
+        let plugin = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::CharsLen(Operation::Le(count(1))), "name", &ctx),
+            "The length of characters must be smaller than or equals to 1".to_string()
+        );
+        let l = locale_en_short();
+        let p = PluralLocale {
+            rule: PluralRule::En,
+            messages: BTreeMap::from([("chars_len_le".into(), BTreeMap::from([(PluralCategory::Many, "Max. %value% characters!".into())]))]),
+        };
+        let plugin = plugin_locale_en_short();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p);
+        assert_eq!(localize_validation_err(&ValidationErr::CharsLen(Operation::Le(count(1))), "name", &ctx), "Max. 1 characters".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_overrides() {
+        let count = |n: usize| Operand::Value(OperandValue::USize(n));
+        let overrides = MessageOverrides::default()
+            .add(
+                "password",
+                "symbols_ge",
+                MessageOverride::from("Your password needs at least %value% symbols, like ! or #")
+                    .locale("pt", "Sua senha precisa de pelo menos %value% símbolos, como ! ou #"),
+            )
+            .add("period.end", "gt_field", MessageOverride::from("%field% must come after %value%"));
+        let labels = FieldLabels::from([("period.start".into(), "Start date".into()), ("period.end".into(), "End date".into())]);
+        let l = locale_en_short();
+        let p = locale_plural_en_short();
+        let plugin = plugin_locale_en_short();
+        let ctx = LocalizeCtx::new(&l, &plugin).plural(&p).labels(&labels).overrides(&overrides);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(2))), "password", &ctx),
+            "Your password needs at least 2 symbols, like ! or #".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(1))), "username", &ctx), "Min. 1 symbol".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::SymbolsLen(Operation::Gt(count(2))), "password", &ctx), "Over 2 symbols".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Gt(field("period.start"))), "period.end", &ctx),
+            "End date must come after Start date".to_string()
+        );
+        let l = locale_pt_long();
+        let plugin = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &plugin).overrides(&overrides).tag("pt-BR");
+        assert_eq!(
+            localize_validation_err(&ValidationErr::SymbolsLen(Operation::Ge(count(2))), "password", &ctx),
+            "Sua senha precisa de pelo menos 2 símbolos, como ! ou #".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_pattern() {
+        let sku = ValidationErr::Pattern("[A-Z]{3}-[0-9]{4}".into(), Some("ABC-1234".into()));
+        let cep = ValidationErr::Pattern("[0-9]{5}-[0-9]{3}".into(), None);
+        assert_eq!(validation_err_key(&sku), "pattern_description".to_string());
+        assert_eq!(validation_err_key(&cep), "pattern".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&sku, "sku", &ctx), "Must be in the format ABC-1234".to_string());
+        assert_eq!(localize_validation_err(&cep, "cep", &ctx), "Must match the pattern [0-9]{5}-[0-9]{3}".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&sku, "sku", &ctx), "Deve estar no formato ABC-1234".to_string());
+        assert_eq!(localize_validation_err(&cep, "cep", &ctx), "Deve corresponder ao padrão [0-9]{5}-[0-9]{3}".to_string());
+        let l = locale_es_short();
+        let p = plugin_locale_es_short();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&cep, "cep", &ctx), "Patrón [0-9]{5}-[0-9]{3}".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_custom() {
+        let sku = ValidationErr::Custom("sku_exists".into(), "sku_unknown".into());
+        assert_eq!(validation_err_key(&sku), "sku_unknown".to_string());
+        let l = locale_en_long();
+        let plugin = plugin_locale_en_long();
+        assert_eq!(localize_validation_err(&sku, "sku", &LocalizeCtx::new(&l, &plugin)), "sku_unknown".to_string());
+        let custom = CustomMessages::from([("sku_unknown".into(), "%field% is not in the catalogue".into())]);
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&LABELS).custom(&custom);
+        assert_eq!(localize_validation_err(&sku, "sku", &ctx), "sku is not in the catalogue".to_string());
+        let labels = FieldLabels::from([("sku".into(), "SKU".into())]);
+        let overrides =
+            MessageOverrides::default().add("sku", "sku_unknown", MessageOverride::from("Unknown %field%").locale("pt", "%field% desconhecido"));
+        let ctx = LocalizeCtx::new(&l, &plugin).labels(&labels).custom(&custom).overrides(&overrides).tag("pt-BR");
+        assert_eq!(localize_validation_err(&sku, "sku", &ctx), "SKU desconhecido".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_br_documents() {
+        assert_eq!(validation_err_key(&ValidationErr::Cpf), "cpf".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Cnpj), "cnpj".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Cep), "cep".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Cnpj, "cnpj", &ctx), "Must be a valid CNPJ".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Cpf, "cpf", &ctx), "Deve ser um CPF válido".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Cep, "cep", &ctx), "Deve ser um CEP válido".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_network() {
+        let https = ValidationErr::UrlScheme(vec!["https".into()]);
+        let web = ValidationErr::UrlScheme(vec!["http".into(), "https".into()]);
+        assert_eq!(validation_err_key(&ValidationErr::Ip(IpVersion::V6)), "ipv6".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Cidr(IpVersion::Any)), "cidr".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Url, "webhook", &ctx), "Must be a valid URL".to_string());
+        assert_eq!(localize_validation_err(&https, "webhook", &ctx), "The URL scheme must be one of [ https ]".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::UrlCredentials, "webhook", &ctx), "The URL must not contain credentials".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Cidr(IpVersion::V4), "allow", &ctx), "Must be a valid IPv4 CIDR block".to_string());
+        let f = locale_format_en();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f);
+        assert_eq!(localize_validation_err(&web, "webhook", &ctx), "The URL scheme must be one of http or https".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let f = locale_format_pt();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f);
+        assert_eq!(localize_validation_err(&web, "webhook", &ctx), "O esquema da URL deve ser um de http ou https".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Ip(IpVersion::Any), "ip", &ctx), "Deve ser um endereço IP válido".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Hostname, "host", &ctx), "Deve ser um nome de host válido".to_string());
+        let l = locale_es_short();
+        let p = plugin_locale_es_short();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Ip(IpVersion::V4), "ip", &ctx), "IPv4 inválida".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_identifier() {
+        let versions = ValidationErr::UuidVersion(vec![4, 7]);
+        assert_eq!(validation_err_key(&versions), "uuid_version".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::NanoId(21)), "nano_id".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Uuid, "id", &ctx), "Must be a valid UUID".to_string());
+        assert_eq!(localize_validation_err(&versions, "id", &ctx), "Must be a UUID of version [ 4, 7 ]".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Ulid, "id", &ctx), "Must be a valid ULID".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::NanoId(21), "id", &ctx), "Must be a valid Nano ID with 21 characters".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let f = locale_format_pt();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f);
+        assert_eq!(localize_validation_err(&versions, "id", &ctx), "Deve ser um UUID da versão 4 ou 7".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::NanoId(21), "id", &ctx), "Deve ser um Nano ID válido com 21 caracteres".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_card() {
+        let brands = ValidationErr::CardBrand(vec![CardBrand::Visa, CardBrand::Mastercard, CardBrand::Elo]);
+        assert_eq!(validation_err_key(&brands), "card_brand".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::CardNumber, "card", &ctx), "Must be a valid card number".to_string());
+        assert_eq!(localize_validation_err(&brands, "card", &ctx), "Card brand not accepted, use one of [ Visa, Mastercard, Elo ]".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::CardExpiry, "expiry", &ctx),
+            "Must be a valid expiry date in the format MM/YY".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::CardExpired, "expiry", &ctx), "The card is expired".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let f = locale_format_pt();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f);
+        assert_eq!(localize_validation_err(&brands, "card", &ctx), "Bandeira do cartão não aceita, use Visa, Mastercard ou Elo".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::CardExpired, "expiry", &ctx), "O cartão está vencido".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_bank() {
+        assert_eq!(validation_err_key(&ValidationErr::IbanLength(22)), "iban_length".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Iban, "iban", &ctx), "Must be a valid IBAN".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::IbanCountry, "iban", &ctx), "The IBAN country is not supported".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::IbanLength(22), "iban", &ctx),
+            "The IBAN for this country must have 22 characters".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::IbanChecksum, "iban", &ctx), "The IBAN check digits are invalid".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Bic, "bic", &ctx), "Must be a valid BIC/SWIFT code".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::IbanLength(29), "iban", &ctx), "O IBAN deste país deve ter 29 caracteres".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::IbanChecksum, "iban", &ctx), "Os dígitos verificadores do IBAN são inválidos".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_phone() {
+        let countries = ValidationErr::PhoneCountry(vec!["BR".into(), "PT".into()]);
+        assert_eq!(validation_err_key(&countries), "phone_country".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Phone, "phone", &ctx), "Must be a valid phone number".to_string());
+        assert_eq!(localize_validation_err(&countries, "phone", &ctx), "The phone number must be from [ BR, PT ]".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let f = locale_format_pt();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f);
+        assert_eq!(localize_validation_err(&countries, "phone", &ctx), "O telefone deve ser de BR ou PT".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_iso() {
+        assert_eq!(validation_err_key(&ValidationErr::Country(CountryFormat::Alpha3)), "country_alpha3".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Country(CountryFormat::Alpha2), "country", &ctx),
+            "Must be a valid country code".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Country(CountryFormat::Alpha3), "country", &ctx),
+            "Must be a valid three-letter country code".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::Currency, "currency", &ctx), "Must be a valid currency code".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Language, "language", &ctx), "Must be a valid language tag".to_string());
+        assert_eq!(localize_validation_err(&ValidationErr::Timezone, "timezone", &ctx), "Must be a valid time zone".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Country(CountryFormat::Alpha2), "country", &ctx),
+            "Deve ser um código de país válido".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::Timezone, "timezone", &ctx), "Deve ser um fuso horário válido".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_date_time() {
+        assert_eq!(validation_err_key(&ValidationErr::Precision(Precision::Millis)), "precision_millis".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::DateTimeOffset(OffsetPolicy::Required)), "date_time_offset_required".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Precision(Precision::Minutes), "starts_at", &ctx), "Must not include seconds".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DateTimeOffset(OffsetPolicy::Utc), "starts_at", &ctx),
+            "Must be in UTC (ending in Z)".to_string()
+        );
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Precision(Precision::Seconds), "starts_at", &ctx),
+            "Não deve incluir frações de segundo".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DateTimeOffset(OffsetPolicy::Required), "starts_at", &ctx),
+            "Deve incluir o deslocamento de UTC".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::TimeSeconds, "opens_at", &ctx), "Deve incluir os segundos".to_string());
+    }
+
+    #[test]
+    fn localize_validation_err_operand() {
+        assert_eq!(validation_err_key(&ValidationErr::TimeOperand(Operand::FieldPath("opens_at".into()))), "time_operand".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DateOperand(Operand::Value(OperandValue::Str("01/01/2026".into()))), "checkin", &ctx),
+            "Cannot be compared with \"01/01/2026\", which is not a valid date".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DateTimeOperand(Operand::FieldPath("checkin".into())), "starts_at", &ctx),
+            "Cannot be compared with checkin, which is not a valid date and time".to_string()
+        );
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::TimeOperand(Operand::Value(OperandValue::Str("8pm".into()))), "opens_at", &ctx),
+            "Não pode ser comparado com \"8pm\", que não é um horário válido".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_operation_field_duration() {
+        let err = ValidationErr::Operation(Operation::Ge(field("period.start + P1D")));
+        assert_eq!(validation_err_key(&err), "ge_field".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p).labels(&LABELS);
+        assert_eq!(
+            localize_validation_err(&err, "period.end", &ctx),
+            "Must be greater than or equals to the field Start date plus 1 day".to_string()
+        );
+        let p = plugin_locale_en_long();
+        let f = locale_format_en();
+        let ctx = LocalizeCtx::new(&l, &p).format(&f).labels(&LABELS);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Lt(field("period.start - P1DT2H"))), "period.end", &ctx),
+            "Must be smaller than the field Start date minus 1 day, 2 hours".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Lt(field("period.start - P1X"))), "period.end", &ctx),
+            "Must be smaller than the field period.start - P1X".to_string()
+        );
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Operation(Operation::Btwn(field("checkin + P1D"), field("checkin + P30D"))), "checkout", &ctx),
+            "Deve estar entre checkin mais 1 dia e checkin mais 30 dias".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_duration() {
+        assert_eq!(validation_err_key(&ValidationErr::IntervalOrder), "interval_order".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Duration, "sla", &ctx), "Must be a valid ISO 8601 duration".to_string());
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DurationMin(IsoDuration::try_from("PT5M").unwrap()), "sla", &ctx),
+            "Must be at least 5 minutes".to_string()
+        );
+        assert_eq!(localize_validation_err(&ValidationErr::IntervalOrder, "period", &ctx), "The start must be before the end".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::IntervalMax(IsoDuration::try_from("P1Y6M").unwrap()), "period", &ctx),
+            "Deve abranger no máximo 1 ano, 6 meses".to_string()
+        );
+        let l = locale_es_short();
+        let p = plugin_locale_es_short();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::DurationMax(IsoDuration::try_from("P7D").unwrap()), "sla", &ctx),
+            "Máx. 7 días".to_string()
+        );
+    }
+
+    #[test]
+    fn localize_validation_err_relative() {
+        assert_eq!(validation_err_key(&ValidationErr::Relative(RelativeRule::Past)), "past".to_string());
+        assert_eq!(validation_err_key(&ValidationErr::Relative(RelativeRule::WithinNext(TimeSpan::days(90)))), "within_next".to_string());
+        let l = locale_en_long();
+        let p = plugin_locale_en_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(localize_validation_err(&ValidationErr::Relative(RelativeRule::Past), "birthdate", &ctx), "Must be in the past".to_string());
+        let l = locale_pt_long();
+        let p = plugin_locale_pt_long();
+        let ctx = LocalizeCtx::new(&l, &p);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Relative(RelativeRule::AtLeastAgo(TimeSpan::years(18))), "birthdate", &ctx),
+            "Deve ter sido há pelo menos 18 anos".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Relative(RelativeRule::WithinLast(TimeSpan::hours(1))), "updated_at", &ctx),
+            "Deve ser entre 1 hora atrás e agora".to_string()
+        );
+        let l = locale_es_short();
+        let p = plugin_locale_es_short();
+        let pl = locale_plural_es_short();
+        let ctx = LocalizeCtx::new(&l, &p).plural(&pl);
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Relative(RelativeRule::WithinNext(TimeSpan::days(1))), "starts_at", &ctx),
+            "Máx. dentro de 1 día".to_string()
+        );
+        assert_eq!(
+            localize_validation_err(&ValidationErr::Relative(RelativeRule::AtLeastAhead(TimeSpan::weeks(2))), "starts_at", &ctx),
+            "Mín. dentro de 2 semanas".to_string()
+        );
+    }
+}
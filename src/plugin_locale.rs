@@ -0,0 +1,887 @@
+macro_rules! plugin_locale_keys {
+    ($($key:ident),* $(,)?) => {
+        #[derive(Debug, PartialEq, Clone)]
+        pub struct PluginLocale {
+            $(pub $key: String,)*
+        }
+
+        pub const PLUGIN_LOCALE_KEYS: [&str; 74] = [$(stringify!($key)),*];
+
+        pub fn plugin_locale_fields(locale: &PluginLocale) -> [(&'static str, &String); 74] {
+            [$((stringify!($key), &locale.$key)),*]
+        }
+
+        pub fn plugin_locale_field<'a>(locale: &'a PluginLocale, key: &str) -> Option<&'a String> {
+            match key {
+                $(stringify!($key) => Some(&locale.$key),)*
+                _ => None,
+            }
+        }
+
+        pub fn plugin_locale_field_mut<'a>(locale: &'a mut PluginLocale, key: &str) -> Option<&'a mut String> {
+            match key {
+                $(stringify!($key) => Some(&mut locale.$key),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+plugin_locale_keys!(
+    pattern,
+    pattern_description,
+    cpf,
+    cnpj,
+    cep,
+    url,
+    url_scheme,
+    url_credentials,
+    ipv4,
+    ipv6,
+    ip,
+    cidr_ipv4,
+    cidr_ipv6,
+    cidr,
+    hostname,
+    uuid,
+    uuid_version,
+    ulid,
+    nano_id,
+    card_number,
+    card_brand,
+    card_expiry,
+    card_expired,
+    iban,
+    iban_country,
+    iban_length,
+    iban_checksum,
+    bic,
+    phone,
+    phone_country,
+    country_alpha2,
+    country_alpha3,
+    currency,
+    language,
+    timezone,
+    precision_minutes,
+    precision_seconds,
+    precision_millis,
+    precision_nanos,
+    date_time_offset_utc,
+    date_time_offset_required,
+    time_seconds,
+    date_operand,
+    time_operand,
+    date_time_operand,
+    past,
+    future,
+    at_least_ago,
+    within_last,
+    within_next,
+    at_least_ahead,
+    unit_year,
+    unit_years,
+    unit_month,
+    unit_months,
+    unit_week,
+    unit_weeks,
+    unit_day,
+    unit_days,
+    unit_hour,
+    unit_hours,
+    unit_minute,
+    unit_minutes,
+    unit_second,
+    unit_seconds,
+    operand_plus,
+    operand_minus,
+    duration,
+    duration_min,
+    duration_max,
+    interval,
+    interval_order,
+    interval_min,
+    interval_max,
+);
+
+pub fn plugin_locale_pt_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Deve corresponder ao padrão %value%".into(),
+        pattern_description: "Deve estar no formato %value%".into(),
+        cpf: "Deve ser um CPF válido".into(),
+        cnpj: "Deve ser um CNPJ válido".into(),
+        cep: "Deve ser um CEP válido".into(),
+        url: "Deve ser uma URL válida".into(),
+        url_scheme: "O esquema da URL deve ser um de %value%".into(),
+        url_credentials: "A URL não deve conter credenciais".into(),
+        ipv4: "Deve ser um endereço IPv4 válido".into(),
+        ipv6: "Deve ser um endereço IPv6 válido".into(),
+        ip: "Deve ser um endereço IP válido".into(),
+        cidr_ipv4: "Deve ser um bloco CIDR IPv4 válido".into(),
+        cidr_ipv6: "Deve ser um bloco CIDR IPv6 válido".into(),
+        cidr: "Deve ser um bloco CIDR válido".into(),
+        hostname: "Deve ser um nome de host válido".into(),
+        uuid: "Deve ser um UUID válido".into(),
+        uuid_version: "Deve ser um UUID da versão %value%".into(),
+        ulid: "Deve ser um ULID válido".into(),
+        nano_id: "Deve ser um Nano ID válido com %value% caracteres".into(),
+        card_number: "Deve ser um número de cartão válido".into(),
+        card_brand: "Bandeira do cartão não aceita, use %value%".into(),
+        card_expiry: "Deve ser uma validade no formato MM/AA".into(),
+        card_expired: "O cartão está vencido".into(),
+        iban: "Deve ser um IBAN válido".into(),
+        iban_country: "O país do IBAN não é suportado".into(),
+        iban_length: "O IBAN deste país deve ter %value% caracteres".into(),
+        iban_checksum: "Os dígitos verificadores do IBAN são inválidos".into(),
+        bic: "Deve ser um código BIC/SWIFT válido".into(),
+        phone: "Deve ser um número de telefone válido".into(),
+        phone_country: "O telefone deve ser de %value%".into(),
+        country_alpha2: "Deve ser um código de país válido".into(),
+        country_alpha3: "Deve ser um código de país de três letras válido".into(),
+        currency: "Deve ser um código de moeda válido".into(),
+        language: "Deve ser uma tag de idioma válida".into(),
+        timezone: "Deve ser um fuso horário válido".into(),
+        precision_minutes: "Não deve incluir segundos".into(),
+        precision_seconds: "Não deve incluir frações de segundo".into(),
+        precision_millis: "Deve ter no máximo precisão de milissegundos".into(),
+        precision_nanos: "Deve ter no máximo precisão de nanossegundos".into(),
+        date_time_offset_utc: "Deve estar em UTC (terminar em Z)".into(),
+        date_time_offset_required: "Deve incluir o deslocamento de UTC".into(),
+        time_seconds: "Deve incluir os segundos".into(),
+        date_operand: "Não pode ser comparado com %value%, que não é uma data válida".into(),
+        time_operand: "Não pode ser comparado com %value%, que não é um horário válido".into(),
+        date_time_operand: "Não pode ser comparado com %value%, que não é uma data e hora válida".into(),
+        past: "Deve estar no passado".into(),
+        future: "Deve estar no futuro".into(),
+        at_least_ago: "Deve ter sido há pelo menos %value%".into(),
+        within_last: "Deve ser entre %value% atrás e agora".into(),
+        within_next: "Deve ser entre agora e daqui a %value%".into(),
+        at_least_ahead: "Deve ser daqui a pelo menos %value%".into(),
+        unit_year: "%value% ano".into(),
+        unit_years: "%value% anos".into(),
+        unit_month: "%value% mês".into(),
+        unit_months: "%value% meses".into(),
+        unit_week: "%value% semana".into(),
+        unit_weeks: "%value% semanas".into(),
+        unit_day: "%value% dia".into(),
+        unit_days: "%value% dias".into(),
+        unit_hour: "%value% hora".into(),
+        unit_hours: "%value% horas".into(),
+        unit_minute: "%value% minuto".into(),
+        unit_minutes: "%value% minutos".into(),
+        unit_second: "%value% segundo".into(),
+        unit_seconds: "%value% segundos".into(),
+        operand_plus: "%field% mais %value%".into(),
+        operand_minus: "%field% menos %value%".into(),
+        duration: "Deve ser uma duração ISO 8601 válida".into(),
+        duration_min: "Deve ser de pelo menos %value%".into(),
+        duration_max: "Deve ser de no máximo %value%".into(),
+        interval: "Deve ser um intervalo ISO 8601 válido".into(),
+        interval_order: "O início deve ser anterior ao fim".into(),
+        interval_min: "Deve abranger pelo menos %value%".into(),
+        interval_max: "Deve abranger no máximo %value%".into(),
+    }
+}
+
+pub fn plugin_locale_es_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Debe coincidir con el patrón %value%".into(),
+        pattern_description: "Debe tener el formato %value%".into(),
+        cpf: "Debe ser un CPF válido".into(),
+        cnpj: "Debe ser un CNPJ válido".into(),
+        cep: "Debe ser un CEP válido".into(),
+        url: "Debe ser una URL válida".into(),
+        url_scheme: "El esquema de la URL debe ser uno de %value%".into(),
+        url_credentials: "La URL no debe contener credenciales".into(),
+        ipv4: "Debe ser una dirección IPv4 válida".into(),
+        ipv6: "Debe ser una dirección IPv6 válida".into(),
+        ip: "Debe ser una dirección IP válida".into(),
+        cidr_ipv4: "Debe ser un bloque CIDR IPv4 válido".into(),
+        cidr_ipv6: "Debe ser un bloque CIDR IPv6 válido".into(),
+        cidr: "Debe ser un bloque CIDR válido".into(),
+        hostname: "Debe ser un nombre de host válido".into(),
+        uuid: "Debe ser un UUID válido".into(),
+        uuid_version: "Debe ser un UUID de la versión %value%".into(),
+        ulid: "Debe ser un ULID válido".into(),
+        nano_id: "Debe ser un Nano ID válido de %value% caracteres".into(),
+        card_number: "Debe ser un número de tarjeta válido".into(),
+        card_brand: "Marca de tarjeta no aceptada, use %value%".into(),
+        card_expiry: "Debe ser un vencimiento con el formato MM/AA".into(),
+        card_expired: "La tarjeta está vencida".into(),
+        iban: "Debe ser un IBAN válido".into(),
+        iban_country: "El país del IBAN no es compatible".into(),
+        iban_length: "El IBAN de este país debe tener %value% caracteres".into(),
+        iban_checksum: "Los dígitos de control del IBAN no son válidos".into(),
+        bic: "Debe ser un código BIC/SWIFT válido".into(),
+        phone: "Debe ser un número de teléfono válido".into(),
+        phone_country: "El teléfono debe ser de %value%".into(),
+        country_alpha2: "Debe ser un código de país válido".into(),
+        country_alpha3: "Debe ser un código de país de tres letras válido".into(),
+        currency: "Debe ser un código de moneda válido".into(),
+        language: "Debe ser una etiqueta de idioma válida".into(),
+        timezone: "Debe ser una zona horaria válida".into(),
+        precision_minutes: "No debe incluir segundos".into(),
+        precision_seconds: "No debe incluir fracciones de segundo".into(),
+        precision_millis: "Debe tener como máximo precisión de milisegundos".into(),
+        precision_nanos: "Debe tener como máximo precisión de nanosegundos".into(),
+        date_time_offset_utc: "Debe estar en UTC (terminar en Z)".into(),
+        date_time_offset_required: "Debe incluir el desfase respecto a UTC".into(),
+        time_seconds: "Debe incluir los segundos".into(),
+        date_operand: "No se puede comparar con %value%, que no es una fecha válida".into(),
+        time_operand: "No se puede comparar con %value%, que no es una hora válida".into(),
+        date_time_operand: "No se puede comparar con %value%, que no es una fecha y hora válida".into(),
+        past: "Debe estar en el pasado".into(),
+        future: "Debe estar en el futuro".into(),
+        at_least_ago: "Debe ser de hace al menos %value%".into(),
+        within_last: "Debe ser entre hace %value% y ahora".into(),
+        within_next: "Debe ser entre ahora y dentro de %value%".into(),
+        at_least_ahead: "Debe ser dentro de al menos %value%".into(),
+        unit_year: "%value% año".into(),
+        unit_years: "%value% años".into(),
+        unit_month: "%value% mes".into(),
+        unit_months: "%value% meses".into(),
+        unit_week: "%value% semana".into(),
+        unit_weeks: "%value% semanas".into(),
+        unit_day: "%value% día".into(),
+        unit_days: "%value% días".into(),
+        unit_hour: "%value% hora".into(),
+        unit_hours: "%value% horas".into(),
+        unit_minute: "%value% minuto".into(),
+        unit_minutes: "%value% minutos".into(),
+        unit_second: "%value% segundo".into(),
+        unit_seconds: "%value% segundos".into(),
+        operand_plus: "%field% más %value%".into(),
+        operand_minus: "%field% menos %value%".into(),
+        duration: "Debe ser una duración ISO 8601 válida".into(),
+        duration_min: "Debe ser de al menos %value%".into(),
+        duration_max: "Debe ser de como máximo %value%".into(),
+        interval: "Debe ser un intervalo ISO 8601 válido".into(),
+        interval_order: "El inicio debe ser anterior al fin".into(),
+        interval_min: "Debe abarcar al menos %value%".into(),
+        interval_max: "Debe abarcar como máximo %value%".into(),
+    }
+}
+
+pub fn plugin_locale_en_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Must match the pattern %value%".into(),
+        pattern_description: "Must be in the format %value%".into(),
+        cpf: "Must be a valid CPF".into(),
+        cnpj: "Must be a valid CNPJ".into(),
+        cep: "Must be a valid CEP".into(),
+        url: "Must be a valid URL".into(),
+        url_scheme: "The URL scheme must be one of %value%".into(),
+        url_credentials: "The URL must not contain credentials".into(),
+        ipv4: "Must be a valid IPv4 address".into(),
+        ipv6: "Must be a valid IPv6 address".into(),
+        ip: "Must be a valid IP address".into(),
+        cidr_ipv4: "Must be a valid IPv4 CIDR block".into(),
+        cidr_ipv6: "Must be a valid IPv6 CIDR block".into(),
+        cidr: "Must be a valid CIDR block".into(),
+        hostname: "Must be a valid hostname".into(),
+        uuid: "Must be a valid UUID".into(),
+        uuid_version: "Must be a UUID of version %value%".into(),
+        ulid: "Must be a valid ULID".into(),
+        nano_id: "Must be a valid Nano ID with %value% characters".into(),
+        card_number: "Must be a valid card number".into(),
+        card_brand: "Card brand not accepted, use one of %value%".into(),
+        card_expiry: "Must be a valid expiry date in the format MM/YY".into(),
+        card_expired: "The card is expired".into(),
+        iban: "Must be a valid IBAN".into(),
+        iban_country: "The IBAN country is not supported".into(),
+        iban_length: "The IBAN for this country must have %value% characters".into(),
+        iban_checksum: "The IBAN check digits are invalid".into(),
+        bic: "Must be a valid BIC/SWIFT code".into(),
+        phone: "Must be a valid phone number".into(),
+        phone_country: "The phone number must be from %value%".into(),
+        country_alpha2: "Must be a valid country code".into(),
+        country_alpha3: "Must be a valid three-letter country code".into(),
+        currency: "Must be a valid currency code".into(),
+        language: "Must be a valid language tag".into(),
+        timezone: "Must be a valid time zone".into(),
+        precision_minutes: "Must not include seconds".into(),
+        precision_seconds: "Must not include fractional seconds".into(),
+        precision_millis: "Must have at most millisecond precision".into(),
+        precision_nanos: "Must have at most nanosecond precision".into(),
+        date_time_offset_utc: "Must be in UTC (ending in Z)".into(),
+        date_time_offset_required: "Must include a UTC offset".into(),
+        time_seconds: "Must include seconds".into(),
+        date_operand: "Cannot be compared with %value%, which is not a valid date".into(),
+        time_operand: "Cannot be compared with %value%, which is not a valid time".into(),
+        date_time_operand: "Cannot be compared with %value%, which is not a valid date and time".into(),
+        past: "Must be in the past".into(),
+        future: "Must be in the future".into(),
+        at_least_ago: "Must be at least %value% ago".into(),
+        within_last: "Must be within the last %value%".into(),
+        within_next: "Must be within the next %value%".into(),
+        at_least_ahead: "Must be at least %value% from now".into(),
+        unit_year: "%value% year".into(),
+        unit_years: "%value% years".into(),
+        unit_month: "%value% month".into(),
+        unit_months: "%value% months".into(),
+        unit_week: "%value% week".into(),
+        unit_weeks: "%value% weeks".into(),
+        unit_day: "%value% day".into(),
+        unit_days: "%value% days".into(),
+        unit_hour: "%value% hour".into(),
+        unit_hours: "%value% hours".into(),
+        unit_minute: "%value% minute".into(),
+        unit_minutes: "%value% minutes".into(),
+        unit_second: "%value% second".into(),
+        unit_seconds: "%value% seconds".into(),
+        operand_plus: "%field% plus %value%".into(),
+        operand_minus: "%field% minus %value%".into(),
+        duration: "Must be a valid ISO 8601 duration".into(),
+        duration_min: "Must be at least %value%".into(),
+        duration_max: "Must be at most %value%".into(),
+        interval: "Must be a valid ISO 8601 interval".into(),
+        interval_order: "The start must be before the end".into(),
+        interval_min: "Must span at least %value%".into(),
+        interval_max: "Must span at most %value%".into(),
+    }
+}
+
+pub fn plugin_locale_fr_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Doit correspondre au motif %value%".into(),
+        pattern_description: "Doit être au format %value%".into(),
+        cpf: "Doit être un CPF valide".into(),
+        cnpj: "Doit être un CNPJ valide".into(),
+        cep: "Doit être un CEP valide".into(),
+        url: "Doit être une URL valide".into(),
+        url_scheme: "Le schéma de l'URL doit être l'un de %value%".into(),
+        url_credentials: "L'URL ne doit pas contenir d'identifiants".into(),
+        ipv4: "Doit être une adresse IPv4 valide".into(),
+        ipv6: "Doit être une adresse IPv6 valide".into(),
+        ip: "Doit être une adresse IP valide".into(),
+        cidr_ipv4: "Doit être un bloc CIDR IPv4 valide".into(),
+        cidr_ipv6: "Doit être un bloc CIDR IPv6 valide".into(),
+        cidr: "Doit être un bloc CIDR valide".into(),
+        hostname: "Doit être un nom d'hôte valide".into(),
+        uuid: "Doit être un UUID valide".into(),
+        uuid_version: "Doit être un UUID de version %value%".into(),
+        ulid: "Doit être un ULID valide".into(),
+        nano_id: "Doit être un Nano ID valide de %value% caractères".into(),
+        card_number: "Doit être un numéro de carte valide".into(),
+        card_brand: "Marque de carte non acceptée, utilisez %value%".into(),
+        card_expiry: "Doit être une date d'expiration au format MM/AA".into(),
+        card_expired: "La carte est expirée".into(),
+        iban: "Doit être un IBAN valide".into(),
+        iban_country: "Le pays de l'IBAN n'est pas pris en charge".into(),
+        iban_length: "L'IBAN de ce pays doit comporter %value% caractères".into(),
+        iban_checksum: "Les chiffres de contrôle de l'IBAN sont invalides".into(),
+        bic: "Doit être un code BIC/SWIFT valide".into(),
+        phone: "Doit être un numéro de téléphone valide".into(),
+        phone_country: "Le numéro de téléphone doit provenir de %value%".into(),
+        country_alpha2: "Doit être un code pays valide".into(),
+        country_alpha3: "Doit être un code pays à trois lettres valide".into(),
+        currency: "Doit être un code de devise valide".into(),
+        language: "Doit être une balise de langue valide".into(),
+        timezone: "Doit être un fuseau horaire valide".into(),
+        precision_minutes: "Ne doit pas inclure de secondes".into(),
+        precision_seconds: "Ne doit pas inclure de fractions de seconde".into(),
+        precision_millis: "Doit avoir au plus une précision à la milliseconde".into(),
+        precision_nanos: "Doit avoir au plus une précision à la nanoseconde".into(),
+        date_time_offset_utc: "Doit être en UTC (se terminer par Z)".into(),
+        date_time_offset_required: "Doit inclure un décalage UTC".into(),
+        time_seconds: "Doit inclure les secondes".into(),
+        date_operand: "Ne peut pas être comparé à %value%, qui n'est pas une date valide".into(),
+        time_operand: "Ne peut pas être comparé à %value%, qui n'est pas une heure valide".into(),
+        date_time_operand: "Ne peut pas être comparé à %value%, qui n'est pas une date et heure valide".into(),
+        past: "Doit être dans le passé".into(),
+        future: "Doit être dans le futur".into(),
+        at_least_ago: "Doit dater d'il y a au moins %value%".into(),
+        within_last: "Doit être entre il y a %value% et maintenant".into(),
+        within_next: "Doit être entre maintenant et dans %value%".into(),
+        at_least_ahead: "Doit être dans au moins %value%".into(),
+        unit_year: "%value% an".into(),
+        unit_years: "%value% ans".into(),
+        unit_month: "%value% mois".into(),
+        unit_months: "%value% mois".into(),
+        unit_week: "%value% semaine".into(),
+        unit_weeks: "%value% semaines".into(),
+        unit_day: "%value% jour".into(),
+        unit_days: "%value% jours".into(),
+        unit_hour: "%value% heure".into(),
+        unit_hours: "%value% heures".into(),
+        unit_minute: "%value% minute".into(),
+        unit_minutes: "%value% minutes".into(),
+        unit_second: "%value% seconde".into(),
+        unit_seconds: "%value% secondes".into(),
+        operand_plus: "%field% plus %value%".into(),
+        operand_minus: "%field% moins %value%".into(),
+        duration: "Doit être une durée ISO 8601 valide".into(),
+        duration_min: "Doit être d'au moins %value%".into(),
+        duration_max: "Doit être d'au plus %value%".into(),
+        interval: "Doit être un intervalle ISO 8601 valide".into(),
+        interval_order: "Le début doit précéder la fin".into(),
+        interval_min: "Doit couvrir au moins %value%".into(),
+        interval_max: "Doit couvrir au plus %value%".into(),
+    }
+}
+
+pub fn plugin_locale_de_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Muss dem Muster %value% entsprechen".into(),
+        pattern_description: "Muss das Format %value% haben".into(),
+        cpf: "Muss eine gültige CPF sein".into(),
+        cnpj: "Muss eine gültige CNPJ sein".into(),
+        cep: "Muss eine gültige CEP sein".into(),
+        url: "Muss eine gültige URL sein".into(),
+        url_scheme: "Das URL-Schema muss eines von %value% sein".into(),
+        url_credentials: "Die URL darf keine Zugangsdaten enthalten".into(),
+        ipv4: "Muss eine gültige IPv4-Adresse sein".into(),
+        ipv6: "Muss eine gültige IPv6-Adresse sein".into(),
+        ip: "Muss eine gültige IP-Adresse sein".into(),
+        cidr_ipv4: "Muss ein gültiger IPv4-CIDR-Block sein".into(),
+        cidr_ipv6: "Muss ein gültiger IPv6-CIDR-Block sein".into(),
+        cidr: "Muss ein gültiger CIDR-Block sein".into(),
+        hostname: "Muss ein gültiger Hostname sein".into(),
+        uuid: "Muss eine gültige UUID sein".into(),
+        uuid_version: "Muss eine UUID der Version %value% sein".into(),
+        ulid: "Muss eine gültige ULID sein".into(),
+        nano_id: "Muss eine gültige Nano ID mit %value% Zeichen sein".into(),
+        card_number: "Muss eine gültige Kartennummer sein".into(),
+        card_brand: "Kartenmarke nicht akzeptiert, verwenden Sie %value%".into(),
+        card_expiry: "Muss ein gültiges Ablaufdatum im Format MM/JJ sein".into(),
+        card_expired: "Die Karte ist abgelaufen".into(),
+        iban: "Muss eine gültige IBAN sein".into(),
+        iban_country: "Das Land der IBAN wird nicht unterstützt".into(),
+        iban_length: "Die IBAN dieses Landes muss %value% Zeichen haben".into(),
+        iban_checksum: "Die Prüfziffern der IBAN sind ungültig".into(),
+        bic: "Muss ein gültiger BIC/SWIFT-Code sein".into(),
+        phone: "Muss eine gültige Telefonnummer sein".into(),
+        phone_country: "Die Telefonnummer muss aus %value% stammen".into(),
+        country_alpha2: "Muss ein gültiger Ländercode sein".into(),
+        country_alpha3: "Muss ein gültiger dreistelliger Ländercode sein".into(),
+        currency: "Muss ein gültiger Währungscode sein".into(),
+        language: "Muss ein gültiges Sprachkennzeichen sein".into(),
+        timezone: "Muss eine gültige Zeitzone sein".into(),
+        precision_minutes: "Darf keine Sekunden enthalten".into(),
+        precision_seconds: "Darf keine Sekundenbruchteile enthalten".into(),
+        precision_millis: "Darf höchstens Millisekundengenauigkeit haben".into(),
+        precision_nanos: "Darf höchstens Nanosekundengenauigkeit haben".into(),
+        date_time_offset_utc: "Muss in UTC sein (mit Z enden)".into(),
+        date_time_offset_required: "Muss einen UTC-Versatz enthalten".into(),
+        time_seconds: "Muss Sekunden enthalten".into(),
+        date_operand: "Kann nicht mit %value% verglichen werden, das kein gültiges Datum ist".into(),
+        time_operand: "Kann nicht mit %value% verglichen werden, das keine gültige Uhrzeit ist".into(),
+        date_time_operand: "Kann nicht mit %value% verglichen werden, das kein gültiges Datum mit Uhrzeit ist".into(),
+        past: "Muss in der Vergangenheit liegen".into(),
+        future: "Muss in der Zukunft liegen".into(),
+        at_least_ago: "Muss mindestens %value% zurückliegen".into(),
+        within_last: "Muss innerhalb der letzten %value% liegen".into(),
+        within_next: "Muss innerhalb der nächsten %value% liegen".into(),
+        at_least_ahead: "Muss mindestens %value% in der Zukunft liegen".into(),
+        unit_year: "%value% Jahr".into(),
+        unit_years: "%value% Jahre".into(),
+        unit_month: "%value% Monat".into(),
+        unit_months: "%value% Monate".into(),
+        unit_week: "%value% Woche".into(),
+        unit_weeks: "%value% Wochen".into(),
+        unit_day: "%value% Tag".into(),
+        unit_days: "%value% Tage".into(),
+        unit_hour: "%value% Stunde".into(),
+        unit_hours: "%value% Stunden".into(),
+        unit_minute: "%value% Minute".into(),
+        unit_minutes: "%value% Minuten".into(),
+        unit_second: "%value% Sekunde".into(),
+        unit_seconds: "%value% Sekunden".into(),
+        operand_plus: "%field% plus %value%".into(),
+        operand_minus: "%field% minus %value%".into(),
+        duration: "Muss eine gültige ISO-8601-Dauer sein".into(),
+        duration_min: "Muss mindestens %value% betragen".into(),
+        duration_max: "Darf höchstens %value% betragen".into(),
+        interval: "Muss ein gültiges ISO-8601-Intervall sein".into(),
+        interval_order: "Der Beginn muss vor dem Ende liegen".into(),
+        interval_min: "Muss mindestens %value% umfassen".into(),
+        interval_max: "Darf höchstens %value% umfassen".into(),
+    }
+}
+
+pub fn plugin_locale_it_long() -> PluginLocale {
+    PluginLocale {
+        pattern: "Deve corrispondere al modello %value%".into(),
+        pattern_description: "Deve essere nel formato %value%".into(),
+        cpf: "Deve essere un CPF valido".into(),
+        cnpj: "Deve essere un CNPJ valido".into(),
+        cep: "Deve essere un CEP valido".into(),
+        url: "Deve essere un URL valido".into(),
+        url_scheme: "Lo schema dell'URL deve essere uno tra %value%".into(),
+        url_credentials: "L'URL non deve contenere credenziali".into(),
+        ipv4: "Deve essere un indirizzo IPv4 valido".into(),
+        ipv6: "Deve essere un indirizzo IPv6 valido".into(),
+        ip: "Deve essere un indirizzo IP valido".into(),
+        cidr_ipv4: "Deve essere un blocco CIDR IPv4 valido".into(),
+        cidr_ipv6: "Deve essere un blocco CIDR IPv6 valido".into(),
+        cidr: "Deve essere un blocco CIDR valido".into(),
+        hostname: "Deve essere un nome host valido".into(),
+        uuid: "Deve essere un UUID valido".into(),
+        uuid_version: "Deve essere un UUID della versione %value%".into(),
+        ulid: "Deve essere un ULID valido".into(),
+        nano_id: "Deve essere un Nano ID valido di %value% caratteri".into(),
+        card_number: "Deve essere un numero di carta valido".into(),
+        card_brand: "Circuito della carta non accettato, usa %value%".into(),
+        card_expiry: "Deve essere una scadenza nel formato MM/AA".into(),
+        card_expired: "La carta è scaduta".into(),
+        iban: "Deve essere un IBAN valido".into(),
+        iban_country: "Il paese dell'IBAN non è supportato".into(),
+        iban_length: "L'IBAN di questo paese deve avere %value% caratteri".into(),
+        iban_checksum: "Le cifre di controllo dell'IBAN non sono valide".into(),
+        bic: "Deve essere un codice BIC/SWIFT valido".into(),
+        phone: "Deve essere un numero di telefono valido".into(),
+        phone_country: "Il numero di telefono deve essere di %value%".into(),
+        country_alpha2: "Deve essere un codice paese valido".into(),
+        country_alpha3: "Deve essere un codice paese di tre lettere valido".into(),
+        currency: "Deve essere un codice valuta valido".into(),
+        language: "Deve essere un tag di lingua valido".into(),
+        timezone: "Deve essere un fuso orario valido".into(),
+        precision_minutes: "Non deve includere i secondi".into(),
+        precision_seconds: "Non deve includere frazioni di secondo".into(),
+        precision_millis: "Deve avere al massimo una precisione al millisecondo".into(),
+        precision_nanos: "Deve avere al massimo una precisione al nanosecondo".into(),
+        date_time_offset_utc: "Deve essere in UTC (terminare con Z)".into(),
+        date_time_offset_required: "Deve includere uno scostamento UTC".into(),
+        time_seconds: "Deve includere i secondi".into(),
+        date_operand: "Non può essere confrontato con %value%, che non è una data valida".into(),
+        time_operand: "Non può essere confrontato con %value%, che non è un orario valido".into(),
+        date_time_operand: "Non può essere confrontato con %value%, che non è una data e ora valida".into(),
+        past: "Deve essere nel passato".into(),
+        future: "Deve essere nel futuro".into(),
+        at_least_ago: "Deve risalire ad almeno %value% fa".into(),
+        within_last: "Deve essere tra %value% fa e adesso".into(),
+        within_next: "Deve essere tra adesso e fra %value%".into(),
+        at_least_ahead: "Deve essere fra almeno %value%".into(),
+        unit_year: "%value% anno".into(),
+        unit_years: "%value% anni".into(),
+        unit_month: "%value% mese".into(),
+        unit_months: "%value% mesi".into(),
+        unit_week: "%value% settimana".into(),
+        unit_weeks: "%value% settimane".into(),
+        unit_day: "%value% giorno".into(),
+        unit_days: "%value% giorni".into(),
+        unit_hour: "%value% ora".into(),
+        unit_hours: "%value% ore".into(),
+        unit_minute: "%value% minuto".into(),
+        unit_minutes: "%value% minuti".into(),
+        unit_second: "%value% secondo".into(),
+        unit_seconds: "%value% secondi".into(),
+        operand_plus: "%field% più %value%".into(),
+        operand_minus: "%field% meno %value%".into(),
+        duration: "Deve essere una durata ISO 8601 valida".into(),
+        duration_min: "Deve essere di almeno %value%".into(),
+        duration_max: "Deve essere di al massimo %value%".into(),
+        interval: "Deve essere un intervallo ISO 8601 valido".into(),
+        interval_order: "L'inizio deve precedere la fine".into(),
+        interval_min: "Deve coprire almeno %value%".into(),
+        interval_max: "Deve coprire al massimo %value%".into(),
+    }
+}
+
+pub fn plugin_locale_pt_short() -> PluginLocale {
+    PluginLocale {
+        pattern: "Padrão %value%".into(),
+        pattern_description: "Formato %value%".into(),
+        cpf: "CPF inválido".into(),
+        cnpj: "CNPJ inválido".into(),
+        cep: "CEP inválido".into(),
+        url: "URL inválida".into(),
+        url_scheme: "Esquema %value%".into(),
+        url_credentials: "URL com credenciais".into(),
+        ipv4: "IPv4 inválido".into(),
+        ipv6: "IPv6 inválido".into(),
+        ip: "IP inválido".into(),
+        cidr_ipv4: "CIDR IPv4 inválido".into(),
+        cidr_ipv6: "CIDR IPv6 inválido".into(),
+        cidr: "CIDR inválido".into(),
+        hostname: "Host inválido".into(),
+        uuid: "UUID inválido".into(),
+        uuid_version: "UUID versão %value%".into(),
+        ulid: "ULID inválido".into(),
+        nano_id: "Nano ID de %value% caracteres".into(),
+        card_number: "Cartão inválido".into(),
+        card_brand: "Bandeira %value%".into(),
+        card_expiry: "Validade MM/AA".into(),
+        card_expired: "Cartão vencido".into(),
+        iban: "IBAN inválido".into(),
+        iban_country: "País do IBAN desconhecido".into(),
+        iban_length: "IBAN de %value% caracteres".into(),
+        iban_checksum: "Dígitos do IBAN inválidos".into(),
+        bic: "BIC inválido".into(),
+        phone: "Telefone inválido".into(),
+        phone_country: "Telefone de %value%".into(),
+        country_alpha2: "País inválido".into(),
+        country_alpha3: "País inválido".into(),
+        currency: "Moeda inválida".into(),
+        language: "Idioma inválido".into(),
+        timezone: "Fuso inválido".into(),
+        precision_minutes: "Segundos não permitidos".into(),
+        precision_seconds: "Frações de segundo não permitidas".into(),
+        precision_millis: "Precisão máxima: milissegundos".into(),
+        precision_nanos: "Precisão máxima: nanossegundos".into(),
+        date_time_offset_utc: "Deve estar em UTC".into(),
+        date_time_offset_required: "Deslocamento UTC obrigatório".into(),
+        time_seconds: "Segundos obrigatórios".into(),
+        date_operand: "Data de comparação inválida: %value%".into(),
+        time_operand: "Horário de comparação inválido: %value%".into(),
+        date_time_operand: "Data e hora de comparação inválida: %value%".into(),
+        past: "No passado".into(),
+        future: "No futuro".into(),
+        at_least_ago: "Mín. %value% atrás".into(),
+        within_last: "Máx. %value% atrás".into(),
+        within_next: "Máx. daqui a %value%".into(),
+        at_least_ahead: "Mín. daqui a %value%".into(),
+        unit_year: "%value% ano".into(),
+        unit_years: "%value% anos".into(),
+        unit_month: "%value% mês".into(),
+        unit_months: "%value% meses".into(),
+        unit_week: "%value% semana".into(),
+        unit_weeks: "%value% semanas".into(),
+        unit_day: "%value% dia".into(),
+        unit_days: "%value% dias".into(),
+        unit_hour: "%value% hora".into(),
+        unit_hours: "%value% horas".into(),
+        unit_minute: "%value% minuto".into(),
+        unit_minutes: "%value% minutos".into(),
+        unit_second: "%value% segundo".into(),
+        unit_seconds: "%value% segundos".into(),
+        operand_plus: "%field% mais %value%".into(),
+        operand_minus: "%field% menos %value%".into(),
+        duration: "Duração inválida".into(),
+        duration_min: "Mín. %value%".into(),
+        duration_max: "Máx. %value%".into(),
+        interval: "Intervalo inválido".into(),
+        interval_order: "Início após o fim".into(),
+        interval_min: "Mín. %value%".into(),
+        interval_max: "Máx. %value%".into(),
+    }
+}
+
+pub fn plugin_locale_es_short() -> PluginLocale {
+    PluginLocale {
+        pattern: "Patrón %value%".into(),
+        pattern_description: "Formato %value%".into(),
+        cpf: "CPF inválido".into(),
+        cnpj: "CNPJ inválido".into(),
+        cep: "CEP inválido".into(),
+        url: "URL inválida".into(),
+        url_scheme: "Esquema %value%".into(),
+        url_credentials: "URL con credenciales".into(),
+        ipv4: "IPv4 inválida".into(),
+        ipv6: "IPv6 inválida".into(),
+        ip: "IP inválida".into(),
+        cidr_ipv4: "CIDR IPv4 inválido".into(),
+        cidr_ipv6: "CIDR IPv6 inválido".into(),
+        cidr: "CIDR inválido".into(),
+        hostname: "Host inválido".into(),
+        uuid: "UUID inválido".into(),
+        uuid_version: "UUID versión %value%".into(),
+        ulid: "ULID inválido".into(),
+        nano_id: "Nano ID de %value% caracteres".into(),
+        card_number: "Tarjeta inválida".into(),
+        card_brand: "Marca %value%".into(),
+        card_expiry: "Vencimiento MM/AA".into(),
+        card_expired: "Tarjeta vencida".into(),
+        iban: "IBAN inválido".into(),
+        iban_country: "País del IBAN desconocido".into(),
+        iban_length: "IBAN de %value% caracteres".into(),
+        iban_checksum: "Dígitos del IBAN inválidos".into(),
+        bic: "BIC inválido".into(),
+        phone: "Teléfono inválido".into(),
+        phone_country: "Teléfono de %value%".into(),
+        country_alpha2: "País inválido".into(),
+        country_alpha3: "País inválido".into(),
+        currency: "Moneda inválida".into(),
+        language: "Idioma inválido".into(),
+        timezone: "Zona horaria inválida".into(),
+        precision_minutes: "Segundos no permitidos".into(),
+        precision_seconds: "Fracciones de segundo no permitidas".into(),
+        precision_millis: "Precisión máxima: milisegundos".into(),
+        precision_nanos: "Precisión máxima: nanosegundos".into(),
+        date_time_offset_utc: "Debe estar en UTC".into(),
+        date_time_offset_required: "Desfase UTC obligatorio".into(),
+        time_seconds: "Segundos obligatorios".into(),
+        date_operand: "Fecha de comparación inválida: %value%".into(),
+        time_operand: "Hora de comparación inválida: %value%".into(),
+        date_time_operand: "Fecha y hora de comparación inválida: %value%".into(),
+        past: "En el pasado".into(),
+        future: "En el futuro".into(),
+        at_least_ago: "Mín. hace %value%".into(),
+        within_last: "Máx. hace %value%".into(),
+        within_next: "Máx. dentro de %value%".into(),
+        at_least_ahead: "Mín. dentro de %value%".into(),
+        unit_year: "%value% año".into(),
+        unit_years: "%value% años".into(),
+        unit_month: "%value% mes".into(),
+        unit_months: "%value% meses".into(),
+        unit_week: "%value% semana".into(),
+        unit_weeks: "%value% semanas".into(),
+        unit_day: "%value% día".into(),
+        unit_days: "%value% días".into(),
+        unit_hour: "%value% hora".into(),
+        unit_hours: "%value% horas".into(),
+        unit_minute: "%value% minuto".into(),
+        unit_minutes: "%value% minutos".into(),
+        unit_second: "%value% segundo".into(),
+        unit_seconds: "%value% segundos".into(),
+        operand_plus: "%field% más %value%".into(),
+        operand_minus: "%field% menos %value%".into(),
+        duration: "Duración inválida".into(),
+        duration_min: "Mín. %value%".into(),
+        duration_max: "Máx. %value%".into(),
+        interval: "Intervalo inválido".into(),
+        interval_order: "Inicio tras el fin".into(),
+        interval_min: "Mín. %value%".into(),
+        interval_max: "Máx. %value%".into(),
+    }
+}
+
+pub fn plugin_locale_en_short() -> PluginLocale {
+    PluginLocale {
+        pattern: "Pattern %value%".into(),
+        pattern_description: "Format %value%".into(),
+        cpf: "Invalid CPF".into(),
+        cnpj: "Invalid CNPJ".into(),
+        cep: "Invalid CEP".into(),
+        url: "Invalid URL".into(),
+        url_scheme: "Scheme %value%".into(),
+        url_credentials: "URL with credentials".into(),
+        ipv4: "Invalid IPv4".into(),
+        ipv6: "Invalid IPv6".into(),
+        ip: "Invalid IP".into(),
+        cidr_ipv4: "Invalid IPv4 CIDR".into(),
+        cidr_ipv6: "Invalid IPv6 CIDR".into(),
+        cidr: "Invalid CIDR".into(),
+        hostname: "Invalid hostname".into(),
+        uuid: "Invalid UUID".into(),
+        uuid_version: "UUID version %value%".into(),
+        ulid: "Invalid ULID".into(),
+        nano_id: "Nano ID of %value% characters".into(),
+        card_number: "Invalid card number".into(),
+        card_brand: "Brand %value%".into(),
+        card_expiry: "Expiry MM/YY".into(),
+        card_expired: "Card expired".into(),
+        iban: "Invalid IBAN".into(),
+        iban_country: "Unknown IBAN country".into(),
+        iban_length: "IBAN of %value% characters".into(),
+        iban_checksum: "Invalid IBAN check digits".into(),
+        bic: "Invalid BIC".into(),
+        phone: "Invalid phone".into(),
+        phone_country: "Phone from %value%".into(),
+        country_alpha2: "Invalid country".into(),
+        country_alpha3: "Invalid country".into(),
+        currency: "Invalid currency".into(),
+        language: "Invalid language".into(),
+        timezone: "Invalid time zone".into(),
+        precision_minutes: "Seconds not allowed".into(),
+        precision_seconds: "Fractional seconds not allowed".into(),
+        precision_millis: "Max precision: milliseconds".into(),
+        precision_nanos: "Max precision: nanoseconds".into(),
+        date_time_offset_utc: "Must be UTC".into(),
+        date_time_offset_required: "UTC offset required".into(),
+        time_seconds: "Seconds required".into(),
+        date_operand: "Invalid comparison date: %value%".into(),
+        time_operand: "Invalid comparison time: %value%".into(),
+        date_time_operand: "Invalid comparison date and time: %value%".into(),
+        past: "In the past".into(),
+        future: "In the future".into(),
+        at_least_ago: "Min. %value% ago".into(),
+        within_last: "Max. %value% ago".into(),
+        within_next: "Max. %value% ahead".into(),
+        at_least_ahead: "Min. %value% ahead".into(),
+        unit_year: "%value% year".into(),
+        unit_years: "%value% years".into(),
+        unit_month: "%value% month".into(),
+        unit_months: "%value% months".into(),
+        unit_week: "%value% week".into(),
+        unit_weeks: "%value% weeks".into(),
+        unit_day: "%value% day".into(),
+        unit_days: "%value% days".into(),
+        unit_hour: "%value% hour".into(),
+        unit_hours: "%value% hours".into(),
+        unit_minute: "%value% minute".into(),
+        unit_minutes: "%value% minutes".into(),
+        unit_second: "%value% second".into(),
+        unit_seconds: "%value% seconds".into(),
+        operand_plus: "%field% plus %value%".into(),
+        operand_minus: "%field% minus %value%".into(),
+        duration: "Invalid duration".into(),
+        duration_min: "Min. %value%".into(),
+        duration_max: "Max. %value%".into(),
+        interval: "Invalid interval".into(),
+        interval_order: "Start after end".into(),
+        interval_min: "Min. %value%".into(),
+        interval_max: "Max. %value%".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::locale::message_placeholders;
+
+    use super::{
+        PLUGIN_LOCALE_KEYS, plugin_locale_de_long, plugin_locale_en_long, plugin_locale_en_short, plugin_locale_es_long, plugin_locale_es_short,
+        plugin_locale_field, plugin_locale_field_mut, plugin_locale_fields, plugin_locale_fr_long, plugin_locale_it_long, plugin_locale_pt_long,
+        plugin_locale_pt_short,
+    };
+
+    #[test]
+    fn plugin_locale_fields_filled() {
+        for l in [
+            plugin_locale_pt_long(),
+            plugin_locale_es_long(),
+            plugin_locale_en_long(),
+            plugin_locale_fr_long(),
+            plugin_locale_de_long(),
+            plugin_locale_it_long(),
+            plugin_locale_pt_short(),
+            plugin_locale_es_short(),
+            plugin_locale_en_short(),
+        ] {
+            for (key, message) in plugin_locale_fields(&l) {
+                assert!(!message.trim().is_empty(), "{key}");
+            }
+        }
+    }
+
+    #[test]
+    fn plugin_locale_placeholders_match() {
+        let en = plugin_locale_en_long();
+        for l in [
+            plugin_locale_pt_long(),
+            plugin_locale_es_long(),
+            plugin_locale_fr_long(),
+            plugin_locale_de_long(),
+            plugin_locale_it_long(),
+            plugin_locale_pt_short(),
+            plugin_locale_es_short(),
+            plugin_locale_en_short(),
+        ] {
+            for ((key, message), (_, en_message)) in plugin_locale_fields(&l).into_iter().zip(plugin_locale_fields(&en)) {
+                assert_eq!(message_placeholders(message), message_placeholders(en_message), "{key}");
+            }
+        }
+    }
+
+    #[test]
+    fn plugin_locale_field_keys() {
+        let l = plugin_locale_pt_long();
+        for (key, message) in plugin_locale_fields(&l) {
+            assert_eq!(plugin_locale_field(&l, key), Some(message));
+        }
+        assert_eq!(plugin_locale_field(&l, "required"), None);
+    }
+
+    #[test]
+    fn plugin_locale_field_mut_keys() {
+        let mut l = plugin_locale_en_long();
+        for key in PLUGIN_LOCALE_KEYS {
+            *plugin_locale_field_mut(&mut l, key).unwrap() = key.to_uppercase();
+        }
+        for (key, message) in plugin_locale_fields(&l) {
+            assert_eq!(message, &key.to_uppercase());
+        }
+        assert_eq!(plugin_locale_field_mut(&mut l, "required"), None);
+        assert_eq!(plugin_locale_field_mut(&mut l, ""), None);
+    }
+}
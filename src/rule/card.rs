@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    Elo,
+    Hipercard,
+    Diners,
+    Discover,
+    Jcb,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CardRule {
+    pub brands: Vec<CardBrand>,
+}
+
+impl CardBrand {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard => "Mastercard",
+            CardBrand::Amex => "American Express",
+            CardBrand::Elo => "Elo",
+            CardBrand::Hipercard => "Hipercard",
+            CardBrand::Diners => "Diners Club",
+            CardBrand::Discover => "Discover",
+            CardBrand::Jcb => "JCB",
+        }
+    }
+}
+
+impl CardRule {
+    pub fn brands<const N: usize>(mut self, brands: [CardBrand; N]) -> Self {
+        self.brands = brands.to_vec();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CardBrand, CardRule};
+
+    #[test]
+    fn card_rule_serde() {
+        let rule = CardRule::default().brands([CardBrand::Visa, CardBrand::Elo]);
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"brands":["Visa","Elo"]}"#.to_string());
+        assert_eq!(serde_json::from_str::<CardRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<CardRule>("{}").unwrap(), CardRule::default());
+    }
+
+    #[test]
+    fn card_brand_name() {
+        assert_eq!(CardBrand::Amex.name(), "American Express");
+        assert_eq!(CardBrand::Jcb.name(), "JCB");
+    }
+}
@@ -0,0 +1,96 @@
+use std::{collections::BTreeMap, fmt, sync::Arc};
+
+use araucaria::value::Value;
+
+use super::{Rule, Rules};
+
+pub trait CustomValidator: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn message_key(&self) -> &str;
+
+    fn validate(&self, value: &Value, root: &Value) -> bool;
+}
+
+#[derive(Clone, Default)]
+pub struct ValidatorRegistry {
+    validators: BTreeMap<String, Arc<dyn CustomValidator>>,
+}
+
+impl fmt::Debug for ValidatorRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.validators.keys()).finish()
+    }
+}
+
+impl ValidatorRegistry {
+    pub fn register<V>(mut self, validator: V) -> Self
+    where
+        V: CustomValidator + 'static,
+    {
+        self.validators.insert(validator.name().to_string(), Arc::new(validator));
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn CustomValidator> {
+        self.validators.get(name).map(|validator| validator.as_ref())
+    }
+
+    pub fn unknown(&self, rules: &Rules) -> Vec<String> {
+        rules
+            .0
+            .values()
+            .flatten()
+            .filter_map(|rule| match rule {
+                Rule::Custom(name) if !self.validators.contains_key(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use araucaria::value::Value;
+
+    use super::super::{Rule, Rules};
+    use super::{CustomValidator, ValidatorRegistry};
+
+    struct Even;
+
+    impl CustomValidator for Even {
+        fn name(&self) -> &str {
+            "even"
+        }
+
+        fn message_key(&self) -> &str {
+            "even"
+        }
+
+        fn validate(&self, value: &Value, _root: &Value) -> bool {
+            !matches!(value, Value::U64(n) if n % 2 == 1)
+        }
+    }
+
+    #[test]
+    fn validator_registry_get() {
+        let registry = ValidatorRegistry::default().register(Even);
+        let validator = registry.get("even").unwrap();
+        assert_eq!(validator.message_key(), "even");
+        assert!(validator.validate(&Value::U64(2), &Value::None));
+        assert!(!validator.validate(&Value::U64(3), &Value::None));
+        assert!(registry.get("odd").is_none());
+        assert_eq!(format!("{registry:?}"), r#"{"even"}"#.to_string());
+    }
+
+    #[test]
+    fn validator_registry_unknown() {
+        let registry = ValidatorRegistry::default().register(Even);
+        let rules =
+            Rules::default().add("a", Rule::Custom("even".into())).add("b", Rule::Custom("odd".into())).add("c", Rule::Custom("prime".into()));
+        assert_eq!(registry.unknown(&rules).into_iter().collect::<BTreeSet<_>>(), BTreeSet::from(["odd".to_string(), "prime".to_string()]));
+        assert_eq!(registry.unknown(&Rules::default().add("a", Rule::Custom("even".into()))), Vec::<String>::new());
+    }
+}
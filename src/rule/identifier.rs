@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::nano_id::NANO_ID_ALPHABET;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum UuidHyphens {
+    #[default]
+    Optional,
+    Required,
+    Forbidden,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UuidRule {
+    pub versions: Vec<u8>,
+    pub hyphens: UuidHyphens,
+    pub canonicalize: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UlidRule {
+    pub canonicalize: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NanoIdRule {
+    pub alphabet: String,
+    pub len: usize,
+}
+
+impl UuidRule {
+    pub fn versions<const N: usize>(mut self, versions: [u8; N]) -> Self {
+        self.versions = versions.to_vec();
+        self
+    }
+
+    pub fn hyphens(mut self, hyphens: UuidHyphens) -> Self {
+        self.hyphens = hyphens;
+        self
+    }
+
+    pub fn canonicalize(mut self) -> Self {
+        self.canonicalize = true;
+        self
+    }
+}
+
+impl UlidRule {
+    pub fn canonicalize(mut self) -> Self {
+        self.canonicalize = true;
+        self
+    }
+}
+
+impl Default for NanoIdRule {
+    fn default() -> Self {
+        NanoIdRule { alphabet: NANO_ID_ALPHABET.into(), len: 21 }
+    }
+}
+
+impl NanoIdRule {
+    pub fn alphabet(mut self, alphabet: &str) -> Self {
+        self.alphabet = alphabet.into();
+        self
+    }
+
+    pub fn len(mut self, len: usize) -> Self {
+        self.len = len;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NanoIdRule, UlidRule, UuidHyphens, UuidRule};
+
+    #[test]
+    fn uuid_rule_serde() {
+        let rule = UuidRule::default().versions([4, 7]).hyphens(UuidHyphens::Required).canonicalize();
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"versions":[4,7],"hyphens":"Required","canonicalize":true}"#.to_string());
+        assert_eq!(serde_json::from_str::<UuidRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<UuidRule>(r#"{"versions":[7]}"#).unwrap(), UuidRule::default().versions([7]));
+    }
+
+    #[test]
+    fn ulid_rule_serde() {
+        assert_eq!(serde_json::to_string(&UlidRule::default().canonicalize()).unwrap(), r#"{"canonicalize":true}"#.to_string());
+        assert_eq!(serde_json::from_str::<UlidRule>("{}").unwrap(), UlidRule::default());
+    }
+
+    #[test]
+    fn nano_id_rule_serde() {
+        let rule = NanoIdRule::default().alphabet("0123456789abcdef").len(10);
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"alphabet":"0123456789abcdef","len":10}"#.to_string());
+        assert_eq!(serde_json::from_str::<NanoIdRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<NanoIdRule>(r#"{"len":12}"#).unwrap(), NanoIdRule::default().len(12));
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum CountryFormat {
+    #[default]
+    Alpha2,
+    Alpha3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CountryFormat;
+
+    #[test]
+    fn country_format_serde() {
+        assert_eq!(serde_json::to_string(&CountryFormat::Alpha3).unwrap(), r#""Alpha3""#.to_string());
+        assert_eq!(serde_json::from_str::<CountryFormat>(r#""Alpha2""#).unwrap(), CountryFormat::Alpha2);
+        assert_eq!(CountryFormat::default(), CountryFormat::Alpha2);
+    }
+}
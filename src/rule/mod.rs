@@ -0,0 +1,19 @@
+pub use card::{CardBrand, CardRule};
+pub use custom::{CustomValidator, ValidatorRegistry};
+pub use identifier::{NanoIdRule, UlidRule, UuidHyphens, UuidRule};
+pub use iso::CountryFormat;
+pub use network::{IpVersion, UrlRule};
+pub use pattern::{PatternRule, compile_pattern};
+pub use phone::PhoneRule;
+pub use rules::{Rule, Rules};
+pub use temporal::{DateTimeRule, DurationRule, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeRule, TimeSpan, TimeUnit};
+
+mod card;
+mod custom;
+mod identifier;
+mod iso;
+mod network;
+mod pattern;
+mod phone;
+mod rules;
+mod temporal;
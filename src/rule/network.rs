@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlRule {
+    pub schemes: Vec<String>,
+    pub require_https: bool,
+    pub allow_credentials: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum IpVersion {
+    V4,
+    V6,
+    #[default]
+    Any,
+}
+
+impl UrlRule {
+    pub fn schemes<const N: usize>(mut self, schemes: [&str; N]) -> Self {
+        self.schemes = schemes.iter().map(|scheme| scheme.to_ascii_lowercase()).collect();
+        self
+    }
+
+    pub fn require_https(mut self) -> Self {
+        self.require_https = true;
+        self
+    }
+
+    pub fn allow_credentials(mut self) -> Self {
+        self.allow_credentials = true;
+        self
+    }
+
+    pub fn allowed_schemes(&self) -> Vec<String> {
+        if self.require_https {
+            return vec!["https".into()];
+        }
+        self.schemes.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IpVersion, UrlRule};
+
+    #[test]
+    fn url_rule_allowed_schemes() {
+        assert_eq!(UrlRule::default().allowed_schemes(), Vec::<String>::new());
+        assert_eq!(UrlRule::default().schemes(["HTTP", "https"]).allowed_schemes(), vec!["http".to_string(), "https".to_string()]);
+        assert_eq!(UrlRule::default().schemes(["http", "https"]).require_https().allowed_schemes(), vec!["https".to_string()]);
+    }
+
+    #[test]
+    fn url_rule_serde() {
+        let rule = UrlRule::default().schemes(["https"]).allow_credentials();
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"schemes":["https"],"require_https":false,"allow_credentials":true}"#.to_string());
+        assert_eq!(serde_json::from_str::<UrlRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<UrlRule>(r#"{"require_https":true}"#).unwrap(), UrlRule::default().require_https());
+        assert_eq!(serde_json::from_str::<UrlRule>("{}").unwrap(), UrlRule::default());
+        assert_eq!(serde_json::to_string(&IpVersion::V4).unwrap(), r#""V4""#.to_string());
+        assert_eq!(serde_json::from_str::<IpVersion>(r#""Any""#).unwrap(), IpVersion::Any);
+    }
+}
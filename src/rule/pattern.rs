@@ -0,0 +1,122 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::string::normalize_nfc;
+
+pub fn compile_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", normalize_nfc(pattern)))
+}
+
+#[derive(Serialize, Deserialize)]
+struct PatternRuleDef {
+    pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "PatternRuleDef", into = "PatternRuleDef")]
+pub struct PatternRule {
+    pub pattern: String,
+    pub description: Option<String>,
+    regex: Regex,
+}
+
+impl PartialEq for PatternRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern && self.description == other.description
+    }
+}
+
+impl TryFrom<PatternRuleDef> for PatternRule {
+    type Error = regex::Error;
+
+    fn try_from(def: PatternRuleDef) -> Result<Self, Self::Error> {
+        let rule = PatternRule::new(&def.pattern)?;
+        Ok(match def.description {
+            Some(description) => rule.description(&description),
+            None => rule,
+        })
+    }
+}
+
+impl From<PatternRule> for PatternRuleDef {
+    fn from(rule: PatternRule) -> Self {
+        PatternRuleDef { pattern: rule.pattern, description: rule.description }
+    }
+}
+
+impl PatternRule {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(PatternRule { pattern: pattern.into(), description: None, regex: compile_pattern(pattern)? })
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.regex.is_match(&normalize_nfc(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PatternRule, compile_pattern};
+
+    #[test]
+    fn compile_pattern_full_match() {
+        let regex = compile_pattern("[A-Z]{3}-[0-9]{4}").unwrap();
+        assert!(regex.is_match("ABC-1234"));
+        assert!(!regex.is_match("xABC-1234"));
+        assert!(!regex.is_match("ABC-12345"));
+        let regex = compile_pattern("cat|dog").unwrap();
+        assert!(regex.is_match("dog"));
+        assert!(!regex.is_match("catdog"));
+        assert!(!regex.is_match("hotdog"));
+    }
+
+    #[test]
+    fn pattern_rule_compiled_once() {
+        let rule = PatternRule::new("[0-9]{5}-[0-9]{3}").unwrap();
+        let clone = rule.clone();
+        assert_eq!(rule.regex.as_str(), "^(?:[0-9]{5}-[0-9]{3})$");
+        assert_eq!(clone.regex.as_str(), rule.regex.as_str());
+        assert!(clone.is_match("01310-100"));
+    }
+
+    #[test]
+    fn compile_pattern_invalid() {
+        assert!(compile_pattern("[A-Z").is_err());
+        assert!(compile_pattern("(?P<sku").is_err());
+        assert!(PatternRule::new("a{2,1}").is_err());
+    }
+
+    #[test]
+    fn pattern_rule_nfc() {
+        let rule = PatternRule::new("caf\u{e9}").unwrap();
+        assert!(rule.is_match("caf\u{e9}"));
+        assert!(rule.is_match("cafe\u{301}"));
+        let rule = PatternRule::new("cafe\u{301}").unwrap();
+        assert!(rule.is_match("caf\u{e9}"));
+    }
+
+    #[test]
+    fn pattern_rule_description() {
+        let rule = PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap().description("ABC-1234");
+        assert_eq!(rule.pattern, "[A-Z]{3}-[0-9]{4}".to_string());
+        assert_eq!(rule.description, Some("ABC-1234".into()));
+        assert!(rule.is_match("XYZ-0001"));
+    }
+
+    #[test]
+    fn pattern_rule_serde() {
+        let rule = PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap().description("ABC-1234");
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"pattern":"[A-Z]{3}-[0-9]{4}","description":"ABC-1234"}"#.to_string());
+        assert_eq!(serde_json::from_str::<PatternRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<PatternRule>(r#"{"pattern":"[0-9]+"}"#).unwrap(), PatternRule::new("[0-9]+").unwrap());
+        assert!(serde_json::from_str::<PatternRule>(r#"{"pattern":"[0-9"}"#).is_err());
+    }
+}
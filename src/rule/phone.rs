@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PhoneRule {
+    pub countries: Vec<String>,
+    pub default_country: Option<String>,
+}
+
+impl PhoneRule {
+    pub fn countries<const N: usize>(mut self, countries: [&str; N]) -> Self {
+        self.countries = countries.iter().map(|country| country.to_ascii_uppercase()).collect();
+        self
+    }
+
+    pub fn default_country(mut self, country: &str) -> Self {
+        self.default_country = Some(country.to_ascii_uppercase());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PhoneRule;
+
+    #[test]
+    fn phone_rule_serde() {
+        let rule = PhoneRule::default().countries(["br", "PT"]).default_country("br");
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"countries":["BR","PT"],"default_country":"BR"}"#.to_string());
+        assert_eq!(serde_json::from_str::<PhoneRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<PhoneRule>("{}").unwrap(), PhoneRule::default());
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{
+    bic::normalize_bic,
+    card::parse_card_number,
+    cep::normalize_cep,
+    cnpj::normalize_cnpj,
+    country::{normalize_country_alpha2, normalize_country_alpha3},
+    cpf::normalize_cpf,
+    currency::normalize_currency,
+    date_time::parse_date_time_with,
+    iban::normalize_iban,
+    language::normalize_language,
+    phone::parse_phone,
+    timezone::normalize_timezone,
+    ulid::normalize_ulid,
+    uuid::parse_uuid,
+};
+
+use super::{
+    CardRule, CountryFormat, DateTimeRule, DurationRule, IpVersion, NanoIdRule, PatternRule, PhoneRule, RelativeRule, TimeRule, UlidRule, UrlRule,
+    UuidRule,
+};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Rule {
+    Pattern(PatternRule),
+    Custom(String),
+    Cpf,
+    Cnpj,
+    Cep,
+    Url(UrlRule),
+    Ip(IpVersion),
+    Cidr(IpVersion),
+    Hostname,
+    Uuid(UuidRule),
+    Ulid(UlidRule),
+    NanoId(NanoIdRule),
+    CardNumber(CardRule),
+    CardExpiry,
+    Iban,
+    Bic,
+    Phone(PhoneRule),
+    Country(CountryFormat),
+    Currency,
+    Language,
+    Timezone,
+    DateTime(DateTimeRule),
+    Time(TimeRule),
+    Relative(RelativeRule),
+    Duration(DurationRule),
+    Interval(DurationRule),
+}
+
+impl Rule {
+    pub fn normalize(&self, value: &str) -> Option<String> {
+        match self {
+            Rule::Cpf => normalize_cpf(value).ok(),
+            Rule::Cnpj => normalize_cnpj(value).ok(),
+            Rule::Cep => normalize_cep(value).ok(),
+            Rule::Uuid(rule) if rule.canonicalize => parse_uuid(value).ok().map(|uuid| uuid.canonical()),
+            Rule::Ulid(rule) if rule.canonicalize => normalize_ulid(value).ok(),
+            Rule::CardNumber(_) => parse_card_number(value).ok().map(|(digits, _)| digits),
+            Rule::Iban => normalize_iban(value).ok(),
+            Rule::Bic => normalize_bic(value).ok(),
+            Rule::Phone(rule) => parse_phone(value, rule.default_country.as_deref()).ok().map(|phone| phone.e164),
+            Rule::Country(CountryFormat::Alpha2) => normalize_country_alpha2(value).ok(),
+            Rule::Country(CountryFormat::Alpha3) => normalize_country_alpha3(value).ok(),
+            Rule::Currency => normalize_currency(value).ok(),
+            Rule::Language => normalize_language(value).ok(),
+            Rule::Timezone => normalize_timezone(value).ok(),
+            Rule::DateTime(rule) if rule.utc => parse_date_time_with(value, rule.precision, rule.offset)
+                .ok()
+                .filter(|date_time| date_time.offset.is_some())
+                .map(|date_time| date_time.to_utc_string()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Rules(pub BTreeMap<String, Vec<Rule>>);
+
+impl Rules {
+    pub fn add(mut self, path: &str, rule: Rule) -> Self {
+        self.0.entry(path.into()).or_default().push(rule);
+        self
+    }
+}
+
+impl<const N: usize> From<[(String, Rule); N]> for Rules {
+    fn from(rules: [(String, Rule); N]) -> Self {
+        rules.into_iter().fold(Rules::default(), |result, (path, rule)| result.add(&path, rule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::super::{
+        CardRule, CountryFormat, DateTimeRule, IpVersion, NanoIdRule, OffsetPolicy, PatternRule, PhoneRule, UlidRule, UrlRule, UuidRule,
+    };
+    use super::{Rule, Rules};
+
+    #[test]
+    fn rules_from() {
+        let sku = Rule::Pattern(PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap());
+        let cep = Rule::Pattern(PatternRule::new("[0-9]{5}-[0-9]{3}").unwrap());
+        let digits = Rule::Pattern(PatternRule::new("[0-9-]+").unwrap());
+        assert_eq!(
+            Rules::from([("sku".into(), sku.clone()), ("address.cep".into(), cep.clone()), ("address.cep".into(), digits.clone())]),
+            Rules(BTreeMap::from([("sku".into(), vec![sku]), ("address.cep".into(), vec![cep, digits])]))
+        );
+    }
+
+    #[test]
+    fn rules_serde() {
+        let rules = Rules::default().add("sku", Rule::Pattern(PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap().description("ABC-1234")));
+        let json = serde_json::to_string(&rules).unwrap();
+        assert_eq!(json, r#"{"sku":[{"Pattern":{"pattern":"[A-Z]{3}-[0-9]{4}","description":"ABC-1234"}}]}"#.to_string());
+        assert_eq!(serde_json::from_str::<Rules>(&json).unwrap(), rules);
+        assert!(serde_json::from_str::<Rules>(r#"{"sku":[{"Pattern":{"pattern":"("}}]}"#).is_err());
+        let rules = Rules::default().add("sku", Rule::Custom("sku_exists".into()));
+        assert_eq!(serde_json::to_string(&rules).unwrap(), r#"{"sku":[{"Custom":"sku_exists"}]}"#.to_string());
+        assert_eq!(serde_json::from_str::<Rules>(r#"{"sku":[{"Custom":"sku_exists"}]}"#).unwrap(), rules);
+        let rules = Rules::default().add("cpf", Rule::Cpf).add("cnpj", Rule::Cnpj).add("cep", Rule::Cep);
+        assert_eq!(serde_json::to_string(&rules).unwrap(), r#"{"cep":["Cep"],"cnpj":["Cnpj"],"cpf":["Cpf"]}"#.to_string());
+        assert_eq!(serde_json::from_str::<Rules>(r#"{"cep":["Cep"],"cnpj":["Cnpj"],"cpf":["Cpf"]}"#).unwrap(), rules);
+        let rules = Rules::default()
+            .add("webhook", Rule::Url(UrlRule::default().require_https()))
+            .add("allow", Rule::Cidr(IpVersion::V4))
+            .add("host", Rule::Hostname);
+        let json =
+            r#"{"allow":[{"Cidr":"V4"}],"host":["Hostname"],"webhook":[{"Url":{"schemes":[],"require_https":true,"allow_credentials":false}}]}"#;
+        assert_eq!(serde_json::to_string(&rules).unwrap(), json.to_string());
+        assert_eq!(serde_json::from_str::<Rules>(json).unwrap(), rules);
+        assert_eq!(
+            serde_json::from_str::<Rules>(r#"{"webhook":[{"Url":{"require_https":true}}]}"#).unwrap(),
+            Rules::default().add("webhook", Rule::Url(UrlRule::default().require_https()))
+        );
+    }
+
+    #[test]
+    fn rule_normalize() {
+        assert_eq!(Rule::Cpf.normalize("529.982.247-25"), Some("52998224725".into()));
+        assert_eq!(Rule::Cpf.normalize("529.982.247-24"), None);
+        assert_eq!(Rule::Cnpj.normalize("12.abc.345/01de-35"), Some("12ABC34501DE35".into()));
+        assert_eq!(Rule::Cep.normalize("01310-100"), Some("01310100".into()));
+        assert_eq!(Rule::Custom("sku_exists".into()).normalize("ABC-1234"), None);
+        assert_eq!(
+            Rule::Uuid(UuidRule::default().canonicalize()).normalize("F47AC10B58CC4372A5670E02B2C3D479"),
+            Some("f47ac10b-58cc-4372-a567-0e02b2c3d479".into())
+        );
+        assert_eq!(Rule::Uuid(UuidRule::default()).normalize("F47AC10B58CC4372A5670E02B2C3D479"), None);
+        assert_eq!(Rule::Ulid(UlidRule::default().canonicalize()).normalize("01arz3ndektsv4rrffq69g5fav"), Some("01ARZ3NDEKTSV4RRFFQ69G5FAV".into()));
+        assert_eq!(Rule::Ulid(UlidRule::default()).normalize("01arz3ndektsv4rrffq69g5fav"), None);
+        assert_eq!(Rule::NanoId(NanoIdRule::default()).normalize("V1StGXR8_Z5jdHi6B-myT"), None);
+        assert_eq!(Rule::CardNumber(CardRule::default()).normalize("4111 1111 1111 1111"), Some("4111111111111111".into()));
+        assert_eq!(Rule::CardExpiry.normalize("08/27"), None);
+        assert_eq!(Rule::Iban.normalize("gb82 west 1234 5698 7654 32"), Some("GB82WEST12345698765432".into()));
+        assert_eq!(Rule::Iban.normalize("GB82 WEST 1234 5698 7654 31"), None);
+        assert_eq!(Rule::Bic.normalize("deutdeff500"), Some("DEUTDEFF500".into()));
+        assert_eq!(Rule::Phone(PhoneRule::default().default_country("BR")).normalize("(11) 91234-5678"), Some("+5511912345678".into()));
+        assert_eq!(Rule::Phone(PhoneRule::default()).normalize("+351 912 345 678"), Some("+351912345678".into()));
+        assert_eq!(Rule::Phone(PhoneRule::default()).normalize("(11) 91234-5678"), None);
+        assert_eq!(Rule::Country(CountryFormat::Alpha2).normalize("br"), Some("BR".into()));
+        assert_eq!(Rule::Country(CountryFormat::Alpha3).normalize("br"), None);
+        assert_eq!(Rule::Country(CountryFormat::Alpha3).normalize("bra"), Some("BRA".into()));
+        assert_eq!(Rule::Currency.normalize("brl"), Some("BRL".into()));
+        assert_eq!(Rule::Language.normalize("pt-br"), Some("pt-BR".into()));
+        assert_eq!(Rule::Timezone.normalize("america/sao_paulo"), Some("America/Sao_Paulo".into()));
+        assert_eq!(Rule::DateTime(DateTimeRule::default().utc()).normalize("2026-10-28T22:27:29.973-03:00"), Some("2026-10-29T01:27:29.973Z".into()));
+        assert_eq!(Rule::DateTime(DateTimeRule::default().utc()).normalize("2026-10-28T22:27:29"), None);
+        assert_eq!(Rule::DateTime(DateTimeRule::default().offset(OffsetPolicy::Any).utc()).normalize("2026-10-28T22:27:29"), None);
+        assert_eq!(
+            Rule::DateTime(DateTimeRule::default().offset(OffsetPolicy::Any).utc()).normalize("2026-10-28T22:27:29Z"),
+            Some("2026-10-28T22:27:29Z".into())
+        );
+        assert_eq!(Rule::DateTime(DateTimeRule::default()).normalize("2026-10-28T22:27:29-03:00"), None);
+        assert_eq!(Rule::Pattern(PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap()).normalize("ABC-1234"), None);
+    }
+}
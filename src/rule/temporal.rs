@@ -0,0 +1,297 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::duration::parse_duration;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum Precision {
+    Minutes,
+    Seconds,
+    Millis,
+    #[default]
+    Nanos,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum OffsetPolicy {
+    Utc,
+    #[default]
+    Required,
+    /// Offset-less values stay local: `utc` leaves them as-is and relative rules compare them with the clock's local time.
+    Any,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DateTimeRule {
+    pub precision: Precision,
+    pub offset: OffsetPolicy,
+    pub utc: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimeRule {
+    pub precision: Precision,
+    pub require_seconds: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum TimeUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct TimeSpan {
+    pub amount: u32,
+    pub unit: TimeUnit,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IsoDuration {
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DurationRule {
+    pub min: Option<IsoDuration>,
+    pub max: Option<IsoDuration>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum RelativeRule {
+    Past,
+    Future,
+    AtLeastAgo(TimeSpan),
+    WithinLast(TimeSpan),
+    WithinNext(TimeSpan),
+    AtLeastAhead(TimeSpan),
+}
+
+impl IsoDuration {
+    pub fn spans(&self) -> Vec<TimeSpan> {
+        [
+            TimeSpan::years(self.years),
+            TimeSpan::months(self.months),
+            TimeSpan::weeks(self.weeks),
+            TimeSpan::days(self.days),
+            TimeSpan::hours(self.hours),
+            TimeSpan::minutes(self.minutes),
+            TimeSpan::seconds(self.seconds),
+        ]
+        .into_iter()
+        .filter(|span| span.amount > 0)
+        .collect()
+    }
+
+    /// Length in seconds with fixed calendar units: a year is 365 days and a month is 30 days,
+    /// so `P1M` is shorter than `P31D` and `P1Y` is shorter than `P12M6D`.
+    pub fn nominal_seconds(&self) -> u64 {
+        let days = u64::from(self.years) * 365 + u64::from(self.months) * 30 + u64::from(self.weeks) * 7 + u64::from(self.days);
+        days * 86_400 + u64::from(self.hours) * 3_600 + u64::from(self.minutes) * 60 + u64::from(self.seconds)
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.spans().is_empty() {
+            return write!(f, "PT0S");
+        }
+        write!(f, "P")?;
+        for (amount, designator) in [(self.years, "Y"), (self.months, "M"), (self.weeks, "W"), (self.days, "D")] {
+            if amount > 0 {
+                write!(f, "{amount}{designator}")?;
+            }
+        }
+        if self.hours > 0 || self.minutes > 0 || self.seconds > 0 {
+            write!(f, "T")?;
+        }
+        for (amount, designator) in [(self.hours, "H"), (self.minutes, "M"), (self.seconds, "S")] {
+            if amount > 0 {
+                write!(f, "{amount}{designator}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for IsoDuration {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_duration(value).map_err(|()| format!("invalid ISO 8601 duration \"{value}\""))
+    }
+}
+
+impl TryFrom<String> for IsoDuration {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        IsoDuration::try_from(value.as_str())
+    }
+}
+
+impl From<IsoDuration> for String {
+    fn from(duration: IsoDuration) -> Self {
+        duration.to_string()
+    }
+}
+
+impl DurationRule {
+    pub fn min(mut self, min: IsoDuration) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: IsoDuration) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl TimeSpan {
+    pub fn years(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Years }
+    }
+
+    pub fn months(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Months }
+    }
+
+    pub fn weeks(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Weeks }
+    }
+
+    pub fn days(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Days }
+    }
+
+    pub fn hours(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Hours }
+    }
+
+    pub fn minutes(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Minutes }
+    }
+
+    pub fn seconds(amount: u32) -> Self {
+        TimeSpan { amount, unit: TimeUnit::Seconds }
+    }
+}
+
+impl DateTimeRule {
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn offset(mut self, offset: OffsetPolicy) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn utc(mut self) -> Self {
+        self.utc = true;
+        self
+    }
+}
+
+impl TimeRule {
+    pub fn precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn require_seconds(mut self) -> Self {
+        self.require_seconds = true;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DateTimeRule, DurationRule, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeRule, TimeSpan, TimeUnit};
+
+    #[test]
+    fn precision_order() {
+        assert!(Precision::Minutes < Precision::Seconds);
+        assert!(Precision::Seconds < Precision::Millis);
+        assert!(Precision::Millis < Precision::Nanos);
+    }
+
+    #[test]
+    fn date_time_rule_serde() {
+        let rule = DateTimeRule::default().precision(Precision::Seconds).offset(OffsetPolicy::Utc).utc();
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"precision":"Seconds","offset":"Utc","utc":true}"#.to_string());
+        assert_eq!(serde_json::from_str::<DateTimeRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<DateTimeRule>("{}").unwrap(), DateTimeRule::default());
+    }
+
+    #[test]
+    fn time_rule_serde() {
+        let rule = TimeRule::default().precision(Precision::Millis).require_seconds();
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"precision":"Millis","require_seconds":true}"#.to_string());
+        assert_eq!(serde_json::from_str::<TimeRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<TimeRule>("{}").unwrap(), TimeRule::default());
+    }
+
+    #[test]
+    fn relative_rule_serde() {
+        let rule = RelativeRule::AtLeastAgo(TimeSpan::years(18));
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"AtLeastAgo":{"amount":18,"unit":"Years"}}"#.to_string());
+        assert_eq!(serde_json::from_str::<RelativeRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<RelativeRule>(r#""Past""#).unwrap(), RelativeRule::Past);
+        assert_eq!(TimeSpan::days(90), TimeSpan { amount: 90, unit: TimeUnit::Days });
+    }
+
+    #[test]
+    fn iso_duration_spans() {
+        assert_eq!(IsoDuration::default().spans(), vec![]);
+        assert_eq!(IsoDuration::try_from("P1DT2H").unwrap().spans(), vec![TimeSpan::days(1), TimeSpan::hours(2)]);
+    }
+
+    #[test]
+    fn iso_duration_nominal_seconds() {
+        assert_eq!(IsoDuration::try_from("PT5M").unwrap().nominal_seconds(), 300);
+        assert_eq!(IsoDuration::try_from("P1W").unwrap().nominal_seconds(), 604_800);
+        assert_eq!(IsoDuration::try_from("P1Y2M").unwrap().nominal_seconds(), 425 * 86_400);
+        assert_eq!(IsoDuration::try_from("P1M").unwrap().nominal_seconds(), IsoDuration::try_from("P30D").unwrap().nominal_seconds());
+        assert_eq!(IsoDuration::try_from("P1Y").unwrap().nominal_seconds(), IsoDuration::try_from("P365D").unwrap().nominal_seconds());
+    }
+
+    #[test]
+    fn iso_duration_display() {
+        assert_eq!(IsoDuration::default().to_string(), "PT0S".to_string());
+        assert_eq!(IsoDuration::try_from("P1Y2M3DT4H5M6S").unwrap().to_string(), "P1Y2M3DT4H5M6S".to_string());
+        assert_eq!(IsoDuration::try_from("PT90M").unwrap().to_string(), "PT90M".to_string());
+        assert_eq!(IsoDuration::try_from("P0D").unwrap().to_string(), "PT0S".to_string());
+    }
+
+    #[test]
+    fn duration_rule_serde() {
+        let rule = DurationRule::default().min(IsoDuration::try_from("PT5M").unwrap()).max(IsoDuration::try_from("P7D").unwrap());
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"min":"PT5M","max":"P7D"}"#.to_string());
+        assert_eq!(serde_json::from_str::<DurationRule>(&json).unwrap(), rule);
+        assert_eq!(serde_json::from_str::<DurationRule>("{}").unwrap(), DurationRule::default());
+        assert!(serde_json::from_str::<DurationRule>(r#"{"min":"5 minutes"}"#).is_err());
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Serialize, Serializer};
 use std::collections::BTreeMap;
 
-use super::{EnumValues, Operation, schema::to_enum_values, to_operation};
+use crate::rule::{CardBrand, CountryFormat, IpVersion, IsoDuration, OffsetPolicy, Precision, RelativeRule};
+
+use super::{EnumValues, Operand, Operation, schema::to_enum_values, to_operation};
 
 #[derive(Debug, PartialEq, Clone, Serialize)]
 pub enum ValidationErr {
@@ -29,6 +31,50 @@ pub enum ValidationErr {
     ISizeEnum(Vec<isize>),
     StrEnum(Vec<String>),
     Enumerated(EnumValues),
+    Pattern(String, Option<String>),
+    Custom(String, String),
+    Cpf,
+    Cnpj,
+    Cep,
+    Url,
+    UrlScheme(Vec<String>),
+    UrlCredentials,
+    Ip(IpVersion),
+    Cidr(IpVersion),
+    Hostname,
+    Uuid,
+    UuidVersion(Vec<u8>),
+    Ulid,
+    NanoId(usize),
+    CardNumber,
+    CardBrand(Vec<CardBrand>),
+    CardExpiry,
+    CardExpired,
+    Iban,
+    IbanCountry,
+    IbanLength(usize),
+    IbanChecksum,
+    Bic,
+    Phone,
+    PhoneCountry(Vec<String>),
+    Country(CountryFormat),
+    Currency,
+    Language,
+    Timezone,
+    Precision(Precision),
+    DateTimeOffset(OffsetPolicy),
+    TimeSeconds,
+    DateOperand(Operand),
+    TimeOperand(Operand),
+    DateTimeOperand(Operand),
+    Relative(RelativeRule),
+    Duration,
+    DurationMin(IsoDuration),
+    DurationMax(IsoDuration),
+    Interval,
+    IntervalOrder,
+    IntervalMin(IsoDuration),
+    IntervalMax(IsoDuration),
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -38,6 +84,24 @@ pub enum SchemaErr {
     Obj(BTreeMap<String, SchemaErr>),
 }
 
+impl<const N: usize> From<[ValidationErr; N]> for SchemaErr {
+    fn from(errs: [ValidationErr; N]) -> Self {
+        SchemaErr::Validation(errs.to_vec())
+    }
+}
+
+impl<const N: usize> From<[(String, SchemaErr); N]> for SchemaErr {
+    fn from(errs: [(String, SchemaErr); N]) -> Self {
+        SchemaErr::Obj(BTreeMap::from(errs))
+    }
+}
+
+impl<const N: usize> From<[SchemaErr; N]> for SchemaErr {
+    fn from(errs: [SchemaErr; N]) -> Self {
+        SchemaErr::Arr(errs.to_vec())
+    }
+}
+
 impl Serialize for SchemaErr {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -90,6 +154,8 @@ mod tests {
     use std::collections::BTreeMap;
     use std::sync::LazyLock;
 
+    use crate::rule::{CardBrand, CountryFormat, IpVersion, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeSpan};
+
     use super::super::{EnumValues, Operand, OperandValue, Operation};
 
     use super::{SchemaErr, ValidationErr, to_schema_err, to_validation_err};
@@ -377,6 +443,68 @@ mod tests {
         assert_eq!(serde_json::to_string(&ERR_ENUM_USIZE.clone()).unwrap(), r#"{"Enumerated":[0,3,6,9,12,15,18]}"#.to_string());
         assert_eq!(serde_json::to_string(&ERR_ENUM_ISIZE.clone()).unwrap(), r#"{"Enumerated":[0,-3,6,-9,12,-15]}"#.to_string());
         assert_eq!(serde_json::to_string(&ERR_ENUM_STR.clone()).unwrap(), r#"{"Enumerated":["ROCK","PAPER","SCISSORS"]}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Pattern("[A-Z]{3}".into(), None)).unwrap(), r#"{"Pattern":["[A-Z]{3}",null]}"#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::Pattern("[A-Z]{3}".into(), Some("ABC".into()))).unwrap(),
+            r#"{"Pattern":["[A-Z]{3}","ABC"]}"#.to_string()
+        );
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::Custom("sku_exists".into(), "sku_unknown".into())).unwrap(),
+            r#"{"Custom":["sku_exists","sku_unknown"]}"#.to_string()
+        );
+        assert_eq!(serde_json::to_string(&ValidationErr::Cpf).unwrap(), r#""Cpf""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Cnpj).unwrap(), r#""Cnpj""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Cep).unwrap(), r#""Cep""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Url).unwrap(), r#""Url""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::UrlScheme(vec!["https".into()])).unwrap(), r#"{"UrlScheme":["https"]}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::UrlCredentials).unwrap(), r#""UrlCredentials""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Ip(IpVersion::V4)).unwrap(), r#"{"Ip":"V4"}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Cidr(IpVersion::Any)).unwrap(), r#"{"Cidr":"Any"}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Hostname).unwrap(), r#""Hostname""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Uuid).unwrap(), r#""Uuid""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::UuidVersion(vec![4, 7])).unwrap(), r#"{"UuidVersion":[4,7]}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Ulid).unwrap(), r#""Ulid""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::NanoId(21)).unwrap(), r#"{"NanoId":21}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::CardNumber).unwrap(), r#""CardNumber""#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::CardBrand(vec![CardBrand::Visa, CardBrand::Elo])).unwrap(),
+            r#"{"CardBrand":["Visa","Elo"]}"#.to_string()
+        );
+        assert_eq!(serde_json::to_string(&ValidationErr::CardExpiry).unwrap(), r#""CardExpiry""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::CardExpired).unwrap(), r#""CardExpired""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Iban).unwrap(), r#""Iban""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::IbanCountry).unwrap(), r#""IbanCountry""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::IbanLength(22)).unwrap(), r#"{"IbanLength":22}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::IbanChecksum).unwrap(), r#""IbanChecksum""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Bic).unwrap(), r#""Bic""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Phone).unwrap(), r#""Phone""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::PhoneCountry(vec!["BR".into()])).unwrap(), r#"{"PhoneCountry":["BR"]}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Country(CountryFormat::Alpha2)).unwrap(), r#"{"Country":"Alpha2"}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Currency).unwrap(), r#""Currency""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Language).unwrap(), r#""Language""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Timezone).unwrap(), r#""Timezone""#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::Precision(Precision::Seconds)).unwrap(), r#"{"Precision":"Seconds"}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::DateTimeOffset(OffsetPolicy::Utc)).unwrap(), r#"{"DateTimeOffset":"Utc"}"#.to_string());
+        assert_eq!(serde_json::to_string(&ValidationErr::TimeSeconds).unwrap(), r#""TimeSeconds""#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::DateOperand(Operand::FieldPath("checkin".into()))).unwrap(),
+            r#"{"DateOperand":"checkin"}"#.to_string()
+        );
+        assert_eq!(serde_json::to_string(&ValidationErr::Relative(RelativeRule::Past)).unwrap(), r#"{"Relative":"Past"}"#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::Relative(RelativeRule::WithinNext(TimeSpan::days(90)))).unwrap(),
+            r#"{"Relative":{"WithinNext":{"amount":90,"unit":"Days"}}}"#.to_string()
+        );
+        assert_eq!(serde_json::to_string(&ValidationErr::Duration).unwrap(), r#""Duration""#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::DurationMin(IsoDuration::try_from("PT5M").unwrap())).unwrap(),
+            r#"{"DurationMin":"PT5M"}"#.to_string()
+        );
+        assert_eq!(serde_json::to_string(&ValidationErr::IntervalOrder).unwrap(), r#""IntervalOrder""#.to_string());
+        assert_eq!(
+            serde_json::to_string(&ValidationErr::IntervalMax(IsoDuration::try_from("P3M").unwrap())).unwrap(),
+            r#"{"IntervalMax":"P3M"}"#.to_string()
+        );
     }
 
     #[test]
@@ -1,7 +1,7 @@
 pub use error::{SchemaErr, ValidationErr, to_schema_err, to_validation_err};
 pub use locale::{SchemaErrLocale, to_schema_err_locale};
 pub use operation::{Operand, OperandValue, Operation, to_operand, to_operand_value, to_operation};
-use schema::EnumValues;
+pub use schema::EnumValues;
 pub use value::{Value, to_value};
 
 mod error;
@@ -0,0 +1,36 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static BIC_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Z]{4}[A-Z]{2}[A-Z0-9]{2}(?:[A-Z0-9]{3})?$").unwrap());
+
+pub fn normalize_bic(s: &str) -> Result<String, ()> {
+    let bic: String = s.trim().to_ascii_uppercase();
+    if !BIC_REGEX.is_match(&bic) {
+        return Err(());
+    }
+    Ok(bic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_bic;
+
+    #[test]
+    fn normalize_bic_ok() {
+        assert_eq!(normalize_bic("DEUTDEFF"), Ok("DEUTDEFF".into()));
+        assert_eq!(normalize_bic("deutdeff500"), Ok("DEUTDEFF500".into()));
+        assert_eq!(normalize_bic(" BOFAUS3N "), Ok("BOFAUS3N".into()));
+        assert_eq!(normalize_bic("ITAUBRSP"), Ok("ITAUBRSP".into()));
+    }
+
+    #[test]
+    fn normalize_bic_err() {
+        assert_eq!(normalize_bic("DEUTDEF"), Err(()));
+        assert_eq!(normalize_bic("DEUTDEFF5"), Err(()));
+        assert_eq!(normalize_bic("DEUTDEFF5000"), Err(()));
+        assert_eq!(normalize_bic("DEU1DEFF"), Err(()));
+        assert_eq!(normalize_bic("DEUT1EFF"), Err(()));
+        assert_eq!(normalize_bic("DEUT DEFF"), Err(()));
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::rule::CardBrand;
+
+static CARD_EXPIRY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(0[1-9]|1[0-2]) ?/ ?([0-9]{2}|[0-9]{4})$").unwrap());
+
+const ELO_RANGES: [(u32, u32); 23] = [
+    (401178, 401179),
+    (431274, 431274),
+    (438935, 438935),
+    (451416, 451416),
+    (457393, 457393),
+    (457631, 457632),
+    (504175, 504175),
+    (506699, 506778),
+    (509000, 509999),
+    (627780, 627780),
+    (636297, 636297),
+    (636368, 636368),
+    (650031, 650033),
+    (650035, 650051),
+    (650405, 650439),
+    (650485, 650538),
+    (650541, 650598),
+    (650700, 650718),
+    (650720, 650727),
+    (650901, 650978),
+    (651652, 651679),
+    (655000, 655019),
+    (655021, 655058),
+];
+
+const HIPERCARD_BINS: [u32; 9] = [606282, 384100, 384140, 384160, 637095, 637568, 637599, 637609, 637612];
+
+pub fn card_digits(s: &str) -> Result<String, ()> {
+    if !s.chars().all(|c| c.is_ascii_digit() || c == ' ' || c == '-') {
+        return Err(());
+    }
+    let digits: String = s.chars().filter(|c| c.is_ascii_digit()).collect();
+    if digits.len() < 12 || digits.len() > 19 {
+        return Err(());
+    }
+    Ok(digits)
+}
+
+pub fn luhn_is_valid(digits: &str) -> bool {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(i, digit)| match (i % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+fn prefix(digits: &str, len: usize) -> u32 {
+    digits[..len].parse::<u32>().unwrap()
+}
+
+pub fn card_brand(digits: &str) -> Option<CardBrand> {
+    let bin = prefix(digits, 6);
+    if ELO_RANGES.iter().any(|(start, end)| (*start..=*end).contains(&bin)) {
+        return Some(CardBrand::Elo);
+    }
+    if HIPERCARD_BINS.contains(&bin) {
+        return Some(CardBrand::Hipercard);
+    }
+    match (prefix(digits, 1), prefix(digits, 2), prefix(digits, 3), prefix(digits, 4)) {
+        (4, _, _, _) => Some(CardBrand::Visa),
+        (_, 34 | 37, _, _) => Some(CardBrand::Amex),
+        (_, 51..=55, _, _) | (_, _, _, 2221..=2720) => Some(CardBrand::Mastercard),
+        (_, _, _, 3528..=3589) => Some(CardBrand::Jcb),
+        (_, 36 | 38 | 39, _, _) | (_, _, 300..=305, _) => Some(CardBrand::Diners),
+        (_, 65, _, _) | (_, _, 644..=649, _) | (_, _, _, 6011) => Some(CardBrand::Discover),
+        _ if (622126..=622925).contains(&bin) => Some(CardBrand::Discover),
+        _ => None,
+    }
+}
+
+pub fn card_brand_lengths(brand: CardBrand) -> &'static [usize] {
+    match brand {
+        CardBrand::Visa => &[13, 16, 19],
+        CardBrand::Mastercard => &[16],
+        CardBrand::Amex => &[15],
+        CardBrand::Elo => &[16],
+        CardBrand::Hipercard => &[13, 16, 19],
+        CardBrand::Diners => &[14, 15, 16, 17, 18, 19],
+        CardBrand::Discover => &[16, 17, 18, 19],
+        CardBrand::Jcb => &[16, 17, 18, 19],
+    }
+}
+
+pub fn parse_card_number(s: &str) -> Result<(String, CardBrand), ()> {
+    let digits = card_digits(s)?;
+    let brand = card_brand(&digits).ok_or(())?;
+    if !card_brand_lengths(brand).contains(&digits.len()) || !luhn_is_valid(&digits) {
+        return Err(());
+    }
+    Ok((digits, brand))
+}
+
+pub fn parse_card_expiry(s: &str) -> Result<(u32, u32), ()> {
+    let caps = CARD_EXPIRY_REGEX.captures(s).ok_or(())?;
+    let (_, [mm, yy]) = caps.extract();
+    let mm = mm.parse::<u32>().map_err(|_| ())?;
+    let yy = yy.parse::<u32>().map_err(|_| ())?;
+    let yyyy = if yy < 100 { 2000 + yy } else { yy };
+    Ok((yyyy, mm))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rule::CardBrand;
+
+    use super::{card_brand, card_digits, luhn_is_valid, parse_card_expiry, parse_card_number};
+
+    #[test]
+    fn card_digits_strip() {
+        assert_eq!(card_digits("4111 1111 1111 1111"), Ok("4111111111111111".into()));
+        assert_eq!(card_digits("4111-1111-1111-1111"), Ok("4111111111111111".into()));
+        assert_eq!(card_digits("4111.1111.1111.1111"), Err(()));
+        assert_eq!(card_digits("4111 1111 111"), Err(()));
+        assert_eq!(card_digits("4111 1111 1111 1111 1111"), Err(()));
+    }
+
+    #[test]
+    fn luhn() {
+        assert!(luhn_is_valid("4111111111111111"));
+        assert!(luhn_is_valid("378282246310005"));
+        assert!(luhn_is_valid("79927398713"));
+        assert!(!luhn_is_valid("4111111111111112"));
+        assert!(!luhn_is_valid("79927398710"));
+    }
+
+    #[test]
+    fn card_brand_detection() {
+        assert_eq!(card_brand("4111111111111111"), Some(CardBrand::Visa));
+        assert_eq!(card_brand("5555555555554444"), Some(CardBrand::Mastercard));
+        assert_eq!(card_brand("2223003122003222"), Some(CardBrand::Mastercard));
+        assert_eq!(card_brand("378282246310005"), Some(CardBrand::Amex));
+        assert_eq!(card_brand("6362970000457013"), Some(CardBrand::Elo));
+        assert_eq!(card_brand("4389350000000000"), Some(CardBrand::Elo));
+        assert_eq!(card_brand("6062825624254001"), Some(CardBrand::Hipercard));
+        assert_eq!(card_brand("30569309025904"), Some(CardBrand::Diners));
+        assert_eq!(card_brand("6011111111111117"), Some(CardBrand::Discover));
+        assert_eq!(card_brand("3530111333300000"), Some(CardBrand::Jcb));
+        assert_eq!(card_brand("9999999999999999"), None);
+    }
+
+    #[test]
+    fn parse_card_number_ok() {
+        assert_eq!(parse_card_number("4111 1111 1111 1111"), Ok(("4111111111111111".into(), CardBrand::Visa)));
+        assert_eq!(parse_card_number("3782-822463-10005"), Ok(("378282246310005".into(), CardBrand::Amex)));
+        assert_eq!(parse_card_number("6362970000457013"), Ok(("6362970000457013".into(), CardBrand::Elo)));
+    }
+
+    #[test]
+    fn parse_card_number_err() {
+        assert_eq!(parse_card_number("4111 1111 1111 1112"), Err(()));
+        assert_eq!(parse_card_number("37828224631000"), Err(()));
+        assert_eq!(parse_card_number("555555555555444"), Err(()));
+        assert_eq!(parse_card_number("9999999999999995"), Err(()));
+        assert_eq!(parse_card_number("4111a111111111111"), Err(()));
+    }
+
+    #[test]
+    fn parse_card_expiry_formats() {
+        assert_eq!(parse_card_expiry("08/27"), Ok((2027, 8)));
+        assert_eq!(parse_card_expiry("12/2030"), Ok((2030, 12)));
+        assert_eq!(parse_card_expiry("01 / 29"), Ok((2029, 1)));
+        assert_eq!(parse_card_expiry("13/27"), Err(()));
+        assert_eq!(parse_card_expiry("00/27"), Err(()));
+        assert_eq!(parse_card_expiry("8/27"), Err(()));
+        assert_eq!(parse_card_expiry("08/227"), Err(()));
+        assert_eq!(parse_card_expiry("08-27"), Err(()));
+    }
+}
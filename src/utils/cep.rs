@@ -0,0 +1,34 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static CEP_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]{5}-[0-9]{3}$|^[0-9]{8}$").unwrap());
+
+pub fn normalize_cep(s: &str) -> Result<String, ()> {
+    if !CEP_REGEX.is_match(s) {
+        return Err(());
+    }
+    Ok(s.chars().filter(|c| c.is_ascii_digit()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_cep;
+
+    #[test]
+    fn normalize_cep_ok() {
+        assert_eq!(normalize_cep("01310-100"), Ok("01310100".into()));
+        assert_eq!(normalize_cep("01310100"), Ok("01310100".into()));
+    }
+
+    #[test]
+    fn normalize_cep_invalid_format() {
+        assert_eq!(normalize_cep("01310 100"), Err(()));
+        assert_eq!(normalize_cep("01.310-100"), Err(()));
+        assert_eq!(normalize_cep("0131-0100"), Err(()));
+        assert_eq!(normalize_cep("0131010"), Err(()));
+        assert_eq!(normalize_cep("013101000"), Err(()));
+        assert_eq!(normalize_cep("01310-10a"), Err(()));
+        assert_eq!(normalize_cep(""), Err(()));
+    }
+}
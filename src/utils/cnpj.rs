@@ -0,0 +1,71 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static CNPJ_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9A-Z]{2}\.[0-9A-Z]{3}\.[0-9A-Z]{3}/[0-9A-Z]{4}-[0-9]{2}$|^[0-9A-Z]{12}[0-9]{2}$").unwrap());
+
+fn check_digit(values: &[u32]) -> u32 {
+    let weights = (2..=9).cycle().take(values.len()).collect::<Vec<u32>>();
+    let sum: u32 = values.iter().rev().zip(weights).map(|(value, weight)| value * weight).sum();
+    match sum % 11 {
+        0 | 1 => 0,
+        rest => 11 - rest,
+    }
+}
+
+pub fn normalize_cnpj(s: &str) -> Result<String, ()> {
+    let s = s.to_ascii_uppercase();
+    if !CNPJ_REGEX.is_match(&s) {
+        return Err(());
+    }
+    let normalized: String = s.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    let values: Vec<u32> = normalized.bytes().map(|c| u32::from(c - b'0')).collect();
+    if values.iter().all(|value| *value == values[0]) {
+        return Err(());
+    }
+    if check_digit(&values[..12]) != values[12] || check_digit(&values[..13]) != values[13] {
+        return Err(());
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_cnpj;
+
+    #[test]
+    fn normalize_cnpj_ok() {
+        assert_eq!(normalize_cnpj("11.222.333/0001-81"), Ok("11222333000181".into()));
+        assert_eq!(normalize_cnpj("11222333000181"), Ok("11222333000181".into()));
+        assert_eq!(normalize_cnpj("11.444.777/0001-61"), Ok("11444777000161".into()));
+    }
+
+    #[test]
+    fn normalize_cnpj_alphanumeric() {
+        assert_eq!(normalize_cnpj("12.ABC.345/01DE-35"), Ok("12ABC34501DE35".into()));
+        assert_eq!(normalize_cnpj("12ABC34501DE35"), Ok("12ABC34501DE35".into()));
+        assert_eq!(normalize_cnpj("12.abc.345/01de-35"), Ok("12ABC34501DE35".into()));
+        assert_eq!(normalize_cnpj("12.ABC.345/01DE-36"), Err(()));
+        assert_eq!(normalize_cnpj("12.ABC.345/01DE-3E"), Err(()));
+    }
+
+    #[test]
+    fn normalize_cnpj_invalid_format() {
+        assert_eq!(normalize_cnpj("11.222.333/000181"), Err(()));
+        assert_eq!(normalize_cnpj("11222333/0001-81"), Err(()));
+        assert_eq!(normalize_cnpj("11 222 333 0001 81"), Err(()));
+        assert_eq!(normalize_cnpj("1122233300018"), Err(()));
+        assert_eq!(normalize_cnpj("112223330001811"), Err(()));
+        assert_eq!(normalize_cnpj("11.222.333/0001-8!"), Err(()));
+        assert_eq!(normalize_cnpj(""), Err(()));
+    }
+
+    #[test]
+    fn normalize_cnpj_invalid_value() {
+        assert_eq!(normalize_cnpj("11.222.333/0001-80"), Err(()));
+        assert_eq!(normalize_cnpj("11222333000191"), Err(()));
+        assert_eq!(normalize_cnpj("00.000.000/0000-00"), Err(()));
+        assert_eq!(normalize_cnpj("11111111111111"), Err(()));
+    }
+}
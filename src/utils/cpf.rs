@@ -0,0 +1,58 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static CPF_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]{3}\.[0-9]{3}\.[0-9]{3}-[0-9]{2}$|^[0-9]{11}$").unwrap());
+
+fn check_digit(digits: &[u32]) -> u32 {
+    let weight = digits.len() as u32 + 1;
+    let sum: u32 = digits.iter().enumerate().map(|(i, digit)| digit * (weight - i as u32)).sum();
+    sum * 10 % 11 % 10
+}
+
+pub fn normalize_cpf(s: &str) -> Result<String, ()> {
+    if !CPF_REGEX.is_match(s) {
+        return Err(());
+    }
+    let normalized: String = s.chars().filter(|c| c.is_ascii_digit()).collect();
+    let digits: Vec<u32> = normalized.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.iter().all(|digit| *digit == digits[0]) {
+        return Err(());
+    }
+    if check_digit(&digits[..9]) != digits[9] || check_digit(&digits[..10]) != digits[10] {
+        return Err(());
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_cpf;
+
+    #[test]
+    fn normalize_cpf_ok() {
+        assert_eq!(normalize_cpf("529.982.247-25"), Ok("52998224725".into()));
+        assert_eq!(normalize_cpf("52998224725"), Ok("52998224725".into()));
+        assert_eq!(normalize_cpf("111.444.777-35"), Ok("11144477735".into()));
+    }
+
+    #[test]
+    fn normalize_cpf_invalid_format() {
+        assert_eq!(normalize_cpf("529.982.24725"), Err(()));
+        assert_eq!(normalize_cpf("529982247-25"), Err(()));
+        assert_eq!(normalize_cpf("529 982 247 25"), Err(()));
+        assert_eq!(normalize_cpf("5299822472"), Err(()));
+        assert_eq!(normalize_cpf("529982247255"), Err(()));
+        assert_eq!(normalize_cpf("5299822472a"), Err(()));
+        assert_eq!(normalize_cpf(""), Err(()));
+    }
+
+    #[test]
+    fn normalize_cpf_invalid_value() {
+        assert_eq!(normalize_cpf("529.982.247-24"), Err(()));
+        assert_eq!(normalize_cpf("52998224715"), Err(()));
+        assert_eq!(normalize_cpf("000.000.000-00"), Err(()));
+        assert_eq!(normalize_cpf("11111111111"), Err(()));
+        assert_eq!(normalize_cpf("99999999999"), Err(()));
+    }
+}
@@ -0,0 +1,39 @@
+const CURRENCIES: [&str; 177] = [
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD",
+    "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK",
+    "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF",
+    "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP",
+    "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD",
+    "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD",
+    "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD",
+    "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB",
+    "XBC", "XBD", "XCD", "XCG", "XDR", "XOF", "XPD", "XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+pub fn normalize_currency(s: &str) -> Result<String, ()> {
+    let code = s.to_ascii_uppercase();
+    CURRENCIES.binary_search(&code.as_str()).map_err(|_| ())?;
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CURRENCIES, normalize_currency};
+
+    #[test]
+    fn currencies_sorted() {
+        assert!(CURRENCIES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn normalize_currency_codes() {
+        assert_eq!(normalize_currency("BRL"), Ok("BRL".into()));
+        assert_eq!(normalize_currency("usd"), Ok("USD".into()));
+        assert_eq!(normalize_currency("Eur"), Ok("EUR".into()));
+        assert_eq!(normalize_currency("XXX"), Ok("XXX".into()));
+        assert_eq!(normalize_currency("HRK"), Err(()));
+        assert_eq!(normalize_currency("BTC"), Err(()));
+        assert_eq!(normalize_currency("US"), Err(()));
+        assert_eq!(normalize_currency("USDT"), Err(()));
+    }
+}
@@ -3,8 +3,8 @@ use std::sync::LazyLock;
 use chrono::NaiveDate;
 use regex::Regex;
 
-#[derive(Debug, PartialEq)]
-pub struct Dt(pub u32, pub u8, pub u8);
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Dt(pub NaiveDate);
 
 static DT_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]{4})-([0-9]{2})-([0-9]{2})$").unwrap());
 
@@ -14,18 +14,19 @@ pub fn parse_date(s: &str) -> Result<Dt, ()> {
     let yyyy = yyyy.parse::<u32>().map_err(|_| ())?;
     let mm = mm.parse::<u8>().map_err(|_| ())?;
     let dd = dd.parse::<u8>().map_err(|_| ())?;
-    NaiveDate::from_ymd_opt(yyyy as i32, mm.into(), dd.into()).ok_or(())?;
-    Ok(Dt(yyyy, mm, dd))
+    NaiveDate::from_ymd_opt(yyyy as i32, mm.into(), dd.into()).map(Dt).ok_or(())
 }
 
 #[cfg(test)]
 mod tests {
+    use chrono::NaiveDate;
+
     use super::{Dt, parse_date};
 
     #[test]
     fn parse_date_ok() {
-        assert_eq!(parse_date("2029-12-31"), Ok(Dt(2029, 12, 31)));
-        assert_eq!(parse_date("2024-02-29"), Ok(Dt(2024, 2, 29)));
+        assert_eq!(parse_date("2029-12-31"), Ok(Dt(NaiveDate::from_ymd_opt(2029, 12, 31).unwrap())));
+        assert_eq!(parse_date("2024-02-29"), Ok(Dt(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())));
     }
 
     #[test]
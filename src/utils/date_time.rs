@@ -1,35 +1,186 @@
 use std::sync::LazyLock;
 
-use chrono::NaiveDate;
+use chrono::{FixedOffset, Months, NaiveDate, NaiveDateTime, SecondsFormat, TimeDelta};
 use regex::Regex;
 
-#[derive(Debug, PartialEq)]
-pub struct DtTm(pub u32, pub u16, pub u16, pub u8, pub u8);
+use crate::rule::{OffsetPolicy, Precision, TimeSpan, TimeUnit};
 
-static DT_TM_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]{4})-([0-9]{2})-([0-9]{2})T([0-9]{2}):([0-9]{2})Z$").unwrap());
+use super::time::time_from_parts;
 
-pub fn parse_date_time(s: &str) -> Result<DtTm, ()> {
-    let caps = DT_TM_REGEX.captures(s).ok_or(())?;
-    let (_, [yyyy, mm, dd, h, m]) = caps.extract();
-    let yyyy = yyyy.parse::<u32>().map_err(|_| ())?;
-    let mm = mm.parse::<u16>().map_err(|_| ())?;
-    let dd = dd.parse::<u16>().map_err(|_| ())?;
-    let h = h.parse::<u8>().map_err(|_| ())?;
-    let m = m.parse::<u8>().map_err(|_| ())?;
-    NaiveDate::from_ymd_opt(yyyy as i32, mm.into(), dd.into()).ok_or(())?;
-    if h > 23 || m > 59 {
-        return Err(());
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DtTm {
+    pub local: NaiveDateTime,
+    pub offset: Option<FixedOffset>,
+    pub precision: Precision,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DateTimeErr {
+    Format,
+    Precision,
+    Offset,
+}
+
+static DT_TM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^([0-9]{4})-([0-9]{2})-([0-9]{2})[Tt]([0-9]{2}):([0-9]{2})(?::([0-9]{2})(?:\.([0-9]{1,9}))?)?(?:([Zz])|([+-])([0-9]{2}):([0-9]{2}))?$",
+    )
+    .unwrap()
+});
+
+impl DtTm {
+    pub fn utc(&self) -> NaiveDateTime {
+        match self.offset {
+            Some(offset) => self.local - TimeDelta::seconds(offset.local_minus_utc().into()),
+            None => self.local,
+        }
+    }
+
+    pub fn to_utc_string(self) -> String {
+        self.utc().and_utc().to_rfc3339_opts(SecondsFormat::AutoSi, true)
     }
-    Ok(DtTm(yyyy, mm, dd, h, m))
+}
+
+pub fn parse_date_time_with(s: &str, precision: Precision, offset: OffsetPolicy) -> Result<DtTm, DateTimeErr> {
+    let caps = DT_TM_REGEX.captures(s).ok_or(DateTimeErr::Format)?;
+    let number = |i: usize| caps.get(i).map_or(Ok(0), |m| m.as_str().parse::<u32>().map_err(|_| DateTimeErr::Format));
+    let date = NaiveDate::from_ymd_opt(number(1)? as i32, number(2)?, number(3)?).ok_or(DateTimeErr::Format)?;
+    let time =
+        time_from_parts(&caps[4], &caps[5], caps.get(6).map(|m| m.as_str()), caps.get(7).map(|m| m.as_str())).map_err(|()| DateTimeErr::Format)?;
+    let tz_offset = match (caps.get(8), caps.get(9)) {
+        (Some(_), _) => Some(FixedOffset::east_opt(0).ok_or(DateTimeErr::Format)?),
+        (None, Some(sign)) => {
+            let (h, m) = (number(10)?, number(11)?);
+            if h > 23 || m > 59 {
+                return Err(DateTimeErr::Format);
+            }
+            let seconds = (h * 3600 + m * 60) as i32;
+            Some(FixedOffset::east_opt(if sign.as_str() == "-" { -seconds } else { seconds }).ok_or(DateTimeErr::Format)?)
+        }
+        (None, None) => None,
+    };
+    if time.precision > precision {
+        return Err(DateTimeErr::Precision);
+    }
+    match offset {
+        OffsetPolicy::Utc if caps.get(8).is_none() => return Err(DateTimeErr::Offset),
+        OffsetPolicy::Required if tz_offset.is_none() => return Err(DateTimeErr::Offset),
+        _ => {}
+    }
+    Ok(DtTm { local: NaiveDateTime::new(date, time.time), offset: tz_offset, precision: time.precision })
+}
+
+pub fn parse_date_time(s: &str) -> Result<DtTm, ()> {
+    parse_date_time_with(s, Precision::default(), OffsetPolicy::default()).map_err(|_| ())
+}
+
+pub fn shift_date_time(date_time: NaiveDateTime, span: TimeSpan, forward: bool) -> Option<NaiveDateTime> {
+    let amount = i64::from(span.amount);
+    let delta = match span.unit {
+        TimeUnit::Years | TimeUnit::Months => {
+            let months = Months::new(if span.unit == TimeUnit::Years { span.amount.checked_mul(12)? } else { span.amount });
+            return if forward { date_time.checked_add_months(months) } else { date_time.checked_sub_months(months) };
+        }
+        TimeUnit::Weeks => TimeDelta::try_weeks(amount)?,
+        TimeUnit::Days => TimeDelta::try_days(amount)?,
+        TimeUnit::Hours => TimeDelta::try_hours(amount)?,
+        TimeUnit::Minutes => TimeDelta::try_minutes(amount)?,
+        TimeUnit::Seconds => TimeDelta::try_seconds(amount)?,
+    };
+    if forward { date_time.checked_add_signed(delta) } else { date_time.checked_sub_signed(delta) }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{DtTm, parse_date_time};
+    use chrono::{FixedOffset, NaiveDateTime};
+
+    use crate::rule::{OffsetPolicy, Precision, TimeSpan};
+
+    use super::{DateTimeErr, DtTm, parse_date_time, parse_date_time_with, shift_date_time};
+
+    fn dt_tm(local: &str, offset: Option<i32>, precision: Precision) -> DtTm {
+        DtTm {
+            local: NaiveDateTime::parse_from_str(local, "%Y-%m-%dT%H:%M:%S%.f").unwrap(),
+            offset: offset.map(|seconds| FixedOffset::east_opt(seconds).unwrap()),
+            precision,
+        }
+    }
 
     #[test]
     fn parse_date_time_ok() {
-        assert_eq!(parse_date_time("2029-12-31T06:11Z"), Ok(DtTm(2029, 12, 31, 6, 11)));
+        assert_eq!(parse_date_time("2029-12-31T06:11Z"), Ok(dt_tm("2029-12-31T06:11:00", Some(0), Precision::Minutes)));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29Z"), Ok(dt_tm("2026-10-28T10:27:29", Some(0), Precision::Seconds)));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29.973Z"), Ok(dt_tm("2026-10-28T10:27:29.973", Some(0), Precision::Millis)));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29.123456789Z"), Ok(dt_tm("2026-10-28T10:27:29.123456789", Some(0), Precision::Nanos)));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29-03:00"), Ok(dt_tm("2026-10-28T10:27:29", Some(-10800), Precision::Seconds)));
+        assert_eq!(parse_date_time("2026-10-28t10:27:29+05:30"), Ok(dt_tm("2026-10-28T10:27:29", Some(19800), Precision::Seconds)));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29z"), Ok(dt_tm("2026-10-28T10:27:29", Some(0), Precision::Seconds)));
+    }
+
+    #[test]
+    fn parse_date_time_utc() {
+        assert_eq!(parse_date_time("2026-10-28T22:27:29-03:00").unwrap().utc(), dt_tm("2026-10-29T01:27:29", None, Precision::Seconds).local);
+        assert_eq!(parse_date_time("2026-10-28T22:27:29-03:00").unwrap().to_utc_string(), "2026-10-29T01:27:29Z".to_string());
+        assert_eq!(parse_date_time("2026-10-28T10:27:29.973+01:00").unwrap().to_utc_string(), "2026-10-28T09:27:29.973Z".to_string());
+        assert_eq!(parse_date_time("2026-10-28T10:27Z").unwrap().to_utc_string(), "2026-10-28T10:27:00Z".to_string());
+    }
+
+    #[test]
+    fn parse_date_time_precision() {
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27Z", Precision::Minutes, OffsetPolicy::Required).map(|dt| dt.precision),
+            Ok(Precision::Minutes)
+        );
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29Z", Precision::Minutes, OffsetPolicy::Required), Err(DateTimeErr::Precision));
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29Z", Precision::Seconds, OffsetPolicy::Required).map(|dt| dt.precision),
+            Ok(Precision::Seconds)
+        );
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29.973Z", Precision::Seconds, OffsetPolicy::Required), Err(DateTimeErr::Precision));
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29.973Z", Precision::Millis, OffsetPolicy::Required).map(|dt| dt.precision),
+            Ok(Precision::Millis)
+        );
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29.9731Z", Precision::Millis, OffsetPolicy::Required), Err(DateTimeErr::Precision));
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29.9731Z", Precision::Nanos, OffsetPolicy::Required).map(|dt| dt.precision),
+            Ok(Precision::Nanos)
+        );
+    }
+
+    #[test]
+    fn parse_date_time_offset() {
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29Z", Precision::Nanos, OffsetPolicy::Utc).map(|dt| dt.offset),
+            Ok(FixedOffset::east_opt(0))
+        );
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29+00:00", Precision::Nanos, OffsetPolicy::Utc), Err(DateTimeErr::Offset));
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29-03:00", Precision::Nanos, OffsetPolicy::Utc), Err(DateTimeErr::Offset));
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29-03:00", Precision::Nanos, OffsetPolicy::Required).map(|dt| dt.offset),
+            Ok(FixedOffset::west_opt(10800))
+        );
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29", Precision::Nanos, OffsetPolicy::Required), Err(DateTimeErr::Offset));
+        assert_eq!(parse_date_time_with("2026-10-28T10:27:29", Precision::Nanos, OffsetPolicy::Any).map(|dt| dt.offset), Ok(None));
+        assert_eq!(
+            parse_date_time_with("2026-10-28T10:27:29Z", Precision::Nanos, OffsetPolicy::Any).map(|dt| dt.offset),
+            Ok(FixedOffset::east_opt(0))
+        );
+    }
+
+    #[test]
+    fn shift_date_time_span() {
+        let now = dt_tm("2026-10-18T12:30:00", None, Precision::Seconds).local;
+        assert_eq!(shift_date_time(now, TimeSpan::years(18), false), Some(dt_tm("2008-10-18T12:30:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::months(4), true), Some(dt_tm("2027-02-18T12:30:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::weeks(2), false), Some(dt_tm("2026-10-04T12:30:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::days(90), true), Some(dt_tm("2027-01-16T12:30:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::hours(13), true), Some(dt_tm("2026-10-19T01:30:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::minutes(31), false), Some(dt_tm("2026-10-18T11:59:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::seconds(61), true), Some(dt_tm("2026-10-18T12:31:01", None, Precision::Seconds).local));
+        let leap = dt_tm("2024-02-29T00:00:00", None, Precision::Seconds).local;
+        assert_eq!(shift_date_time(leap, TimeSpan::years(1), true), Some(dt_tm("2025-02-28T00:00:00", None, Precision::Seconds).local));
+        assert_eq!(shift_date_time(now, TimeSpan::years(u32::MAX), true), None);
     }
 
     #[test]
@@ -46,10 +197,13 @@ mod tests {
         assert_eq!(parse_date_time("106"), Err(()));
         assert_eq!(parse_date_time("10"), Err(()));
         assert_eq!(parse_date_time("1"), Err(()));
-        assert_eq!(parse_date_time("2026-10-28T10:27:29Z"), Err(()));
-        assert_eq!(parse_date_time("2026-10-28T10:27:29.973Z"), Err(()));
         assert_eq!(parse_date_time("10-2026-28T10:27:29.973Z"), Err(()));
         assert_eq!(parse_date_time("28-10-2026T10:27:29.973Z"), Err(()));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29.Z"), Err(()));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29.1234567891Z"), Err(()));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29-0300"), Err(()));
+        assert_eq!(parse_date_time("2026-10-28T10:27:29-03"), Err(()));
+        assert_eq!(parse_date_time("2026-10-28 10:27:29Z"), Err(()));
     }
 
     #[test]
@@ -65,5 +219,8 @@ mod tests {
         assert_eq!(parse_date_time("2024-04-26T24:20Z"), Err(()));
         assert_eq!(parse_date_time("2024-04-26T04:99Z"), Err(()));
         assert_eq!(parse_date_time("2024-04-26T72:93Z"), Err(()));
+        assert_eq!(parse_date_time("2024-04-26T10:27:60Z"), Err(()));
+        assert_eq!(parse_date_time("2024-04-26T10:27:29+24:00"), Err(()));
+        assert_eq!(parse_date_time("2024-04-26T10:27:29-03:60"), Err(()));
     }
 }
@@ -0,0 +1,124 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveDateTime;
+use regex::Regex;
+
+use crate::rule::IsoDuration;
+
+use super::date_time::shift_date_time;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FieldOffset<'a> {
+    pub path: &'a str,
+    pub forward: bool,
+    pub duration: IsoDuration,
+}
+
+static DUR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^P(?:([0-9]+)Y)?(?:([0-9]+)M)?(?:([0-9]+)W)?(?:([0-9]+)D)?(?:T(?:([0-9]+)H)?(?:([0-9]+)M)?(?:([0-9]+)S)?)?$").unwrap()
+});
+
+static FIELD_OFFSET_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\S+)\s+([+-])\s+(P\S*)$").unwrap());
+
+static OFFSET_LIKE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s[+-]\s|[+-]\s*PT?[0-9]").unwrap());
+
+pub fn parse_duration(s: &str) -> Result<IsoDuration, ()> {
+    let caps = DUR_REGEX.captures(s).ok_or(())?;
+    if s == "P" || s.ends_with('T') {
+        return Err(());
+    }
+    let number = |i: usize| caps.get(i).map_or(Ok(0), |m| m.as_str().parse::<u32>().map_err(|_| ()));
+    Ok(IsoDuration {
+        years: number(1)?,
+        months: number(2)?,
+        weeks: number(3)?,
+        days: number(4)?,
+        hours: number(5)?,
+        minutes: number(6)?,
+        seconds: number(7)?,
+    })
+}
+
+pub fn shift_by_duration(date_time: NaiveDateTime, duration: &IsoDuration, forward: bool) -> Option<NaiveDateTime> {
+    duration.spans().into_iter().try_fold(date_time, |current, span| shift_date_time(current, span, forward))
+}
+
+pub fn parse_field_offset(path: &str) -> Result<Option<FieldOffset<'_>>, ()> {
+    let Some(caps) = FIELD_OFFSET_REGEX.captures(path) else {
+        return if OFFSET_LIKE_REGEX.is_match(path) { Err(()) } else { Ok(None) };
+    };
+    let duration = parse_duration(&caps[3])?;
+    Ok(Some(FieldOffset { path: caps.get(1).ok_or(())?.as_str(), forward: &caps[2] == "+", duration }))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::rule::IsoDuration;
+
+    use super::{FieldOffset, parse_duration, parse_field_offset, shift_by_duration};
+
+    #[test]
+    fn parse_duration_ok() {
+        assert_eq!(parse_duration("P1D"), Ok(IsoDuration { days: 1, ..IsoDuration::default() }));
+        assert_eq!(parse_duration("PT2H"), Ok(IsoDuration { hours: 2, ..IsoDuration::default() }));
+        assert_eq!(parse_duration("P1Y2M"), Ok(IsoDuration { years: 1, months: 2, ..IsoDuration::default() }));
+        assert_eq!(parse_duration("P2W"), Ok(IsoDuration { weeks: 2, ..IsoDuration::default() }));
+        assert_eq!(parse_duration("P1Y2M3DT4H5M6S"), Ok(IsoDuration { years: 1, months: 2, weeks: 0, days: 3, hours: 4, minutes: 5, seconds: 6 }));
+        assert_eq!(parse_duration("PT0S"), Ok(IsoDuration::default()));
+    }
+
+    #[test]
+    fn parse_duration_invalid() {
+        assert_eq!(parse_duration(""), Err(()));
+        assert_eq!(parse_duration("P"), Err(()));
+        assert_eq!(parse_duration("PT"), Err(()));
+        assert_eq!(parse_duration("P1DT"), Err(()));
+        assert_eq!(parse_duration("1D"), Err(()));
+        assert_eq!(parse_duration("P1H"), Err(()));
+        assert_eq!(parse_duration("PT1D"), Err(()));
+        assert_eq!(parse_duration("P1D2Y"), Err(()));
+        assert_eq!(parse_duration("P-1D"), Err(()));
+        assert_eq!(parse_duration("PT0.5S"), Err(()));
+        assert_eq!(parse_duration("P99999999999D"), Err(()));
+    }
+
+    #[test]
+    fn shift_by_duration_ok() {
+        let date_time = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap().and_hms_opt(22, 0, 0).unwrap();
+        assert_eq!(
+            shift_by_duration(date_time, &parse_duration("P1MT3H").unwrap(), true),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap().and_hms_opt(1, 0, 0)
+        );
+        assert_eq!(
+            shift_by_duration(date_time, &parse_duration("P1Y1W").unwrap(), false),
+            NaiveDate::from_ymd_opt(2025, 1, 24).unwrap().and_hms_opt(22, 0, 0)
+        );
+    }
+
+    #[test]
+    fn parse_field_offset_ok() {
+        assert_eq!(parse_field_offset("checkin"), Ok(None));
+        assert_eq!(parse_field_offset("check-in"), Ok(None));
+        assert_eq!(parse_field_offset("period.start-Period"), Ok(None));
+        assert_eq!(
+            parse_field_offset("checkin + P1D"),
+            Ok(Some(FieldOffset { path: "checkin", forward: true, duration: parse_duration("P1D").unwrap() }))
+        );
+        assert_eq!(
+            parse_field_offset("period.start - PT2H"),
+            Ok(Some(FieldOffset { path: "period.start", forward: false, duration: parse_duration("PT2H").unwrap() }))
+        );
+    }
+
+    #[test]
+    fn parse_field_offset_malformed() {
+        assert_eq!(parse_field_offset("checkin + P1X"), Err(()));
+        assert_eq!(parse_field_offset("checkin+P1D"), Err(()));
+        assert_eq!(parse_field_offset("checkin -PT2H"), Err(()));
+        assert_eq!(parse_field_offset("checkin + 1D"), Err(()));
+        assert_eq!(parse_field_offset("date_value + 1 day"), Err(()));
+        assert_eq!(parse_field_offset("a + b + P1D"), Err(()));
+    }
+}
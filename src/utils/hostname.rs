@@ -0,0 +1,38 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static LABEL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?$").unwrap());
+
+pub fn hostname_is_valid(s: &str) -> bool {
+    !s.is_empty() && s.len() <= 253 && s.split('.').all(|label| LABEL_REGEX.is_match(label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hostname_is_valid;
+
+    #[test]
+    fn hostname_is_valid_true() {
+        assert!(hostname_is_valid("localhost"));
+        assert!(hostname_is_valid("example.com"));
+        assert!(hostname_is_valid("api-v2.example.com.br"));
+        assert!(hostname_is_valid("3com.com"));
+        assert!(hostname_is_valid(&format!("{}.com", "a".repeat(63))));
+    }
+
+    #[test]
+    fn hostname_is_valid_false() {
+        assert!(!hostname_is_valid(""));
+        assert!(!hostname_is_valid("example.com."));
+        assert!(!hostname_is_valid(".example.com"));
+        assert!(!hostname_is_valid("example..com"));
+        assert!(!hostname_is_valid("-example.com"));
+        assert!(!hostname_is_valid("example-.com"));
+        assert!(!hostname_is_valid("exa_mple.com"));
+        assert!(!hostname_is_valid("exa mple.com"));
+        assert!(!hostname_is_valid("exámple.com"));
+        assert!(!hostname_is_valid(&format!("{}.com", "a".repeat(64))));
+        assert!(!hostname_is_valid(&vec!["a".repeat(63); 4].join(".")));
+    }
+}
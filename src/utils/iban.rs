@@ -0,0 +1,198 @@
+#[derive(Debug, PartialEq)]
+pub enum IbanErr {
+    Format,
+    Country,
+    Length(usize),
+    Checksum,
+}
+
+const IBAN_COUNTRIES: [(&str, usize, &str); 89] = [
+    ("AD", 24, "4n,4n,12c"),
+    ("AE", 23, "3n,16n"),
+    ("AL", 28, "8n,16c"),
+    ("AT", 20, "5n,11n"),
+    ("AZ", 28, "4a,20c"),
+    ("BA", 20, "3n,3n,8n,2n"),
+    ("BE", 16, "3n,7n,2n"),
+    ("BG", 22, "4a,4n,2n,8c"),
+    ("BH", 22, "4a,14c"),
+    ("BI", 27, "5n,5n,11n,2n"),
+    ("BR", 29, "8n,5n,10n,1a,1c"),
+    ("BY", 28, "4c,4n,16c"),
+    ("CH", 21, "5n,12c"),
+    ("CR", 22, "4n,14n"),
+    ("CY", 28, "3n,5n,16c"),
+    ("CZ", 24, "4n,6n,10n"),
+    ("DE", 22, "8n,10n"),
+    ("DJ", 27, "5n,5n,11n,2n"),
+    ("DK", 18, "4n,9n,1n"),
+    ("DO", 28, "4c,20n"),
+    ("EE", 20, "2n,14n"),
+    ("EG", 29, "4n,4n,17n"),
+    ("ES", 24, "4n,4n,1n,1n,10n"),
+    ("FI", 18, "3n,11n"),
+    ("FK", 18, "2a,12n"),
+    ("FO", 18, "4n,9n,1n"),
+    ("FR", 27, "5n,5n,11c,2n"),
+    ("GB", 22, "4a,6n,8n"),
+    ("GE", 22, "2a,16n"),
+    ("GI", 23, "4a,15c"),
+    ("GL", 18, "4n,9n,1n"),
+    ("GR", 27, "3n,4n,16c"),
+    ("GT", 28, "4c,20c"),
+    ("HN", 28, "4a,20n"),
+    ("HR", 21, "7n,10n"),
+    ("HU", 28, "3n,4n,1n,15n,1n"),
+    ("IE", 22, "4a,6n,8n"),
+    ("IL", 23, "3n,3n,13n"),
+    ("IQ", 23, "4a,3n,12n"),
+    ("IS", 26, "4n,2n,6n,10n"),
+    ("IT", 27, "1a,5n,5n,12c"),
+    ("JO", 30, "4a,4n,18c"),
+    ("KW", 30, "4a,22c"),
+    ("KZ", 20, "3n,13c"),
+    ("LB", 28, "4n,20c"),
+    ("LC", 32, "4a,24c"),
+    ("LI", 21, "5n,12c"),
+    ("LT", 20, "5n,11n"),
+    ("LU", 20, "3n,13c"),
+    ("LV", 21, "4a,13c"),
+    ("LY", 25, "3n,3n,15n"),
+    ("MC", 27, "5n,5n,11c,2n"),
+    ("MD", 24, "2c,18c"),
+    ("ME", 22, "3n,13n,2n"),
+    ("MK", 19, "3n,10c,2n"),
+    ("MN", 20, "4n,12n"),
+    ("MR", 27, "5n,5n,11n,2n"),
+    ("MT", 31, "4a,5n,18c"),
+    ("MU", 30, "4a,2n,2n,12n,3n,3a"),
+    ("NI", 28, "4a,20n"),
+    ("NL", 18, "4a,10n"),
+    ("NO", 15, "4n,6n,1n"),
+    ("OM", 23, "3n,16c"),
+    ("PK", 24, "4a,16c"),
+    ("PL", 28, "8n,16n"),
+    ("PS", 29, "4a,21c"),
+    ("PT", 25, "4n,4n,11n,2n"),
+    ("QA", 29, "4a,21c"),
+    ("RO", 24, "4a,16c"),
+    ("RS", 22, "3n,13n,2n"),
+    ("RU", 33, "9n,5n,15c"),
+    ("SA", 24, "2n,18c"),
+    ("SC", 31, "4a,2n,2n,16n,3a"),
+    ("SD", 18, "2n,12n"),
+    ("SE", 24, "3n,16n,1n"),
+    ("SI", 19, "5n,8n,2n"),
+    ("SK", 24, "4n,6n,10n"),
+    ("SM", 27, "1a,5n,5n,12c"),
+    ("SO", 23, "4n,3n,12n"),
+    ("ST", 25, "4n,4n,11n,2n"),
+    ("SV", 28, "4a,20n"),
+    ("TL", 23, "3n,14n,2n"),
+    ("TN", 24, "2n,3n,13n,2n"),
+    ("TR", 26, "5n,1n,16c"),
+    ("UA", 29, "6n,19c"),
+    ("VA", 22, "3n,15n"),
+    ("VG", 24, "4a,16n"),
+    ("XK", 20, "4n,10n,2n"),
+    ("YE", 30, "4a,4n,18c"),
+];
+
+fn bban_matches(bban: &str, structure: &str) -> bool {
+    let mut rest = bban;
+    for segment in structure.split(',') {
+        let (len, kind) = segment.split_at(segment.len() - 1);
+        let len = len.parse::<usize>().unwrap();
+        let (part, tail) = rest.split_at(len);
+        let valid = match kind {
+            "n" => part.chars().all(|c| c.is_ascii_digit()),
+            "a" => part.chars().all(|c| c.is_ascii_uppercase()),
+            _ => part.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()),
+        };
+        if !valid {
+            return false;
+        }
+        rest = tail;
+    }
+    true
+}
+
+fn mod_97(iban: &str) -> u32 {
+    iban[4..].chars().chain(iban[..4].chars()).fold(0, |rest, c| {
+        let value = c.to_digit(36).unwrap();
+        if value > 9 { (rest * 100 + value) % 97 } else { (rest * 10 + value) % 97 }
+    })
+}
+
+pub fn normalize_iban(s: &str) -> Result<String, IbanErr> {
+    if !s.chars().all(|c| c.is_ascii_alphanumeric() || c == ' ') {
+        return Err(IbanErr::Format);
+    }
+    let iban: String = s.chars().filter(|c| *c != ' ').map(|c| c.to_ascii_uppercase()).collect();
+    if iban.len() < 4 || !iban[..2].chars().all(|c| c.is_ascii_uppercase()) || !iban[2..4].chars().all(|c| c.is_ascii_digit()) {
+        return Err(IbanErr::Format);
+    }
+    let (_, len, structure) = IBAN_COUNTRIES.iter().find(|(country, _, _)| *country == &iban[..2]).ok_or(IbanErr::Country)?;
+    if iban.len() != *len {
+        return Err(IbanErr::Length(*len));
+    }
+    if !bban_matches(&iban[4..], structure) {
+        return Err(IbanErr::Format);
+    }
+    if mod_97(&iban) != 1 {
+        return Err(IbanErr::Checksum);
+    }
+    Ok(iban)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IBAN_COUNTRIES, IbanErr, normalize_iban};
+
+    #[test]
+    fn iban_countries_structure() {
+        for (country, len, structure) in IBAN_COUNTRIES {
+            let bban_len: usize = structure.split(',').map(|segment| segment[..segment.len() - 1].parse::<usize>().unwrap()).sum();
+            assert_eq!(bban_len + 4, len, "{country}");
+        }
+    }
+
+    #[test]
+    fn normalize_iban_ok() {
+        assert_eq!(normalize_iban("DE89 3704 0044 0532 0130 00"), Ok("DE89370400440532013000".into()));
+        assert_eq!(normalize_iban("gb82 west 1234 5698 7654 32"), Ok("GB82WEST12345698765432".into()));
+        assert_eq!(normalize_iban("FR14 2004 1010 0505 0001 3M02 606"), Ok("FR1420041010050500013M02606".into()));
+        assert_eq!(normalize_iban("BR1800360305000010009795493C1"), Ok("BR1800360305000010009795493C1".into()));
+        assert_eq!(normalize_iban("NO9386011117947"), Ok("NO9386011117947".into()));
+        assert_eq!(normalize_iban("MU17BOMM0101101030300200000MUR"), Ok("MU17BOMM0101101030300200000MUR".into()));
+    }
+
+    #[test]
+    fn normalize_iban_format() {
+        assert_eq!(normalize_iban("DE89-3704-0044-0532-0130-00"), Err(IbanErr::Format));
+        assert_eq!(normalize_iban("D"), Err(IbanErr::Format));
+        assert_eq!(normalize_iban("1289370400440532013000"), Err(IbanErr::Format));
+        assert_eq!(normalize_iban("DEAB370400440532013000"), Err(IbanErr::Format));
+        assert_eq!(normalize_iban("GB82 1234 1234 5698 7654 32"), Err(IbanErr::Format));
+        assert_eq!(normalize_iban("DE89 3704 0044 0532 0130 0A"), Err(IbanErr::Format));
+    }
+
+    #[test]
+    fn normalize_iban_country() {
+        assert_eq!(normalize_iban("US64 SVBK US6S 3300 9673 8637"), Err(IbanErr::Country));
+        assert_eq!(normalize_iban("ZZ00 0000"), Err(IbanErr::Country));
+    }
+
+    #[test]
+    fn normalize_iban_length() {
+        assert_eq!(normalize_iban("DE89 3704 0044 0532 0130 0"), Err(IbanErr::Length(22)));
+        assert_eq!(normalize_iban("NO9386011117947 1"), Err(IbanErr::Length(15)));
+    }
+
+    #[test]
+    fn normalize_iban_checksum() {
+        assert_eq!(normalize_iban("DE89 3704 0044 0532 0130 01"), Err(IbanErr::Checksum));
+        assert_eq!(normalize_iban("GB82 WEST 1234 5698 7654 31"), Err(IbanErr::Checksum));
+        assert_eq!(normalize_iban("DE00 3704 0044 0532 0130 00"), Err(IbanErr::Checksum));
+    }
+}
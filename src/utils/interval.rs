@@ -0,0 +1,87 @@
+use chrono::{NaiveDateTime, NaiveTime};
+
+use super::{
+    date::parse_date,
+    date_time::parse_date_time,
+    duration::{parse_duration, shift_by_duration},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Itv {
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IntervalErr {
+    Format,
+    Order,
+}
+
+fn parse_instant(s: &str) -> Result<NaiveDateTime, ()> {
+    parse_date_time(s).map(|date_time| date_time.utc()).or_else(|()| parse_date(s).map(|date| date.0.and_time(NaiveTime::MIN)))
+}
+
+pub fn parse_interval(s: &str) -> Result<Itv, IntervalErr> {
+    let (a, b) = s.split_once('/').ok_or(IntervalErr::Format)?;
+    let (start, end) = match (a.starts_with('P'), b.starts_with('P')) {
+        (false, false) => (parse_instant(a).map_err(|()| IntervalErr::Format)?, parse_instant(b).map_err(|()| IntervalErr::Format)?),
+        (false, true) => {
+            let start = parse_instant(a).map_err(|()| IntervalErr::Format)?;
+            let duration = parse_duration(b).map_err(|()| IntervalErr::Format)?;
+            (start, shift_by_duration(start, &duration, true).ok_or(IntervalErr::Format)?)
+        }
+        (true, false) => {
+            let end = parse_instant(b).map_err(|()| IntervalErr::Format)?;
+            let duration = parse_duration(a).map_err(|()| IntervalErr::Format)?;
+            (shift_by_duration(end, &duration, false).ok_or(IntervalErr::Format)?, end)
+        }
+        (true, true) => return Err(IntervalErr::Format),
+    };
+    if start >= end {
+        return Err(IntervalErr::Order);
+    }
+    Ok(Itv { start, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::{IntervalErr, Itv, parse_interval};
+
+    fn itv(start: &str, end: &str) -> Itv {
+        Itv {
+            start: NaiveDateTime::parse_from_str(start, "%Y-%m-%d %H:%M").unwrap(),
+            end: NaiveDateTime::parse_from_str(end, "%Y-%m-%d %H:%M").unwrap(),
+        }
+    }
+
+    #[test]
+    fn parse_interval_ok() {
+        assert_eq!(parse_interval("2026-01-01/2026-03-31"), Ok(itv("2026-01-01 00:00", "2026-03-31 00:00")));
+        assert_eq!(parse_interval("2026-01-01T08:00Z/2026-01-01T09:30-03:00"), Ok(itv("2026-01-01 08:00", "2026-01-01 12:30")));
+        assert_eq!(parse_interval("2026-01-01T08:00Z/2026-01-02"), Ok(itv("2026-01-01 08:00", "2026-01-02 00:00")));
+        assert_eq!(parse_interval("2026-01-31/P1M"), Ok(itv("2026-01-31 00:00", "2026-02-28 00:00")));
+        assert_eq!(parse_interval("PT2H/2026-01-01T08:00Z"), Ok(itv("2026-01-01 06:00", "2026-01-01 08:00")));
+    }
+
+    #[test]
+    fn parse_interval_format() {
+        assert_eq!(parse_interval("2026-01-01"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("2026-01-01/"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("2026-01-01--2026-03-31"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("2026-01-01/2026-03-31/2026-04-01"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("01/01/2026"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("P1D/P2D"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("2026-01-01T08:00/P1D"), Err(IntervalErr::Format));
+        assert_eq!(parse_interval("2026-01-01/P1X"), Err(IntervalErr::Format));
+    }
+
+    #[test]
+    fn parse_interval_order() {
+        assert_eq!(parse_interval("2026-03-31/2026-01-01"), Err(IntervalErr::Order));
+        assert_eq!(parse_interval("2026-01-01/2026-01-01"), Err(IntervalErr::Order));
+        assert_eq!(parse_interval("2026-01-01/PT0S"), Err(IntervalErr::Order));
+    }
+}
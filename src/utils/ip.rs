@@ -0,0 +1,84 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub fn parse_ipv4(s: &str) -> Result<Ipv4Addr, ()> {
+    s.parse::<Ipv4Addr>().map_err(|_| ())
+}
+
+pub fn parse_ipv6(s: &str) -> Result<Ipv6Addr, ()> {
+    s.parse::<Ipv6Addr>().map_err(|_| ())
+}
+
+pub fn parse_cidr(s: &str) -> Result<(IpAddr, u8), ()> {
+    let (addr, prefix) = s.split_once('/').ok_or(())?;
+    if prefix.is_empty() || prefix.len() > 3 || !prefix.chars().all(|c| c.is_ascii_digit()) || (prefix.len() > 1 && prefix.starts_with('0')) {
+        return Err(());
+    }
+    let prefix = prefix.parse::<u8>().map_err(|_| ())?;
+    let addr = addr.parse::<IpAddr>().map_err(|_| ())?;
+    let max = if addr.is_ipv4() { 32 } else { 128 };
+    if prefix > max {
+        return Err(());
+    }
+    Ok((addr, prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use super::{parse_cidr, parse_ipv4, parse_ipv6};
+
+    #[test]
+    fn parse_ipv4_ok() {
+        assert_eq!(parse_ipv4("192.168.0.1"), Ok(Ipv4Addr::new(192, 168, 0, 1)));
+        assert_eq!(parse_ipv4("0.0.0.0"), Ok(Ipv4Addr::new(0, 0, 0, 0)));
+        assert_eq!(parse_ipv4("255.255.255.255"), Ok(Ipv4Addr::new(255, 255, 255, 255)));
+    }
+
+    #[test]
+    fn parse_ipv4_err() {
+        assert_eq!(parse_ipv4("256.0.0.1"), Err(()));
+        assert_eq!(parse_ipv4("192.168.0"), Err(()));
+        assert_eq!(parse_ipv4("192.168.0.1.1"), Err(()));
+        assert_eq!(parse_ipv4("192.168.00.1"), Err(()));
+        assert_eq!(parse_ipv4(" 192.168.0.1"), Err(()));
+        assert_eq!(parse_ipv4("::1"), Err(()));
+    }
+
+    #[test]
+    fn parse_ipv6_ok() {
+        assert_eq!(parse_ipv6("::1"), Ok(Ipv6Addr::LOCALHOST));
+        assert_eq!(parse_ipv6("2001:db8::8a2e:370:7334"), Ok(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0x8a2e, 0x370, 0x7334)));
+        assert_eq!(parse_ipv6("::ffff:192.168.0.1"), Ok(Ipv4Addr::new(192, 168, 0, 1).to_ipv6_mapped()));
+    }
+
+    #[test]
+    fn parse_ipv6_err() {
+        assert_eq!(parse_ipv6("2001:db8::8a2e::7334"), Err(()));
+        assert_eq!(parse_ipv6("2001:db8:0:0:0:0:0:0:1"), Err(()));
+        assert_eq!(parse_ipv6("2001:dg8::1"), Err(()));
+        assert_eq!(parse_ipv6("[::1]"), Err(()));
+        assert_eq!(parse_ipv6("192.168.0.1"), Err(()));
+    }
+
+    #[test]
+    fn parse_cidr_ok() {
+        assert_eq!(parse_cidr("10.0.0.0/8"), Ok((IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8)));
+        assert_eq!(parse_cidr("192.168.0.1/32"), Ok((IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)), 32)));
+        assert_eq!(parse_cidr("0.0.0.0/0"), Ok((IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0)));
+        assert_eq!(parse_cidr("2001:db8::/32"), Ok((IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)), 32)));
+        assert_eq!(parse_cidr("::1/128"), Ok((IpAddr::V6(Ipv6Addr::LOCALHOST), 128)));
+    }
+
+    #[test]
+    fn parse_cidr_err() {
+        assert_eq!(parse_cidr("10.0.0.0"), Err(()));
+        assert_eq!(parse_cidr("10.0.0.0/"), Err(()));
+        assert_eq!(parse_cidr("10.0.0.0/33"), Err(()));
+        assert_eq!(parse_cidr("10.0.0.0/08"), Err(()));
+        assert_eq!(parse_cidr("10.0.0.0/+8"), Err(()));
+        assert_eq!(parse_cidr("10.0.0.0/8/8"), Err(()));
+        assert_eq!(parse_cidr("2001:db8::/129"), Err(()));
+        assert_eq!(parse_cidr("example.com/24"), Err(()));
+    }
+}
@@ -0,0 +1,100 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::country::normalize_country_alpha2;
+
+const LANGUAGES_ALPHA2: [&str; 184] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch",
+    "co", "cr", "cs", "cu", "cv", "cy", "da", "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy",
+    "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it",
+    "iu", "ja", "jv", "ka", "kg", "ki", "kj", "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln", "lo",
+    "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb", "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny",
+    "oc", "oj", "om", "or", "os", "pa", "pi", "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "sh", "si", "sk",
+    "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw",
+    "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+const LANGUAGES_ALPHA3: [&str; 237] = [
+    "ace", "ach", "ada", "ady", "afh", "ain", "akk", "ale", "alt", "ang", "anp", "arc", "arn", "arp", "arw", "ast", "awa", "bal", "ban", "bas",
+    "bej", "bem", "bho", "bik", "bin", "bla", "bra", "bua", "bug", "byn", "cad", "car", "ceb", "chb", "chg", "chk", "chm", "chn", "cho", "chp",
+    "chr", "chy", "cnr", "cop", "crh", "csb", "dak", "dar", "del", "den", "dgr", "din", "doi", "dsb", "dua", "dum", "dyu", "efi", "egy", "eka",
+    "elx", "enm", "ewo", "fan", "fat", "fil", "fon", "frm", "fro", "frr", "frs", "fur", "gaa", "gay", "gba", "gez", "gil", "gmh", "goh", "gon",
+    "gor", "got", "grb", "grc", "gsw", "gwi", "hai", "haw", "hil", "hit", "hmn", "hsb", "hup", "iba", "ilo", "inh", "jbo", "jpr", "jrb", "kaa",
+    "kab", "kac", "kam", "kaw", "kbd", "kha", "kho", "kmb", "kok", "kos", "kpe", "krc", "krl", "kru", "kum", "kut", "lad", "lah", "lam", "lez",
+    "lol", "loz", "lua", "lui", "lun", "luo", "lus", "mad", "mag", "mai", "mak", "man", "mas", "mdf", "mdr", "men", "mga", "mic", "min", "mis",
+    "mnc", "mni", "moh", "mos", "mul", "mus", "mwl", "mwr", "myv", "nap", "nds", "new", "nia", "niu", "nog", "non", "nqo", "nso", "nwc", "nym",
+    "nyn", "nyo", "nzi", "osa", "ota", "pag", "pal", "pam", "pap", "pau", "peo", "phn", "pon", "pro", "raj", "rap", "rar", "rom", "rup", "sad",
+    "sah", "sam", "sas", "sat", "scn", "sco", "sel", "sga", "shn", "sid", "sma", "smj", "smn", "sms", "snk", "sog", "srn", "srr", "suk", "sus",
+    "sux", "syc", "syr", "tem", "ter", "tet", "tig", "tiv", "tkl", "tlh", "tli", "tmh", "tog", "tpi", "tsi", "tum", "tvl", "tyv", "udm", "uga",
+    "umb", "und", "vai", "vot", "wal", "war", "was", "xal", "yao", "yap", "zap", "zbl", "zen", "zgh", "zun", "zxx", "zza",
+];
+
+static LANGUAGE_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([A-Za-z]{2,3})(?:-([A-Za-z]{4}))?(?:-([A-Za-z]{2}|[0-9]{3}))?((?:-(?:[A-Za-z0-9]{5,8}|[0-9][A-Za-z0-9]{3}))*)$").unwrap()
+});
+
+fn language_is_valid(language: &str) -> bool {
+    match language.len() {
+        2 => LANGUAGES_ALPHA2.binary_search(&language).is_ok(),
+        _ => LANGUAGES_ALPHA3.binary_search(&language).is_ok(),
+    }
+}
+
+pub fn normalize_language(s: &str) -> Result<String, ()> {
+    let caps = LANGUAGE_TAG_REGEX.captures(s).ok_or(())?;
+    let language = caps[1].to_ascii_lowercase();
+    if !language_is_valid(&language) {
+        return Err(());
+    }
+    let mut tag = language;
+    if let Some(script) = caps.get(2) {
+        let script = script.as_str().to_ascii_lowercase();
+        tag.push('-');
+        tag.push_str(&script[..1].to_ascii_uppercase());
+        tag.push_str(&script[1..]);
+    }
+    if let Some(region) = caps.get(3) {
+        let region = region.as_str();
+        let region = if region.chars().all(|c| c.is_ascii_digit()) { region.to_string() } else { normalize_country_alpha2(region)? };
+        tag.push('-');
+        tag.push_str(&region);
+    }
+    tag.push_str(&caps[4].to_ascii_lowercase());
+    Ok(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LANGUAGES_ALPHA2, LANGUAGES_ALPHA3, normalize_language};
+
+    #[test]
+    fn languages_sorted() {
+        assert!(LANGUAGES_ALPHA2.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(LANGUAGES_ALPHA3.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn normalize_language_ok() {
+        assert_eq!(normalize_language("pt"), Ok("pt".into()));
+        assert_eq!(normalize_language("pt-BR"), Ok("pt-BR".into()));
+        assert_eq!(normalize_language("PT-br"), Ok("pt-BR".into()));
+        assert_eq!(normalize_language("zh-hant-tw"), Ok("zh-Hant-TW".into()));
+        assert_eq!(normalize_language("es-419"), Ok("es-419".into()));
+        assert_eq!(normalize_language("de-CH-1996"), Ok("de-CH-1996".into()));
+        assert_eq!(normalize_language("haw"), Ok("haw".into()));
+        assert_eq!(normalize_language("sl-ROZAJ"), Ok("sl-rozaj".into()));
+    }
+
+    #[test]
+    fn normalize_language_err() {
+        assert_eq!(normalize_language(""), Err(()));
+        assert_eq!(normalize_language("pt_BR"), Err(()));
+        assert_eq!(normalize_language("xx"), Err(()));
+        assert_eq!(normalize_language("por"), Err(()));
+        assert_eq!(normalize_language("pt-XX"), Err(()));
+        assert_eq!(normalize_language("pt-BRA"), Err(()));
+        assert_eq!(normalize_language("english"), Err(()));
+        assert_eq!(normalize_language("pt-"), Err(()));
+    }
+}
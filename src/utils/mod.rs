@@ -1,5 +1,24 @@
+pub mod bic;
+pub mod card;
+pub mod cep;
+pub mod cnpj;
+pub mod country;
+pub mod cpf;
+pub mod currency;
 pub mod date;
 pub mod date_time;
+pub mod duration;
 pub mod email;
+pub mod hostname;
+pub mod iban;
+pub mod interval;
+pub mod ip;
+pub mod language;
+pub mod nano_id;
+pub mod phone;
 pub mod string;
 pub mod time;
+pub mod timezone;
+pub mod ulid;
+pub mod url;
+pub mod uuid;
@@ -0,0 +1,25 @@
+pub const NANO_ID_ALPHABET: &str = "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn nano_id_is_valid(s: &str, alphabet: &str, len: usize) -> bool {
+    s.chars().count() == len && s.chars().all(|c| alphabet.contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NANO_ID_ALPHABET, nano_id_is_valid};
+
+    #[test]
+    fn nano_id_is_valid_true() {
+        assert!(nano_id_is_valid("V1StGXR8_Z5jdHi6B-myT", NANO_ID_ALPHABET, 21));
+        assert!(nano_id_is_valid("4f90d13a42", "0123456789abcdef", 10));
+    }
+
+    #[test]
+    fn nano_id_is_valid_false() {
+        assert!(!nano_id_is_valid("V1StGXR8_Z5jdHi6B-my", NANO_ID_ALPHABET, 21));
+        assert!(!nano_id_is_valid("V1StGXR8_Z5jdHi6B-myTT", NANO_ID_ALPHABET, 21));
+        assert!(!nano_id_is_valid("V1StGXR8 Z5jdHi6B-myT", NANO_ID_ALPHABET, 21));
+        assert!(!nano_id_is_valid("4F90D13A42", "0123456789abcdef", 10));
+        assert!(!nano_id_is_valid("", NANO_ID_ALPHABET, 21));
+    }
+}
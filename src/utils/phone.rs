@@ -0,0 +1,165 @@
+#[derive(Debug, PartialEq)]
+pub struct PhoneCountry {
+    pub country: &'static str,
+    pub code: &'static str,
+    pub trunk: Option<&'static str>,
+    pub min: usize,
+    pub max: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Phone {
+    pub e164: String,
+    pub countries: Vec<&'static str>,
+}
+
+const PHONE_COUNTRIES: [PhoneCountry; 60] = [
+    PhoneCountry { country: "AE", code: "971", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "AO", code: "244", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "AR", code: "54", trunk: Some("0"), min: 10, max: 11 },
+    PhoneCountry { country: "AT", code: "43", trunk: Some("0"), min: 4, max: 13 },
+    PhoneCountry { country: "AU", code: "61", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "BE", code: "32", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "BO", code: "591", trunk: Some("0"), min: 8, max: 8 },
+    PhoneCountry { country: "BR", code: "55", trunk: Some("0"), min: 10, max: 11 },
+    PhoneCountry { country: "CA", code: "1", trunk: Some("1"), min: 10, max: 10 },
+    PhoneCountry { country: "CH", code: "41", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "CL", code: "56", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "CN", code: "86", trunk: Some("0"), min: 10, max: 11 },
+    PhoneCountry { country: "CO", code: "57", trunk: None, min: 10, max: 10 },
+    PhoneCountry { country: "CV", code: "238", trunk: None, min: 7, max: 7 },
+    PhoneCountry { country: "CZ", code: "420", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "DE", code: "49", trunk: Some("0"), min: 6, max: 13 },
+    PhoneCountry { country: "DK", code: "45", trunk: None, min: 8, max: 8 },
+    PhoneCountry { country: "EC", code: "593", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "EG", code: "20", trunk: Some("0"), min: 9, max: 10 },
+    PhoneCountry { country: "ES", code: "34", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "FI", code: "358", trunk: Some("0"), min: 5, max: 12 },
+    PhoneCountry { country: "FR", code: "33", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "GB", code: "44", trunk: Some("0"), min: 9, max: 10 },
+    PhoneCountry { country: "GR", code: "30", trunk: None, min: 10, max: 10 },
+    PhoneCountry { country: "HK", code: "852", trunk: None, min: 8, max: 8 },
+    PhoneCountry { country: "HU", code: "36", trunk: Some("06"), min: 8, max: 9 },
+    PhoneCountry { country: "ID", code: "62", trunk: Some("0"), min: 9, max: 12 },
+    PhoneCountry { country: "IE", code: "353", trunk: Some("0"), min: 7, max: 9 },
+    PhoneCountry { country: "IL", code: "972", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "IN", code: "91", trunk: Some("0"), min: 10, max: 10 },
+    PhoneCountry { country: "IT", code: "39", trunk: None, min: 6, max: 11 },
+    PhoneCountry { country: "JP", code: "81", trunk: Some("0"), min: 9, max: 10 },
+    PhoneCountry { country: "KR", code: "82", trunk: Some("0"), min: 8, max: 10 },
+    PhoneCountry { country: "KZ", code: "7", trunk: Some("8"), min: 10, max: 10 },
+    PhoneCountry { country: "MX", code: "52", trunk: None, min: 10, max: 10 },
+    PhoneCountry { country: "MY", code: "60", trunk: Some("0"), min: 8, max: 10 },
+    PhoneCountry { country: "MZ", code: "258", trunk: None, min: 8, max: 9 },
+    PhoneCountry { country: "NG", code: "234", trunk: Some("0"), min: 8, max: 10 },
+    PhoneCountry { country: "NL", code: "31", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "NO", code: "47", trunk: None, min: 8, max: 8 },
+    PhoneCountry { country: "NZ", code: "64", trunk: Some("0"), min: 8, max: 10 },
+    PhoneCountry { country: "PE", code: "51", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "PH", code: "63", trunk: Some("0"), min: 8, max: 10 },
+    PhoneCountry { country: "PL", code: "48", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "PT", code: "351", trunk: None, min: 9, max: 9 },
+    PhoneCountry { country: "PY", code: "595", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "RO", code: "40", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "RU", code: "7", trunk: Some("8"), min: 10, max: 10 },
+    PhoneCountry { country: "SA", code: "966", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "SE", code: "46", trunk: Some("0"), min: 7, max: 9 },
+    PhoneCountry { country: "SG", code: "65", trunk: None, min: 8, max: 8 },
+    PhoneCountry { country: "TH", code: "66", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "TR", code: "90", trunk: Some("0"), min: 10, max: 10 },
+    PhoneCountry { country: "TW", code: "886", trunk: Some("0"), min: 8, max: 9 },
+    PhoneCountry { country: "UA", code: "380", trunk: Some("0"), min: 9, max: 9 },
+    PhoneCountry { country: "US", code: "1", trunk: Some("1"), min: 10, max: 10 },
+    PhoneCountry { country: "UY", code: "598", trunk: Some("0"), min: 8, max: 8 },
+    PhoneCountry { country: "VE", code: "58", trunk: Some("0"), min: 10, max: 10 },
+    PhoneCountry { country: "VN", code: "84", trunk: Some("0"), min: 9, max: 10 },
+    PhoneCountry { country: "ZA", code: "27", trunk: Some("0"), min: 9, max: 9 },
+];
+
+fn nsn_is_valid(meta: &PhoneCountry, nsn: &str) -> bool {
+    (meta.min..=meta.max).contains(&nsn.len()) && (meta.trunk.is_none() || !nsn.starts_with('0'))
+}
+
+fn clean_phone(s: &str) -> Result<(bool, String), ()> {
+    let s = s.trim();
+    let (plus, rest) = match s.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if !rest.chars().all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '.' | '(' | ')')) {
+        return Err(());
+    }
+    let digits: String = rest.chars().filter(|c| c.is_ascii_digit()).collect();
+    match digits.strip_prefix("00") {
+        Some(international) if !plus => Ok((true, international.into())),
+        _ => Ok((plus, digits)),
+    }
+}
+
+pub fn parse_phone(s: &str, default_country: Option<&str>) -> Result<Phone, ()> {
+    let (international, digits) = clean_phone(s)?;
+    if international {
+        let (code, nsn) = (1..=3)
+            .filter(|len| *len < digits.len())
+            .map(|len| digits.split_at(len))
+            .find(|(code, _)| PHONE_COUNTRIES.iter().any(|meta| meta.code == *code))
+            .ok_or(())?;
+        let countries: Vec<&'static str> =
+            PHONE_COUNTRIES.iter().filter(|meta| meta.code == code && nsn_is_valid(meta, nsn)).map(|meta| meta.country).collect();
+        if countries.is_empty() {
+            return Err(());
+        }
+        return Ok(Phone { e164: format!("+{code}{nsn}"), countries });
+    }
+    let meta = PHONE_COUNTRIES.iter().find(|meta| Some(meta.country) == default_country).ok_or(())?;
+    let nsn = match meta.trunk {
+        Some(trunk) => digits.strip_prefix(trunk).unwrap_or(&digits),
+        None => &digits,
+    };
+    if !nsn_is_valid(meta, nsn) {
+        return Err(());
+    }
+    Ok(Phone { e164: format!("+{}{nsn}", meta.code), countries: vec![meta.country] })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PHONE_COUNTRIES, Phone, parse_phone};
+
+    #[test]
+    fn phone_countries_metadata() {
+        assert!(PHONE_COUNTRIES.windows(2).all(|pair| pair[0].country < pair[1].country));
+        assert!(PHONE_COUNTRIES.iter().all(|meta| meta.min <= meta.max && meta.code.len() + meta.max <= 15));
+    }
+
+    #[test]
+    fn parse_phone_international() {
+        assert_eq!(parse_phone("+55 (11) 91234-5678", None), Ok(Phone { e164: "+5511912345678".into(), countries: vec!["BR"] }));
+        assert_eq!(parse_phone("0055 11 91234 5678", None), Ok(Phone { e164: "+5511912345678".into(), countries: vec!["BR"] }));
+        assert_eq!(parse_phone("+351 912 345 678", Some("BR")), Ok(Phone { e164: "+351912345678".into(), countries: vec!["PT"] }));
+        assert_eq!(parse_phone("+1 (415) 555-2671", None), Ok(Phone { e164: "+14155552671".into(), countries: vec!["CA", "US"] }));
+        assert_eq!(parse_phone("+39 06 1234 5678", None), Ok(Phone { e164: "+390612345678".into(), countries: vec!["IT"] }));
+    }
+
+    #[test]
+    fn parse_phone_national() {
+        assert_eq!(parse_phone("(11) 91234-5678", Some("BR")), Ok(Phone { e164: "+5511912345678".into(), countries: vec!["BR"] }));
+        assert_eq!(parse_phone("011 91234-5678", Some("BR")), Ok(Phone { e164: "+5511912345678".into(), countries: vec!["BR"] }));
+        assert_eq!(parse_phone("020 7946 0958", Some("GB")), Ok(Phone { e164: "+442079460958".into(), countries: vec!["GB"] }));
+        assert_eq!(parse_phone("912 345 678", Some("PT")), Ok(Phone { e164: "+351912345678".into(), countries: vec!["PT"] }));
+        assert_eq!(parse_phone("8 (912) 345-67-89", Some("RU")), Ok(Phone { e164: "+79123456789".into(), countries: vec!["RU"] }));
+    }
+
+    #[test]
+    fn parse_phone_err() {
+        assert_eq!(parse_phone("(11) 91234-5678", None), Err(()));
+        assert_eq!(parse_phone("(11) 91234-5678", Some("ZZ")), Err(()));
+        assert_eq!(parse_phone("+55 11 91234-567890", None), Err(()));
+        assert_eq!(parse_phone("+55 11 9123", None), Err(()));
+        assert_eq!(parse_phone("+55 011 91234 5678", None), Err(()));
+        assert_eq!(parse_phone("+999 1234 5678", None), Err(()));
+        assert_eq!(parse_phone("+55 11 91234-5678 ext 2", None), Err(()));
+        assert_eq!(parse_phone("55+11912345678", None), Err(()));
+        assert_eq!(parse_phone("+", None), Err(()));
+    }
+}
@@ -1,40 +1,107 @@
 use std::sync::LazyLock;
 
+use chrono::NaiveTime;
 use regex::Regex;
 
-#[derive(Debug, PartialEq)]
-pub struct Tm(pub u8, pub u8);
+use crate::rule::Precision;
 
-static TM_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]{2}):([0-9]{2})$").unwrap());
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Tm {
+    pub time: NaiveTime,
+    pub precision: Precision,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TimeErr {
+    Format,
+    Precision,
+    Seconds,
+}
+
+static TM_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^([0-9]{2}):([0-9]{2})(?::([0-9]{2})(?:\.([0-9]{1,9}))?)?$").unwrap());
+
+pub fn time_from_parts(h: &str, m: &str, s: Option<&str>, fraction: Option<&str>) -> Result<Tm, ()> {
+    let h = h.parse::<u32>().map_err(|_| ())?;
+    let m = m.parse::<u32>().map_err(|_| ())?;
+    let precision = match (s, fraction) {
+        (None, _) => Precision::Minutes,
+        (Some(_), None) => Precision::Seconds,
+        (Some(_), Some(f)) if f.len() <= 3 => Precision::Millis,
+        (Some(_), Some(_)) => Precision::Nanos,
+    };
+    let s = s.map_or(Ok(0), |s| s.parse::<u32>().map_err(|_| ()))?;
+    let nanos = fraction.map_or(Ok(0), |f| format!("{f:0<9}").parse::<u32>().map_err(|_| ()))?;
+    let time = NaiveTime::from_hms_nano_opt(h, m, s, nanos).ok_or(())?;
+    Ok(Tm { time, precision })
+}
+
+pub fn parse_time_with(s: &str, precision: Precision, require_seconds: bool) -> Result<Tm, TimeErr> {
+    let caps = TM_REGEX.captures(s).ok_or(TimeErr::Format)?;
+    let seconds = caps.get(3).map(|m| m.as_str());
+    let time = time_from_parts(&caps[1], &caps[2], seconds, caps.get(4).map(|m| m.as_str())).map_err(|()| TimeErr::Format)?;
+    if time.precision > precision {
+        return Err(TimeErr::Precision);
+    }
+    if require_seconds && seconds.is_none() {
+        return Err(TimeErr::Seconds);
+    }
+    Ok(time)
+}
 
 pub fn parse_time(s: &str) -> Result<Tm, ()> {
-    let caps = TM_REGEX.captures(s).ok_or(())?;
-    let (_, [h, m]) = caps.extract();
-    let h = h.parse::<u8>().map_err(|_| ())?;
-    let m = m.parse::<u8>().map_err(|_| ())?;
-    if h > 23 || m > 59 {
-        return Err(());
-    }
-    Ok(Tm(h, m))
+    parse_time_with(s, Precision::default(), false).map_err(|_| ())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Tm, parse_time};
+    use chrono::NaiveTime;
+
+    use crate::rule::Precision;
+
+    use super::{TimeErr, Tm, parse_time, parse_time_with};
+
+    fn tm(time: &str, precision: Precision) -> Tm {
+        Tm { time: NaiveTime::parse_from_str(time, "%H:%M:%S%.f").unwrap(), precision }
+    }
 
     #[test]
     fn parse_time_ok() {
-        assert_eq!(parse_time("06:11"), Ok(Tm(6, 11)));
+        assert_eq!(parse_time("06:11"), Ok(tm("06:11:00", Precision::Minutes)));
+        assert_eq!(parse_time("08:30:00"), Ok(tm("08:30:00", Precision::Seconds)));
+        assert_eq!(parse_time("10:27:24"), Ok(tm("10:27:24", Precision::Seconds)));
+        assert_eq!(parse_time("08:30:00.250"), Ok(tm("08:30:00.25", Precision::Millis)));
+        assert_eq!(parse_time("10:27:23.2"), Ok(tm("10:27:23.2", Precision::Millis)));
+        assert_eq!(parse_time("10:27:23.123456789"), Ok(tm("10:27:23.123456789", Precision::Nanos)));
+    }
+
+    #[test]
+    fn parse_time_precision() {
+        assert_eq!(parse_time_with("08:30", Precision::Minutes, false).map(|t| t.precision), Ok(Precision::Minutes));
+        assert_eq!(parse_time_with("08:30:00", Precision::Minutes, false), Err(TimeErr::Precision));
+        assert_eq!(parse_time_with("08:30:00", Precision::Seconds, false).map(|t| t.precision), Ok(Precision::Seconds));
+        assert_eq!(parse_time_with("08:30:00.250", Precision::Seconds, false), Err(TimeErr::Precision));
+        assert_eq!(parse_time_with("08:30:00.250", Precision::Millis, false).map(|t| t.precision), Ok(Precision::Millis));
+        assert_eq!(parse_time_with("08:30:00.2500", Precision::Millis, false), Err(TimeErr::Precision));
+    }
+
+    #[test]
+    fn parse_time_seconds() {
+        assert_eq!(parse_time_with("08:30", Precision::Nanos, true), Err(TimeErr::Seconds));
+        assert_eq!(parse_time_with("08:30:00", Precision::Nanos, true).map(|t| t.precision), Ok(Precision::Seconds));
+        assert_eq!(parse_time_with("08:30:00.250", Precision::Nanos, true).map(|t| t.precision), Ok(Precision::Millis));
     }
 
     #[test]
     fn parse_time_invalid_format() {
-        assert_eq!(parse_time("10:27:23.235"), Err(()));
-        assert_eq!(parse_time("10:27:24"), Err(()));
         assert_eq!(parse_time("1061"), Err(()));
         assert_eq!(parse_time("106"), Err(()));
         assert_eq!(parse_time("10"), Err(()));
         assert_eq!(parse_time("1"), Err(()));
+        assert_eq!(parse_time("8:30"), Err(()));
+        assert_eq!(parse_time("08:30:0"), Err(()));
+        assert_eq!(parse_time("08:30:00."), Err(()));
+        assert_eq!(parse_time("08:30:00.1234567890"), Err(()));
+        assert_eq!(parse_time("08:30:00Z"), Err(()));
     }
 
     #[test]
@@ -44,5 +111,7 @@ mod tests {
         assert_eq!(parse_time("24:20"), Err(()));
         assert_eq!(parse_time("04:99"), Err(()));
         assert_eq!(parse_time("72:93"), Err(()));
+        assert_eq!(parse_time("10:27:60"), Err(()));
+        assert_eq!(parse_time("10:27:99.250"), Err(()));
     }
 }
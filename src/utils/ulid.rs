@@ -0,0 +1,37 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+static ULID_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-7][0-9A-HJKMNP-TV-Z]{25}$").unwrap());
+
+pub fn normalize_ulid(s: &str) -> Result<String, ()> {
+    let s = s.to_ascii_uppercase();
+    if !ULID_REGEX.is_match(&s) {
+        return Err(());
+    }
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_ulid;
+
+    #[test]
+    fn normalize_ulid_ok() {
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV"), Ok("01ARZ3NDEKTSV4RRFFQ69G5FAV".into()));
+        assert_eq!(normalize_ulid("01arz3ndektsv4rrffq69g5fav"), Ok("01ARZ3NDEKTSV4RRFFQ69G5FAV".into()));
+        assert_eq!(normalize_ulid("7ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Ok("7ZZZZZZZZZZZZZZZZZZZZZZZZZ".into()));
+    }
+
+    #[test]
+    fn normalize_ulid_err() {
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FA"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAVV"), Err(()));
+        assert_eq!(normalize_ulid("8ZZZZZZZZZZZZZZZZZZZZZZZZZ"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAI"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAL"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAO"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAU"), Err(()));
+        assert_eq!(normalize_ulid("01ARZ3NDEK-SV4RRFFQ69G5FAV"), Err(()));
+    }
+}
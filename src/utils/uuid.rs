@@ -0,0 +1,79 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+#[derive(Debug, PartialEq)]
+pub struct Uuid {
+    pub hex: String,
+    pub hyphenated: bool,
+}
+
+static UUID_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9A-Fa-f]{8}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{4}-[0-9A-Fa-f]{12}$|^[0-9A-Fa-f]{32}$").unwrap());
+
+impl Uuid {
+    pub fn version(&self) -> u8 {
+        u8::from_str_radix(&self.hex[12..13], 16).unwrap()
+    }
+
+    pub fn canonical(&self) -> String {
+        format!("{}-{}-{}-{}-{}", &self.hex[0..8], &self.hex[8..12], &self.hex[12..16], &self.hex[16..20], &self.hex[20..32])
+    }
+}
+
+pub fn parse_uuid(s: &str) -> Result<Uuid, ()> {
+    if !UUID_REGEX.is_match(s) {
+        return Err(());
+    }
+    let hex: String = s.chars().filter(|c| *c != '-').map(|c| c.to_ascii_lowercase()).collect();
+    let uuid = Uuid { hyphenated: hex.len() != s.len(), hex };
+    let nil_or_max = uuid.hex.chars().all(|c| c == '0') || uuid.hex.chars().all(|c| c == 'f');
+    let rfc = (1..=8).contains(&uuid.version()) && matches!(&uuid.hex[16..17], "8" | "9" | "a" | "b");
+    if !nil_or_max && !rfc {
+        return Err(());
+    }
+    Ok(uuid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Uuid, parse_uuid};
+
+    #[test]
+    fn parse_uuid_ok() {
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-a567-0e02b2c3d479"), Ok(Uuid { hex: "f47ac10b58cc4372a5670e02b2c3d479".into(), hyphenated: true }));
+        assert_eq!(parse_uuid("F47AC10B58CC4372A5670E02B2C3D479"), Ok(Uuid { hex: "f47ac10b58cc4372a5670e02b2c3d479".into(), hyphenated: false }));
+        assert_eq!(parse_uuid("00000000-0000-0000-0000-000000000000"), Ok(Uuid { hex: "00000000000000000000000000000000".into(), hyphenated: true }));
+        assert_eq!(parse_uuid("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF"), Ok(Uuid { hex: "ffffffffffffffffffffffffffffffff".into(), hyphenated: true }));
+    }
+
+    #[test]
+    fn parse_uuid_version() {
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-a567-0e02b2c3d479").unwrap().version(), 4);
+        assert_eq!(parse_uuid("01890a5d-ac96-774b-bcce-b302099a8057").unwrap().version(), 7);
+        assert_eq!(parse_uuid("6ba7b810-9dad-11d1-80b4-00c04fd430c8").unwrap().version(), 1);
+    }
+
+    #[test]
+    fn parse_uuid_canonical() {
+        assert_eq!(parse_uuid("F47AC10B58CC4372A5670E02B2C3D479").unwrap().canonical(), "f47ac10b-58cc-4372-a567-0e02b2c3d479".to_string());
+    }
+
+    #[test]
+    fn parse_uuid_invalid_format() {
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-a567-0e02b2c3d47"), Err(()));
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-a567-0e02b2c3d4799"), Err(()));
+        assert_eq!(parse_uuid("f47ac10b58cc-4372-a567-0e02b2c3d479"), Err(()));
+        assert_eq!(parse_uuid("{f47ac10b-58cc-4372-a567-0e02b2c3d479}"), Err(()));
+        assert_eq!(parse_uuid("g47ac10b-58cc-4372-a567-0e02b2c3d479"), Err(()));
+        assert_eq!(parse_uuid(""), Err(()));
+    }
+
+    #[test]
+    fn parse_uuid_invalid_value() {
+        assert_eq!(parse_uuid("f47ac10b-58cc-0372-a567-0e02b2c3d479"), Err(()));
+        assert_eq!(parse_uuid("f47ac10b-58cc-9372-a567-0e02b2c3d479"), Err(()));
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-c567-0e02b2c3d479"), Err(()));
+        assert_eq!(parse_uuid("f47ac10b-58cc-4372-7567-0e02b2c3d479"), Err(()));
+    }
+}
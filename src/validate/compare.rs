@@ -0,0 +1,289 @@
+use std::cmp::Ordering;
+
+use araucaria::{
+    operation::{Operand, OperandValue, Operation},
+    value::Value,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::{
+    rule::IsoDuration,
+    utils::{
+        date::parse_date,
+        date_time::parse_date_time,
+        duration::{parse_field_offset, shift_by_duration},
+        time::parse_time,
+    },
+};
+
+pub trait Shift: Sized {
+    fn shift(self, duration: &IsoDuration, forward: bool) -> Option<Self>;
+}
+
+impl Shift for NaiveDate {
+    fn shift(self, duration: &IsoDuration, forward: bool) -> Option<Self> {
+        shift_by_duration(self.and_time(NaiveTime::MIN), duration, forward).map(|date_time| date_time.date())
+    }
+}
+
+impl Shift for NaiveTime {
+    fn shift(self, duration: &IsoDuration, forward: bool) -> Option<Self> {
+        let date_time = NaiveDateTime::default().date().and_time(self);
+        shift_by_duration(date_time, duration, forward).filter(|shifted| shifted.date() == date_time.date()).map(|shifted| shifted.time())
+    }
+}
+
+impl Shift for NaiveDateTime {
+    fn shift(self, duration: &IsoDuration, forward: bool) -> Option<Self> {
+        shift_by_duration(self, duration, forward)
+    }
+}
+
+pub fn value_at<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path.is_empty() {
+        return Some(value);
+    }
+    path.split('.').try_fold(value, |current, segment| match current {
+        Value::Obj(obj) => obj.get(segment),
+        Value::Arr(arr) => arr.get(segment.parse::<usize>().ok()?),
+        _ => None,
+    })
+}
+
+type ResolvedOperand<'a> = (&'a str, Option<(IsoDuration, bool)>);
+
+fn operand_str<'a>(operand: &'a Operand, root: &'a Value) -> Option<Result<ResolvedOperand<'a>, ()>> {
+    match operand {
+        Operand::Value(OperandValue::Str(value)) => Some(Ok((value, None))),
+        Operand::Value(_) => Some(Err(())),
+        Operand::FieldPath(path) => {
+            let (path, offset) = match parse_field_offset(path) {
+                Ok(Some(field_offset)) => (field_offset.path, Some((field_offset.duration, field_offset.forward))),
+                Ok(None) => (path.as_str(), None),
+                Err(()) => return Some(Err(())),
+            };
+            match value_at(root, path)? {
+                Value::None => None,
+                Value::Str(value) => Some(Ok((value.as_str(), offset))),
+                _ => Some(Err(())),
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CompareErr<'a> {
+    Failed,
+    InvalidOperands(Vec<&'a Operand>),
+}
+
+enum Resolved<T> {
+    Missing,
+    Invalid,
+    Unshiftable,
+    Value(T),
+}
+
+type Expectation<'a> = (&'a Operand, fn(Ordering) -> bool);
+
+fn expectations(operation: &Operation) -> Vec<Expectation<'_>> {
+    match operation {
+        Operation::Eq(operand) => vec![(operand, Ordering::is_eq)],
+        Operation::Ne(operand) => vec![(operand, Ordering::is_ne)],
+        Operation::Gt(operand) => vec![(operand, Ordering::is_gt)],
+        Operation::Ge(operand) => vec![(operand, Ordering::is_ge)],
+        Operation::Lt(operand) => vec![(operand, Ordering::is_lt)],
+        Operation::Le(operand) => vec![(operand, Ordering::is_le)],
+        Operation::Btwn(operand_a, operand_b) => vec![(operand_a, Ordering::is_ge), (operand_b, Ordering::is_le)],
+    }
+}
+
+fn resolve<T: Shift>(operand: &Operand, root: &Value, parse: &impl Fn(&str) -> Option<T>) -> Resolved<T> {
+    match operand_str(operand, root) {
+        None => Resolved::Missing,
+        Some(Err(())) => Resolved::Invalid,
+        Some(Ok((str_value, offset))) => match (parse(str_value), offset) {
+            (None, _) => Resolved::Invalid,
+            (Some(other), None) => Resolved::Value(other),
+            (Some(other), Some((duration, forward))) => other.shift(&duration, forward).map_or(Resolved::Unshiftable, Resolved::Value),
+        },
+    }
+}
+
+pub fn invalid_operands<'a, T: Shift>(operation: &'a Operation, root: &Value, parse: impl Fn(&str) -> Option<T>) -> Vec<&'a Operand> {
+    expectations(operation)
+        .into_iter()
+        .filter(|(operand, _)| matches!(resolve(operand, root, &parse), Resolved::Invalid))
+        .map(|(operand, _)| operand)
+        .collect()
+}
+
+pub fn date_operand(s: &str) -> Option<NaiveDate> {
+    parse_date(s).map(|date| date.0).or_else(|()| parse_date_time(s).map(|date_time| date_time.local.date())).ok()
+}
+
+pub fn time_operand(s: &str) -> Option<NaiveTime> {
+    parse_time(s).map(|time| time.time).or_else(|()| parse_date_time(s).map(|date_time| date_time.local.time())).ok()
+}
+
+pub fn date_time_operand(s: &str) -> Option<NaiveDateTime> {
+    parse_date_time(s).map(|date_time| date_time.utc()).or_else(|()| parse_date(s).map(|date| date.0.and_time(NaiveTime::MIN))).ok()
+}
+
+pub fn compare_by<'a, T: Ord + Shift>(
+    operation: &'a Operation,
+    value: &T,
+    root: &Value,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<(), CompareErr<'a>> {
+    let checks: Vec<(Expectation, Resolved<T>)> =
+        expectations(operation).into_iter().map(|(operand, expected)| ((operand, expected), resolve(operand, root, &parse))).collect();
+    let invalid: Vec<&Operand> =
+        checks.iter().filter(|(_, resolved)| matches!(resolved, Resolved::Invalid)).map(|((operand, _), _)| *operand).collect();
+    if !invalid.is_empty() {
+        return Err(CompareErr::InvalidOperands(invalid));
+    }
+    if checks.iter().any(|(_, resolved)| matches!(resolved, Resolved::Missing)) {
+        return Ok(());
+    }
+    if checks.iter().all(|((_, expected), resolved)| matches!(resolved, Resolved::Value(other) if expected(value.cmp(other)))) {
+        Ok(())
+    } else {
+        Err(CompareErr::Failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use araucaria::{
+        operation::{Operand, OperandValue, Operation},
+        value::Value,
+    };
+    use chrono::{NaiveDate, NaiveTime};
+
+    use crate::{rule::IsoDuration, utils::duration::parse_duration};
+
+    use super::{CompareErr, Shift, compare_by, date_operand, date_time_operand, invalid_operands, time_operand, value_at};
+
+    static ROOT: LazyLock<Value> = LazyLock::new(|| {
+        Value::Obj(BTreeMap::from([("n".into(), Value::from("010")), ("bad".into(), Value::from("x")), ("num".into(), Value::U64(10))]))
+    });
+
+    impl Shift for u32 {
+        fn shift(self, duration: &IsoDuration, forward: bool) -> Option<Self> {
+            if forward { self.checked_add(duration.days) } else { self.checked_sub(duration.days) }
+        }
+    }
+
+    fn parse(s: &str) -> Option<u32> {
+        s.parse().ok()
+    }
+
+    fn value(s: &str) -> Operand {
+        Operand::Value(OperandValue::from(s))
+    }
+
+    fn field(path: &str) -> Operand {
+        Operand::FieldPath(path.into())
+    }
+
+    #[test]
+    fn value_at_path() {
+        let root = Value::Obj(BTreeMap::from([(
+            "a".into(),
+            Value::Arr(vec![Value::from("x"), Value::Obj(BTreeMap::from([("b".into(), Value::from("y"))]))]),
+        )]));
+        assert_eq!(value_at(&root, ""), Some(&root));
+        assert_eq!(value_at(&root, "a.0"), Some(&Value::from("x")));
+        assert_eq!(value_at(&root, "a.1.b"), Some(&Value::from("y")));
+        assert_eq!(value_at(&root, "a.2"), None);
+        assert_eq!(value_at(&root, "c"), None);
+    }
+
+    #[test]
+    fn compare_by_parsed() {
+        assert_eq!(compare_by(&Operation::Eq(value("10")), &10, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Eq(field("n")), &10, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Gt(field("n")), &9, &ROOT, parse), Err(CompareErr::Failed));
+        assert_eq!(compare_by(&Operation::Btwn(value("9"), value("011")), &10, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Btwn(value("9"), value("011")), &12, &ROOT, parse), Err(CompareErr::Failed));
+    }
+
+    #[test]
+    fn compare_by_missing_operand() {
+        assert_eq!(compare_by(&Operation::Ne(field("missing")), &10, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Btwn(value("11"), field("missing")), &10, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Eq(field("missing + P1D")), &10, &ROOT, parse), Ok(()));
+    }
+
+    #[test]
+    fn compare_by_invalid_operand() {
+        let bad = field("bad");
+        assert_eq!(compare_by(&Operation::Ne(bad.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&bad])));
+        let num = field("num");
+        assert_eq!(compare_by(&Operation::Ne(num.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&num])));
+        let u64_value = Operand::Value(OperandValue::U64(9));
+        assert_eq!(compare_by(&Operation::Ne(u64_value.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&u64_value])));
+        let btwn = Operation::Btwn(field("bad"), field("num"));
+        assert_eq!(compare_by(&btwn, &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&bad, &num])));
+        let btwn = Operation::Btwn(value("x"), field("missing"));
+        assert_eq!(compare_by(&btwn, &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&value("x")])));
+        let bad_offset = field("bad - P1D");
+        assert_eq!(compare_by(&Operation::Eq(bad_offset.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&bad_offset])));
+    }
+
+    #[test]
+    fn invalid_operands_resolved() {
+        let bad = field("bad");
+        let btwn = Operation::Btwn(bad.clone(), field("missing"));
+        assert_eq!(invalid_operands(&btwn, &ROOT, parse), vec![&bad]);
+        let offset = field("missing + 1D");
+        assert_eq!(invalid_operands(&Operation::Gt(offset.clone()), &ROOT, parse), vec![&offset]);
+        assert_eq!(invalid_operands(&Operation::Gt(field("n + P1D")), &ROOT, parse), Vec::<&Operand>::new());
+    }
+
+    #[test]
+    fn compare_by_offset() {
+        assert_eq!(compare_by(&Operation::Eq(field("n + P2D")), &12, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Eq(field("n - P2D")), &8, &ROOT, parse), Ok(()));
+        assert_eq!(compare_by(&Operation::Ge(field("n - P11D")), &0, &ROOT, parse), Err(CompareErr::Failed));
+        let malformed = field("n + P1X");
+        assert_eq!(compare_by(&Operation::Eq(malformed.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&malformed])));
+        let unit = field("n + 1D");
+        assert_eq!(compare_by(&Operation::Eq(unit.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&unit])));
+        let unspaced = field("n+P1D");
+        assert_eq!(compare_by(&Operation::Eq(unspaced.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&unspaced])));
+        let missing = field("missing + P1X");
+        assert_eq!(compare_by(&Operation::Eq(missing.clone()), &10, &ROOT, parse), Err(CompareErr::InvalidOperands(vec![&missing])));
+    }
+
+    #[test]
+    fn shift_temporal() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 28).unwrap();
+        assert_eq!(date.shift(&parse_duration("P1M3D").unwrap(), true), NaiveDate::from_ymd_opt(2026, 12, 1));
+        assert_eq!(date.shift(&parse_duration("PT36H").unwrap(), true), NaiveDate::from_ymd_opt(2026, 10, 29));
+        let time = NaiveTime::from_hms_opt(22, 0, 0).unwrap();
+        assert_eq!(time.shift(&parse_duration("PT1H30M").unwrap(), true), NaiveTime::from_hms_opt(23, 30, 0));
+        assert_eq!(time.shift(&parse_duration("PT2H").unwrap(), true), None);
+        assert_eq!(time.shift(&parse_duration("PT2H").unwrap(), false), NaiveTime::from_hms_opt(20, 0, 0));
+        assert_eq!(
+            date.and_time(time).shift(&parse_duration("PT2H").unwrap(), true),
+            NaiveDate::from_ymd_opt(2026, 10, 29).unwrap().and_hms_opt(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn temporal_operands() {
+        assert_eq!(date_operand("2026-10-28"), NaiveDate::from_ymd_opt(2026, 10, 28));
+        assert_eq!(date_operand("2026-10-28T22:27:29-03:00"), NaiveDate::from_ymd_opt(2026, 10, 28));
+        assert_eq!(date_operand("28/10/2026"), None);
+        assert_eq!(time_operand("08:30"), NaiveTime::from_hms_opt(8, 30, 0));
+        assert_eq!(time_operand("2026-10-28T08:30:15Z"), NaiveTime::from_hms_opt(8, 30, 15));
+        assert_eq!(time_operand("8h30"), None);
+        assert_eq!(date_time_operand("2026-10-28T22:27:29-03:00"), NaiveDate::from_ymd_opt(2026, 10, 29).unwrap().and_hms_opt(1, 27, 29));
+        assert_eq!(date_time_operand("2026-10-28"), NaiveDate::from_ymd_opt(2026, 10, 28).unwrap().and_hms_opt(0, 0, 0));
+        assert_eq!(date_time_operand("2026-10-28T22:27:29"), None);
+    }
+}
@@ -1,19 +1,21 @@
 use araucaria::{
     error::{SchemaErr, ValidationErr},
-    operation::{OperandValue, compare},
     schema::DateTimeSchema,
     value::Value,
 };
 
-use crate::utils::date_time::parse_date_time;
+use crate::{
+    utils::date_time::parse_date_time,
+    validate::compare::{compare_by, date_time_operand},
+};
 
 pub fn validate_date_time(schema: &DateTimeSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
     let mut base = vec![];
     match value {
         Value::Str(str_value) => {
-            if parse_date_time(str_value).is_ok() {
+            if let Ok(date_time) = parse_date_time(str_value) {
                 if let Some(operation) = &schema.operation {
-                    if let Some(Err(())) = compare(operation, &OperandValue::Str(str_value.clone()), root) {
+                    if compare_by(operation, &date_time.utc(), root, date_time_operand).is_err() {
                         base.push(ValidationErr::Operation(operation.clone()));
                     }
                 }
@@ -93,6 +95,29 @@ mod tests {
         assert_eq!(validate_date_time(&v, &u64_stub(), &ROOT), Err(SchemaErr::from([DATE_TIME, op_err.clone()])));
     }
 
+    #[test]
+    fn validate_date_time_rfc_3339() {
+        let v = DateTimeSchema::default();
+        assert_eq!(validate_date_time(&v, &Value::from("2026-10-28T10:27:29Z"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2026-10-28T10:27:29.973Z"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2026-10-28T10:27:29-03:00"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2026-10-28T10:27:29"), &ROOT), Err(SchemaErr::from([DATE_TIME])));
+    }
+
+    #[test]
+    fn validate_date_time_operation_offset() {
+        let v = DateTimeSchema::default().eq("2028-11-20T11:27Z".into());
+        let op_err = ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::from("2028-11-20T11:27Z"))));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T08:27:00-03:00"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T11:27:00.000+00:00"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T11:27:00-03:00"), &ROOT), Err(SchemaErr::from([op_err])));
+        let v = DateTimeSchema::default().gt_field("date_time_value".into());
+        let op_err = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("date_time_value".into())));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T10:27:00.001-01:00"), &ROOT), Ok(()));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T12:27+01:00"), &ROOT), Err(SchemaErr::from([op_err.clone()])));
+        assert_eq!(validate_date_time(&v, &Value::from("2028-11-20T20:00+12:00"), &ROOT), Err(SchemaErr::from([op_err])));
+    }
+
     #[test]
     fn validate_date_time_invalid_format() {
         let v = DateTimeSchema::default();
@@ -1,19 +1,21 @@
 use araucaria::{
     error::{SchemaErr, ValidationErr},
-    operation::{OperandValue, compare},
     schema::TimeSchema,
     value::Value,
 };
 
-use crate::utils::time::parse_time;
+use crate::{
+    utils::time::parse_time,
+    validate::compare::{compare_by, time_operand},
+};
 
 pub fn validate_time(schema: &TimeSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
     let mut base = vec![];
     match value {
         Value::Str(str_value) => {
-            if parse_time(str_value).is_ok() {
+            if let Ok(time) = parse_time(str_value) {
                 if let Some(operation) = &schema.operation {
-                    if let Some(Err(())) = compare(operation, &OperandValue::Str(str_value.clone()), root) {
+                    if compare_by(operation, &time.time, root, time_operand).is_err() {
                         base.push(ValidationErr::Operation(operation.clone()));
                     }
                 }
@@ -95,10 +97,32 @@ mod tests {
         assert_eq!(validate_time(&v, &u64_stub(), &ROOT), Err(SchemaErr::from([TIME, op_err.clone()])));
     }
 
+    #[test]
+    fn validate_time_seconds() {
+        let v = TimeSchema::default();
+        assert_eq!(validate_time(&v, &Value::from("08:30:00"), &ROOT), Ok(()));
+        assert_eq!(validate_time(&v, &Value::from("08:30:00.250"), &ROOT), Ok(()));
+        assert_eq!(validate_time(&v, &Value::from("08:30:60"), &ROOT), Err(SchemaErr::from([TIME])));
+    }
+
+    #[test]
+    fn validate_time_operation_precision() {
+        let v = TimeSchema::default().eq("08:30".into());
+        let op_err = ValidationErr::Operation(Operation::Eq(Operand::Value(OperandValue::from("08:30"))));
+        assert_eq!(validate_time(&v, &Value::from("08:30:00"), &ROOT), Ok(()));
+        assert_eq!(validate_time(&v, &Value::from("08:30:00.000"), &ROOT), Ok(()));
+        assert_eq!(validate_time(&v, &Value::from("08:30:00.250"), &ROOT), Err(SchemaErr::from([op_err])));
+        let v = TimeSchema::default().lt_field("time_value".into());
+        let op_err = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("time_value".into())));
+        assert_eq!(validate_time(&v, &Value::from("11:26:59.999"), &ROOT), Ok(()));
+        assert_eq!(validate_time(&v, &Value::from("11:27:00"), &ROOT), Err(SchemaErr::from([op_err.clone()])));
+        assert_eq!(validate_time(&v, &Value::from("11:27:00.001"), &ROOT), Err(SchemaErr::from([op_err])));
+    }
+
     #[test]
     fn validate_time_invalid_format() {
         let v = TimeSchema::default();
-        assert_eq!(validate_time(&v, &Value::from("10:27:23.235"), &ROOT), Err(SchemaErr::from([TIME])));
+        assert_eq!(validate_time(&v, &Value::from("10:27:23,235"), &ROOT), Err(SchemaErr::from([TIME])));
     }
 
     #[test]
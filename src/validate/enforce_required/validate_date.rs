@@ -1,19 +1,21 @@
 use araucaria::{
     error::{SchemaErr, ValidationErr},
-    operation::{OperandValue, compare},
     schema::DateSchema,
     value::Value,
 };
 
-use crate::utils::date::parse_date;
+use crate::{
+    utils::date::parse_date,
+    validate::compare::{compare_by, date_operand},
+};
 
 pub fn validate_date(schema: &DateSchema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
     let mut base = vec![];
     match value {
         Value::Str(str_value) => {
-            if parse_date(str_value).is_ok() {
+            if let Ok(date) = parse_date(str_value) {
                 if let Some(operation) = &schema.operation {
-                    if let Some(Err(())) = compare(operation, &OperandValue::Str(str_value.clone()), root) {
+                    if compare_by(operation, &date.0, root, date_operand).is_err() {
                         base.push(ValidationErr::Operation(operation.clone()));
                     }
                 }
@@ -53,7 +55,13 @@ mod tests {
 
     use super::validate_date;
 
-    static ROOT: LazyLock<Value> = LazyLock::new(|| Value::Obj(BTreeMap::from([("date_value".into(), Value::from("2026-10-28"))])));
+    static ROOT: LazyLock<Value> = LazyLock::new(|| {
+        Value::Obj(BTreeMap::from([
+            ("date_value".into(), Value::from("2026-10-28")),
+            ("date_time_value".into(), Value::from("2026-10-28T22:27-03:00")),
+            ("bad_value".into(), Value::from("28/10/2026")),
+        ]))
+    });
     const REQUIRED: ValidationErr = ValidationErr::Required;
     const DATE: ValidationErr = ValidationErr::Date;
 
@@ -93,6 +101,52 @@ mod tests {
         assert_eq!(validate_date(&v, &u64_stub(), &ROOT), Err(SchemaErr::from([DATE, op_err.clone()])));
     }
 
+    #[test]
+    fn validate_date_operation_chronological() {
+        let v = DateSchema::default().gt_field("date_time_value".into());
+        let op_err = ValidationErr::Operation(Operation::Gt(Operand::FieldPath("date_time_value".into())));
+        assert_eq!(validate_date(&v, &Value::from("2026-10-29"), &ROOT), Ok(()));
+        assert_eq!(validate_date(&v, &Value::from("2026-10-28"), &ROOT), Err(SchemaErr::from([op_err])));
+        let v = DateSchema::default().eq("2026-10-28T10:00Z".into());
+        assert_eq!(validate_date(&v, &Value::from("2026-10-28"), &ROOT), Ok(()));
+    }
+
+    #[test]
+    fn validate_date_operation_field_duration() {
+        let v = DateSchema::default().btwn_field("date_value + P1D".into(), "date_value + P30D".into());
+        let op_err =
+            ValidationErr::Operation(Operation::Btwn(Operand::FieldPath("date_value + P1D".into()), Operand::FieldPath("date_value + P30D".into())));
+        assert_eq!(validate_date(&v, &Value::from("2026-10-29"), &ROOT), Ok(()));
+        assert_eq!(validate_date(&v, &Value::from("2026-11-27"), &ROOT), Ok(()));
+        assert_eq!(validate_date(&v, &Value::from("2026-10-28"), &ROOT), Err(SchemaErr::from([op_err.clone()])));
+        assert_eq!(validate_date(&v, &Value::from("2026-11-28"), &ROOT), Err(SchemaErr::from([op_err])));
+        let v = DateSchema::default().le_field("date_value - P1M".into());
+        assert_eq!(validate_date(&v, &Value::from("2026-09-28"), &ROOT), Ok(()));
+        assert_eq!(
+            validate_date(&v, &Value::from("2026-09-29"), &ROOT),
+            Err(SchemaErr::from([ValidationErr::Operation(Operation::Le(Operand::FieldPath("date_value - P1M".into())))]))
+        );
+        let v = DateSchema::default().gt_field("date_value + 1 day".into());
+        assert_eq!(
+            validate_date(&v, &Value::from("2026-10-28"), &ROOT),
+            Err(SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("date_value + 1 day".into())))]))
+        );
+        let v = DateSchema::default().gt_field("date_value + P1X".into());
+        assert_eq!(
+            validate_date(&v, &Value::from("2026-10-30"), &ROOT),
+            Err(SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("date_value + P1X".into())))]))
+        );
+    }
+
+    #[test]
+    fn validate_date_operation_invalid_operand() {
+        let v = DateSchema::default().lt_field("bad_value".into());
+        let op_err = ValidationErr::Operation(Operation::Lt(Operand::FieldPath("bad_value".into())));
+        assert_eq!(validate_date(&v, &Value::from("2026-01-01"), &ROOT), Err(SchemaErr::from([op_err])));
+        let v = DateSchema::default().lt_field("missing_value".into());
+        assert_eq!(validate_date(&v, &Value::from("2026-01-01"), &ROOT), Ok(()));
+    }
+
     #[test]
     fn validate_date_invalid_format() {
         let v = DateSchema::default();
@@ -1,7 +1,12 @@
 use araucaria::{error::SchemaErr, schema::Schema, value::Value};
 
+pub use rules::{Clock, FixedClock, SystemClock, ValidateCtx, merge_schema_err, validate_rule, validate_rules, validate_with_rules};
+
+mod compare;
 mod enforce_optional;
 mod enforce_required;
+mod operands;
+mod rules;
 
 pub fn validate(schema: &Schema, value: &Value) -> Result<(), SchemaErr> {
     let result = enforce_required::validate(schema, value);
@@ -18,7 +23,8 @@ mod tests {
 
     use araucaria::{
         error::{SchemaErr, ValidationErr},
-        schema::{BoolSchema, F64Schema, ObjSchema, Schema, StrSchema, U64Schema},
+        operation::{Operand, OperandValue, Operation},
+        schema::{BoolSchema, DateSchema, DateTimeSchema, F64Schema, ObjSchema, Schema, StrSchema, TimeSchema, U64Schema},
         value::Value,
     };
 
@@ -104,4 +110,34 @@ mod tests {
         let value = Value::Obj(BTreeMap::from([("user".into(), Value::Obj(BTreeMap::from([("name".into(), Value::from("John"))])))]));
         assert_eq!(validate(&v, &value), Ok(()));
     }
+
+    #[test]
+    fn validate_invalid_operands() {
+        let v = Schema::Obj(ObjSchema::from(BTreeMap::from([
+            ("checkin".into(), Schema::Str(StrSchema::default())),
+            ("nights".into(), Schema::U64(U64Schema::default())),
+            ("checkout".into(), Schema::Date(DateSchema::default().gt_field("checkin".into()))),
+            ("opens_at".into(), Schema::Time(TimeSchema::default().btwn("08:00".into(), "8pm".into()))),
+            ("starts_at".into(), Schema::DateTime(DateTimeSchema::default().gt_field("nights".into()))),
+        ])));
+        let value = Value::Obj(BTreeMap::from([
+            ("checkin".into(), Value::from("28/10/2026")),
+            ("nights".into(), Value::U64(2)),
+            ("checkout".into(), Value::from("2026-10-30")),
+            ("opens_at".into(), Value::from("09:00")),
+            ("starts_at".into(), Value::from("2026-10-28T10:00Z")),
+        ]));
+        let err = SchemaErr::from([
+            ("checkout".into(), SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("checkin".into())))])),
+            (
+                "opens_at".into(),
+                SchemaErr::from([ValidationErr::Operation(Operation::Btwn(
+                    Operand::Value(OperandValue::from("08:00")),
+                    Operand::Value(OperandValue::from("8pm")),
+                ))]),
+            ),
+            ("starts_at".into(), SchemaErr::from([ValidationErr::Operation(Operation::Gt(Operand::FieldPath("nights".into())))])),
+        ]);
+        assert_eq!(validate(&v, &value), Err(err));
+    }
 }
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use araucaria::{
+    operation::Operation,
+    schema::{DateSchema, DateTimeSchema, Schema, TimeSchema},
+    value::Value,
+};
+
+use crate::serialize::{Operand, SchemaErr, ValidationErr, to_operand};
+
+use super::compare::{Shift, date_operand, date_time_operand, invalid_operands, time_operand};
+
+fn operand_errs<T: Shift>(
+    operation: &Operation,
+    root: &Value,
+    parse: impl Fn(&str) -> Option<T>,
+    err: fn(Operand) -> ValidationErr,
+) -> Result<(), SchemaErr> {
+    let errs: Vec<ValidationErr> = invalid_operands(operation, root, parse).into_iter().map(|operand| err(to_operand(operand.clone()))).collect();
+    if errs.is_empty() { Ok(()) } else { Err(SchemaErr::Validation(errs)) }
+}
+
+fn internal_validate_operands(schema: &Schema, value: &Value, root: &Value) -> Result<(), SchemaErr> {
+    match schema {
+        Schema::Date(DateSchema { operation: Some(operation), .. }) => operand_errs(operation, root, date_operand, ValidationErr::DateOperand),
+        Schema::Time(TimeSchema { operation: Some(operation), .. }) => operand_errs(operation, root, time_operand, ValidationErr::TimeOperand),
+        Schema::DateTime(DateTimeSchema { operation: Some(operation), .. }) => {
+            operand_errs(operation, root, date_time_operand, ValidationErr::DateTimeOperand)
+        }
+        Schema::Obj(schema) => {
+            let result: BTreeMap<String, SchemaErr> = schema
+                .validation
+                .iter()
+                .filter_map(|(k, v)| {
+                    let field_value = match value {
+                        Value::Obj(obj) => obj.get(k).unwrap_or(&Value::None),
+                        _ => &Value::None,
+                    };
+                    internal_validate_operands(v, field_value, root).err().map(|err| (k.clone(), err))
+                })
+                .collect();
+            if result.is_empty() { Ok(()) } else { Err(SchemaErr::Obj(result)) }
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_operands(schema: &Schema, value: &Value) -> Result<(), SchemaErr> {
+    internal_validate_operands(schema, value, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use araucaria::{
+        schema::{DateSchema, DateTimeSchema, ObjSchema, Schema, StrSchema, TimeSchema},
+        value::Value,
+    };
+
+    use crate::serialize::{Operand, OperandValue, SchemaErr, ValidationErr};
+
+    use super::validate_operands;
+
+    #[test]
+    fn validate_operands_ok() {
+        let schema = Schema::from(ObjSchema::from([
+            ("checkin".into(), Schema::from(DateSchema::default().ge("2026-01-01".into()))),
+            ("checkout".into(), Schema::from(DateSchema::default().gt_field("checkin".into()))),
+            ("leaves_at".into(), Schema::from(DateSchema::default().le_field("checkin + P30D".into()))),
+            ("opens_at".into(), Schema::from(TimeSchema::default().lt("18:00:00".into()))),
+            ("starts_at".into(), Schema::from(DateTimeSchema::default().gt_field("checkin".into()))),
+            ("name".into(), Schema::from(StrSchema::default().eq("x".into()))),
+        ]));
+        let value = Value::Obj(BTreeMap::from([("checkin".into(), Value::from("2026-10-28")), ("checkout".into(), Value::from("2026-10-30"))]));
+        assert_eq!(validate_operands(&schema, &value), Ok(()));
+        assert_eq!(validate_operands(&schema, &Value::None), Ok(()));
+    }
+
+    #[test]
+    fn validate_operands_err() {
+        let schema = Schema::from(ObjSchema::from([
+            ("checkin".into(), Schema::from(DateSchema::default().ge("01/01/2026".into()))),
+            ("checkout".into(), Schema::from(DateSchema::default().gt_field("checkin".into()))),
+            ("opens_at".into(), Schema::from(TimeSchema::default().btwn("08:00".into(), "8pm".into()))),
+            ("starts_at".into(), Schema::from(DateTimeSchema::default().gt_field("nights".into()))),
+            ("ends_at".into(), Schema::from(DateTimeSchema::default().gt_field("booked_at + 1D".into()))),
+            ("leaves_at".into(), Schema::from(DateTimeSchema::default().gt_field("booked_at + P1X".into()))),
+        ]));
+        let value = Value::Obj(BTreeMap::from([
+            ("checkin".into(), Value::from("28/10/2026")),
+            ("nights".into(), Value::U64(2)),
+            ("booked_at".into(), Value::from("2026-10-28T10:00Z")),
+        ]));
+        assert_eq!(
+            validate_operands(&schema, &value),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("checkin".into(), SchemaErr::Validation(vec![ValidationErr::DateOperand(Operand::Value(OperandValue::Str("01/01/2026".into())))])),
+                ("checkout".into(), SchemaErr::Validation(vec![ValidationErr::DateOperand(Operand::FieldPath("checkin".into()))])),
+                ("ends_at".into(), SchemaErr::Validation(vec![ValidationErr::DateTimeOperand(Operand::FieldPath("booked_at + 1D".into()))])),
+                ("leaves_at".into(), SchemaErr::Validation(vec![ValidationErr::DateTimeOperand(Operand::FieldPath("booked_at + P1X".into()))])),
+                ("opens_at".into(), SchemaErr::Validation(vec![ValidationErr::TimeOperand(Operand::Value(OperandValue::Str("8pm".into())))])),
+                ("starts_at".into(), SchemaErr::Validation(vec![ValidationErr::DateTimeOperand(Operand::FieldPath("nights".into()))])),
+            ])))
+        );
+    }
+}
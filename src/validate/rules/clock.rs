@@ -0,0 +1,41 @@
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+pub trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> DateTime<FixedOffset>;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FixedClock(pub DateTime<FixedOffset>);
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        Utc::now().fixed_offset()
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<FixedOffset> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta};
+
+    use super::{Clock, FixedClock, SystemClock};
+
+    #[test]
+    fn fixed_clock_now() {
+        let now = DateTime::parse_from_rfc3339("2026-10-18T12:30:00-03:00").unwrap();
+        assert_eq!(FixedClock(now).now(), now);
+        assert_eq!(FixedClock(now).now().offset().local_minus_utc(), -3 * 3600);
+        assert!(SystemClock.now() > now - TimeDelta::days(36500));
+        assert_eq!(SystemClock.now().offset().local_minus_utc(), 0);
+    }
+}
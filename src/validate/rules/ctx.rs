@@ -0,0 +1,37 @@
+use chrono::{DateTime, FixedOffset};
+
+use crate::{localize::LocaleFormat, rule::ValidatorRegistry};
+
+use super::{Clock, SystemClock};
+
+#[derive(Debug, Clone, Default)]
+pub struct ValidateCtx<'a> {
+    pub registry: Option<&'a ValidatorRegistry>,
+    pub clock: Option<&'a dyn Clock>,
+    pub lenient: Option<&'a LocaleFormat>,
+}
+
+impl<'a> ValidateCtx<'a> {
+    pub fn new() -> Self {
+        ValidateCtx::default()
+    }
+
+    pub fn registry(mut self, registry: &'a ValidatorRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    pub fn clock(mut self, clock: &'a dyn Clock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn lenient(mut self, format: &'a LocaleFormat) -> Self {
+        self.lenient = Some(format);
+        self
+    }
+
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        self.clock.unwrap_or(&SystemClock).now()
+    }
+}
@@ -0,0 +1,588 @@
+use std::collections::BTreeMap;
+
+use araucaria::{schema::Schema, value::Value};
+
+use crate::{
+    rule::{Rule, Rules},
+    serialize::{SchemaErr, ValidationErr, to_schema_err},
+};
+
+use super::{operands::validate_operands, validate};
+
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use ctx::ValidateCtx;
+use validate_card::{validate_card_expiry, validate_card_number};
+use validate_custom::validate_custom;
+use validate_hostname::validate_hostname;
+use validate_iban::validate_iban;
+use validate_identifier::{validate_nano_id, validate_ulid, validate_uuid};
+use validate_ip::{validate_cidr, validate_ip};
+use validate_normalized::validate_normalized;
+use validate_pattern::validate_pattern;
+use validate_phone::validate_phone;
+use validate_temporal::{validate_date_time, validate_duration, validate_interval, validate_relative, validate_time};
+use validate_url::validate_url;
+
+mod clock;
+mod ctx;
+mod validate_card;
+mod validate_custom;
+mod validate_hostname;
+mod validate_iban;
+mod validate_identifier;
+mod validate_ip;
+mod validate_normalized;
+mod validate_pattern;
+mod validate_phone;
+mod validate_temporal;
+mod validate_url;
+
+fn non_str_err(rule: &Rule) -> Option<ValidationErr> {
+    match rule {
+        Rule::Pattern(rule) => Some(ValidationErr::Pattern(rule.pattern.clone(), rule.description.clone())),
+        Rule::Cpf => Some(ValidationErr::Cpf),
+        Rule::Cnpj => Some(ValidationErr::Cnpj),
+        Rule::Cep => Some(ValidationErr::Cep),
+        Rule::Url(_) => Some(ValidationErr::Url),
+        Rule::Ip(version) => Some(ValidationErr::Ip(*version)),
+        Rule::Cidr(version) => Some(ValidationErr::Cidr(*version)),
+        Rule::Hostname => Some(ValidationErr::Hostname),
+        Rule::Uuid(_) => Some(ValidationErr::Uuid),
+        Rule::Ulid(_) => Some(ValidationErr::Ulid),
+        Rule::NanoId(rule) => Some(ValidationErr::NanoId(rule.len)),
+        Rule::CardNumber(_) => Some(ValidationErr::CardNumber),
+        Rule::CardExpiry => Some(ValidationErr::CardExpiry),
+        Rule::Iban => Some(ValidationErr::Iban),
+        Rule::Bic => Some(ValidationErr::Bic),
+        Rule::Phone(_) => Some(ValidationErr::Phone),
+        Rule::Country(format) => Some(ValidationErr::Country(*format)),
+        Rule::Currency => Some(ValidationErr::Currency),
+        Rule::Language => Some(ValidationErr::Language),
+        Rule::Timezone => Some(ValidationErr::Timezone),
+        Rule::DateTime(_) => Some(ValidationErr::DateTime),
+        Rule::Time(_) => Some(ValidationErr::Time),
+        Rule::Custom(_) | Rule::Relative(_) => None,
+        Rule::Duration(_) => Some(ValidationErr::Duration),
+        Rule::Interval(_) => Some(ValidationErr::Interval),
+    }
+}
+
+pub fn validate_rule(rule: &Rule, value: &Value, root: &Value, ctx: &ValidateCtx) -> Result<(), ValidationErr> {
+    if let Some(err) = non_str_err(rule).filter(|_| !matches!(value, Value::Str(_) | Value::None)) {
+        return Err(err);
+    }
+    match rule {
+        Rule::Pattern(rule) => validate_pattern(rule, value),
+        Rule::Custom(name) => validate_custom(name, ctx.registry, value, root),
+        Rule::Cpf => validate_normalized(rule, value, ValidationErr::Cpf),
+        Rule::Cnpj => validate_normalized(rule, value, ValidationErr::Cnpj),
+        Rule::Cep => validate_normalized(rule, value, ValidationErr::Cep),
+        Rule::Url(rule) => validate_url(rule, value),
+        Rule::Ip(version) => validate_ip(*version, value),
+        Rule::Cidr(version) => validate_cidr(*version, value),
+        Rule::Hostname => validate_hostname(value),
+        Rule::Uuid(rule) => validate_uuid(rule, value),
+        Rule::Ulid(_) => validate_ulid(value),
+        Rule::NanoId(rule) => validate_nano_id(rule, value),
+        Rule::CardNumber(rule) => validate_card_number(rule, value),
+        Rule::CardExpiry => validate_card_expiry(value, ctx.now().date_naive()),
+        Rule::Iban => validate_iban(value),
+        Rule::Bic => validate_normalized(rule, value, ValidationErr::Bic),
+        Rule::Phone(rule) => validate_phone(rule, value),
+        Rule::Country(format) => validate_normalized(rule, value, ValidationErr::Country(*format)),
+        Rule::Currency => validate_normalized(rule, value, ValidationErr::Currency),
+        Rule::Language => validate_normalized(rule, value, ValidationErr::Language),
+        Rule::Timezone => validate_normalized(rule, value, ValidationErr::Timezone),
+        Rule::DateTime(rule) => validate_date_time(rule, value),
+        Rule::Time(rule) => validate_time(rule, value),
+        Rule::Relative(rule) => validate_relative(rule, value, ctx.now()),
+        Rule::Duration(rule) => validate_duration(rule, value),
+        Rule::Interval(rule) => validate_interval(rule, value),
+    }
+}
+
+fn schema_err_at(segments: &[&str], node: Option<&Value>, errs: Vec<ValidationErr>) -> SchemaErr {
+    let Some((segment, rest)) = segments.split_first() else { return SchemaErr::Validation(errs) };
+    match (node, segment.parse::<usize>()) {
+        (Some(Value::Arr(arr)), Ok(i)) => {
+            let mut items = vec![SchemaErr::Validation(vec![]); arr.len().max(i + 1)];
+            items[i] = schema_err_at(rest, arr.get(i), errs);
+            SchemaErr::Arr(items)
+        }
+        (Some(Value::Obj(obj)), _) => SchemaErr::Obj(BTreeMap::from([(segment.to_string(), schema_err_at(rest, obj.get(*segment), errs))])),
+        _ => SchemaErr::Obj(BTreeMap::from([(segment.to_string(), schema_err_at(rest, None, errs))])),
+    }
+}
+
+fn values_at<'a>(node: &'a Value, segments: &[&str]) -> Vec<(Vec<String>, &'a Value)> {
+    let Some((segment, rest)) = segments.split_first() else { return vec![(vec![], node)] };
+    let children: Vec<(String, &Value)> = match (node, *segment) {
+        (Value::Arr(arr), "*") => arr.iter().enumerate().map(|(i, item)| (i.to_string(), item)).collect(),
+        (Value::Arr(arr), index) => index.parse::<usize>().ok().and_then(|i| arr.get(i)).map(|item| (index.to_string(), item)).into_iter().collect(),
+        (Value::Obj(obj), key) => obj.get(key).map(|child| (key.to_string(), child)).into_iter().collect(),
+        _ => vec![],
+    };
+    children
+        .into_iter()
+        .flat_map(|(segment, child)| {
+            values_at(child, rest).into_iter().map(move |(mut path, value)| {
+                path.insert(0, segment.clone());
+                (path, value)
+            })
+        })
+        .collect()
+}
+
+fn into_children(err: SchemaErr) -> BTreeMap<String, SchemaErr> {
+    match err {
+        SchemaErr::Validation(errs) => BTreeMap::from([(String::new(), SchemaErr::Validation(errs))]),
+        SchemaErr::Arr(items) => items.into_iter().enumerate().map(|(i, item)| (i.to_string(), item)).collect(),
+        SchemaErr::Obj(children) => children,
+    }
+}
+
+pub fn merge_schema_err(a: SchemaErr, b: SchemaErr) -> SchemaErr {
+    match (a, b) {
+        (SchemaErr::Validation(mut a), SchemaErr::Validation(b)) => {
+            a.extend(b);
+            SchemaErr::Validation(a)
+        }
+        (SchemaErr::Validation(a), b) if a.is_empty() => b,
+        (a, SchemaErr::Validation(b)) if b.is_empty() => a,
+        (SchemaErr::Arr(a), SchemaErr::Arr(b)) => {
+            let len = a.len().max(b.len());
+            let (mut a, mut b) = (a.into_iter(), b.into_iter());
+            SchemaErr::Arr(
+                (0..len)
+                    .map(|_| match (a.next(), b.next()) {
+                        (Some(a), Some(b)) => merge_schema_err(a, b),
+                        (Some(err), None) | (None, Some(err)) => err,
+                        (None, None) => SchemaErr::Validation(vec![]),
+                    })
+                    .collect(),
+            )
+        }
+        (SchemaErr::Obj(mut a), SchemaErr::Obj(b)) => {
+            for (key, err) in b {
+                let merged = match a.remove(&key) {
+                    Some(existing) => merge_schema_err(existing, err),
+                    None => err,
+                };
+                a.insert(key, merged);
+            }
+            SchemaErr::Obj(a)
+        }
+        (a, b) => merge_schema_err(SchemaErr::Obj(into_children(a)), SchemaErr::Obj(into_children(b))),
+    }
+}
+
+pub fn validate_rules(rules: &Rules, value: &Value, ctx: &ValidateCtx) -> Result<(), SchemaErr> {
+    let mut result: Option<SchemaErr> = None;
+    for (path, path_rules) in &rules.0 {
+        let segments: Vec<&str> = if path.is_empty() { vec![] } else { path.split('.').collect() };
+        for (concrete, path_value) in values_at(value, &segments) {
+            if path_value == &Value::None {
+                continue;
+            }
+            let errs: Vec<ValidationErr> = path_rules.iter().filter_map(|rule| validate_rule(rule, path_value, value, ctx).err()).collect();
+            if !errs.is_empty() {
+                let concrete: Vec<&str> = concrete.iter().map(String::as_str).collect();
+                let err = schema_err_at(&concrete, Some(value), errs);
+                result = Some(match result {
+                    Some(existing) => merge_schema_err(existing, err),
+                    None => err,
+                });
+            }
+        }
+    }
+    match result {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+fn replace_operation_errs(err: SchemaErr, operand_err: SchemaErr) -> SchemaErr {
+    match (err, operand_err) {
+        (SchemaErr::Validation(errs), SchemaErr::Validation(operand_errs)) => {
+            SchemaErr::Validation(errs.into_iter().filter(|err| !matches!(err, ValidationErr::Operation(_))).chain(operand_errs).collect())
+        }
+        (SchemaErr::Obj(mut a), SchemaErr::Obj(b)) => {
+            for (key, operand_err) in b {
+                let replaced = match a.remove(&key) {
+                    Some(existing) => replace_operation_errs(existing, operand_err),
+                    None => operand_err,
+                };
+                a.insert(key, replaced);
+            }
+            SchemaErr::Obj(a)
+        }
+        (err, operand_err) => merge_schema_err(err, operand_err),
+    }
+}
+
+fn validate_schema(schema: &Schema, value: &Value) -> Result<(), SchemaErr> {
+    match (validate(schema, value).map_err(to_schema_err), validate_operands(schema, value)) {
+        (Err(err), Err(operand_err)) => Err(replace_operation_errs(err, operand_err)),
+        (Ok(()), Err(operand_err)) => Err(operand_err),
+        (result, Ok(())) => result,
+    }
+}
+
+pub fn validate_with_rules(schema: &Schema, rules: &Rules, value: &Value, ctx: &ValidateCtx) -> Result<(), SchemaErr> {
+    [validate_schema(schema, value), validate_rules(rules, value, ctx)]
+        .into_iter()
+        .filter_map(Result::err)
+        .reduce(merge_schema_err)
+        .map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use araucaria::{
+        schema::{DateSchema, DateTimeSchema, ObjSchema, Schema, StrSchema, TimeSchema, U64Schema},
+        value::Value,
+    };
+    use chrono::DateTime;
+
+    use crate::{
+        rule::{CustomValidator, PatternRule, RelativeRule, Rule, Rules, TimeSpan, ValidatorRegistry},
+        serialize::{Operand, OperandValue, Operation, SchemaErr, ValidationErr},
+    };
+
+    use super::{FixedClock, ValidateCtx, merge_schema_err, validate_rule, validate_rules, validate_with_rules};
+
+    static SKU: LazyLock<Rule> = LazyLock::new(|| Rule::Pattern(PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap().description("ABC-1234")));
+    static CEP: LazyLock<Rule> = LazyLock::new(|| Rule::Pattern(PatternRule::new("[0-9]{5}-[0-9]{3}").unwrap()));
+    static SKU_ERR: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Pattern("[A-Z]{3}-[0-9]{4}".into(), Some("ABC-1234".into())));
+    static CEP_ERR: LazyLock<ValidationErr> = LazyLock::new(|| ValidationErr::Pattern("[0-9]{5}-[0-9]{3}".into(), None));
+
+    static RULES: LazyLock<Rules> = LazyLock::new(|| Rules::from([("sku".into(), SKU.clone()), ("address.cep".into(), CEP.clone())]));
+
+    static SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+        Schema::from(ObjSchema::from(BTreeMap::from([
+            ("sku".into(), Schema::from(StrSchema::default())),
+            ("address".into(), Schema::from(ObjSchema::from(BTreeMap::from([("cep".into(), Schema::from(StrSchema::default()))])))),
+        ])))
+    });
+
+    struct SkuExists(Vec<String>);
+
+    impl CustomValidator for SkuExists {
+        fn name(&self) -> &str {
+            "sku_exists"
+        }
+
+        fn message_key(&self) -> &str {
+            "sku_unknown"
+        }
+
+        fn validate(&self, value: &Value, _root: &Value) -> bool {
+            match value {
+                Value::Str(sku) => self.0.contains(sku),
+                _ => true,
+            }
+        }
+    }
+
+    struct AddressNumber;
+
+    impl CustomValidator for AddressNumber {
+        fn name(&self) -> &str {
+            "address_number"
+        }
+
+        fn message_key(&self) -> &str {
+            "address_number_missing"
+        }
+
+        fn validate(&self, value: &Value, _root: &Value) -> bool {
+            match value {
+                Value::Obj(address) => address.contains_key("number"),
+                _ => true,
+            }
+        }
+    }
+
+    fn value(sku: Value, cep: Value) -> Value {
+        Value::Obj(BTreeMap::from([("sku".into(), sku), ("address".into(), Value::Obj(BTreeMap::from([("cep".into(), cep)])))]))
+    }
+
+    #[test]
+    fn validate_rules_ok() {
+        assert_eq!(validate_rules(&RULES, &value(Value::from("ABC-1234"), Value::from("01310-100")), &ValidateCtx::new()), Ok(()));
+        assert_eq!(validate_rules(&RULES, &Value::Obj(BTreeMap::new()), &ValidateCtx::new()), Ok(()));
+        assert_eq!(validate_rules(&RULES, &value(Value::None, Value::None), &ValidateCtx::new()), Ok(()));
+        assert_eq!(validate_rules(&Rules::default(), &Value::from("anything"), &ValidateCtx::new()), Ok(()));
+    }
+
+    #[test]
+    fn validate_rules_err() {
+        assert_eq!(
+            validate_rules(&RULES, &value(Value::from("abc"), Value::from("01310100")), &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("sku".into(), SchemaErr::Validation(vec![SKU_ERR.clone()])),
+                ("address".into(), SchemaErr::Obj(BTreeMap::from([("cep".into(), SchemaErr::Validation(vec![CEP_ERR.clone()]))]))),
+            ])))
+        );
+        assert_eq!(
+            validate_rules(&Rules::from([("".into(), CEP.clone())]), &Value::from("0"), &ValidateCtx::new()),
+            Err(SchemaErr::Validation(vec![CEP_ERR.clone()]))
+        );
+    }
+
+    #[test]
+    fn validate_rule_non_str() {
+        let ctx = ValidateCtx::new();
+        let root = Value::None;
+        assert_eq!(validate_rule(&CEP, &Value::U64(1310100), &root, &ctx), Err(CEP_ERR.clone()));
+        assert_eq!(validate_rule(&Rule::Cep, &Value::U64(1310100), &root, &ctx), Err(ValidationErr::Cep));
+        assert_eq!(validate_rule(&Rule::Cpf, &Value::Bool(true), &root, &ctx), Err(ValidationErr::Cpf));
+        assert_eq!(validate_rule(&Rule::Hostname, &Value::Arr(vec![]), &root, &ctx), Err(ValidationErr::Hostname));
+        assert_eq!(validate_rule(&Rule::Cep, &Value::None, &root, &ctx), Ok(()));
+        assert_eq!(validate_rule(&Rule::Custom("sku_exists".into()), &Value::U64(1), &root, &ctx), Ok(()));
+        assert_eq!(validate_rule(&Rule::Relative(RelativeRule::Past), &Value::U64(1), &root, &ctx), Ok(()));
+    }
+
+    #[test]
+    fn validate_rules_arr_index() {
+        let rules = Rules::from([("skus.1".into(), SKU.clone())]);
+        let value = Value::Obj(BTreeMap::from([("skus".into(), Value::Arr(vec![Value::from("ABC-1234"), Value::from("abc")]))]));
+        assert_eq!(
+            validate_rules(&rules, &value, &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([(
+                "skus".into(),
+                SchemaErr::Arr(vec![SchemaErr::Validation(vec![]), SchemaErr::Validation(vec![SKU_ERR.clone()])])
+            )])))
+        );
+        let rules = Rules::from([("skus.0".into(), CEP.clone()), ("skus.2".into(), SKU.clone())]);
+        let value = Value::Obj(BTreeMap::from([("skus".into(), Value::Arr(vec![Value::from("0"), Value::from("ABC-1234"), Value::from("abc")]))]));
+        assert_eq!(
+            validate_rules(&rules, &value, &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([(
+                "skus".into(),
+                SchemaErr::Arr(vec![
+                    SchemaErr::Validation(vec![CEP_ERR.clone()]),
+                    SchemaErr::Validation(vec![]),
+                    SchemaErr::Validation(vec![SKU_ERR.clone()])
+                ])
+            )])))
+        );
+    }
+
+    #[test]
+    fn validate_rules_arr_wildcard() {
+        let rules = Rules::from([("items.*.sku".into(), SKU.clone())]);
+        let item = |sku: Value| Value::Obj(BTreeMap::from([("sku".into(), sku)]));
+        let value = Value::Obj(BTreeMap::from([(
+            "items".into(),
+            Value::Arr(vec![item(Value::from("abc")), item(Value::from("ABC-1234")), item(Value::None), item(Value::from("xyz"))]),
+        )]));
+        let sku_err = || SchemaErr::Obj(BTreeMap::from([("sku".into(), SchemaErr::Validation(vec![SKU_ERR.clone()]))]));
+        assert_eq!(
+            validate_rules(&rules, &value, &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([(
+                "items".into(),
+                SchemaErr::Arr(vec![sku_err(), SchemaErr::Validation(vec![]), SchemaErr::Validation(vec![]), sku_err()])
+            )])))
+        );
+        let value = Value::Obj(BTreeMap::from([("items".into(), Value::Arr(vec![item(Value::from("ABC-1234"))]))]));
+        assert_eq!(validate_rules(&rules, &value, &ValidateCtx::new()), Ok(()));
+        assert_eq!(validate_rules(&rules, &Value::Obj(BTreeMap::new()), &ValidateCtx::new()), Ok(()));
+    }
+
+    #[test]
+    fn validate_with_rules_merged() {
+        assert_eq!(validate_with_rules(&SCHEMA, &RULES, &value(Value::from("ABC-1234"), Value::from("01310-100")), &ValidateCtx::new()), Ok(()));
+        assert_eq!(
+            validate_with_rules(&SCHEMA, &RULES, &value(Value::U64(1), Value::from("01310100")), &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("sku".into(), SchemaErr::Validation(vec![ValidationErr::Str, SKU_ERR.clone()])),
+                ("address".into(), SchemaErr::Obj(BTreeMap::from([("cep".into(), SchemaErr::Validation(vec![CEP_ERR.clone()]))]))),
+            ])))
+        );
+        assert_eq!(
+            validate_with_rules(&SCHEMA, &RULES, &value(Value::from("abc"), Value::None), &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("sku".into(), SchemaErr::Validation(vec![SKU_ERR.clone()])),
+                (
+                    "address".into(),
+                    SchemaErr::Obj(BTreeMap::from([("cep".into(), SchemaErr::Validation(vec![ValidationErr::Required, ValidationErr::Str]))]))
+                ),
+            ])))
+        );
+    }
+
+    #[test]
+    fn validate_rules_clock() {
+        let clock = FixedClock(DateTime::parse_from_rfc3339("2026-10-18T12:00:00Z").unwrap());
+        let adult = RelativeRule::AtLeastAgo(TimeSpan::years(18));
+        let rules = Rules::from([("birthdate".into(), Rule::Relative(adult))]);
+        let ctx = ValidateCtx::new().clock(&clock);
+        assert_eq!(validate_rules(&rules, &Value::Obj(BTreeMap::from([("birthdate".into(), Value::from("2008-10-18"))])), &ctx), Ok(()));
+        assert_eq!(
+            validate_rules(&rules, &Value::Obj(BTreeMap::from([("birthdate".into(), Value::from("2008-10-19"))])), &ctx),
+            Err(SchemaErr::Obj(BTreeMap::from([("birthdate".into(), SchemaErr::Validation(vec![ValidationErr::Relative(adult)]))])))
+        );
+        let rules = Rules::from([("expiry".into(), Rule::CardExpiry)]);
+        let late = FixedClock(DateTime::parse_from_rfc3339("2026-10-31T22:00:00-03:00").unwrap());
+        let value = Value::Obj(BTreeMap::from([("expiry".into(), Value::from("10/26"))]));
+        assert_eq!(validate_rules(&rules, &value, &ValidateCtx::new().clock(&late)), Ok(()));
+        let next = FixedClock(DateTime::parse_from_rfc3339("2026-11-01T01:00:00Z").unwrap());
+        assert_eq!(
+            validate_rules(&rules, &value, &ValidateCtx::new().clock(&next)),
+            Err(SchemaErr::Obj(BTreeMap::from([("expiry".into(), SchemaErr::Validation(vec![ValidationErr::CardExpired]))])))
+        );
+    }
+
+    #[test]
+    fn validate_with_rules_invalid_operand() {
+        let schema = Schema::from(ObjSchema::from([
+            ("checkin".into(), Schema::from(StrSchema::default())),
+            ("checkout".into(), Schema::from(DateSchema::default().gt_field("checkin".into()))),
+        ]));
+        let value = Value::Obj(BTreeMap::from([("checkin".into(), Value::from("28/10/2026")), ("checkout".into(), Value::from("2026-10-30"))]));
+        assert_eq!(
+            validate_with_rules(&schema, &Rules::default(), &value, &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([(
+                "checkout".into(),
+                SchemaErr::Validation(vec![ValidationErr::DateOperand(Operand::FieldPath("checkin".into()))])
+            )])))
+        );
+        let schema = Schema::from(ObjSchema::from([
+            ("nights".into(), Schema::from(U64Schema::default())),
+            ("opens_at".into(), Schema::from(TimeSchema::default().btwn("08:00".into(), "8pm".into()))),
+            ("starts_at".into(), Schema::from(DateTimeSchema::default().gt_field("nights".into()))),
+            ("ends_at".into(), Schema::from(DateTimeSchema::default().lt("2026-12-31T00:00Z".into()))),
+        ]));
+        let value = Value::Obj(BTreeMap::from([("nights".into(), Value::U64(2)), ("opens_at".into(), Value::from("09:00"))]));
+        assert_eq!(
+            validate_with_rules(&schema, &Rules::default(), &value, &ValidateCtx::new()),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                (
+                    "ends_at".into(),
+                    SchemaErr::Validation(vec![
+                        ValidationErr::Required,
+                        ValidationErr::DateTime,
+                        ValidationErr::Operation(Operation::Lt(Operand::Value(OperandValue::Str("2026-12-31T00:00Z".into()))))
+                    ])
+                ),
+                ("opens_at".into(), SchemaErr::Validation(vec![ValidationErr::TimeOperand(Operand::Value(OperandValue::Str("8pm".into())))])),
+                (
+                    "starts_at".into(),
+                    SchemaErr::Validation(vec![
+                        ValidationErr::Required,
+                        ValidationErr::DateTime,
+                        ValidationErr::DateTimeOperand(Operand::FieldPath("nights".into()))
+                    ])
+                ),
+            ])))
+        );
+    }
+
+    #[test]
+    fn validate_with_rules_node_and_children() {
+        let registry = ValidatorRegistry::default().register(AddressNumber);
+        let ctx = ValidateCtx::new().registry(&registry);
+        let rules = Rules::from([("address".into(), Rule::Custom("address_number".into()))]);
+        assert_eq!(
+            validate_with_rules(&SCHEMA, &rules, &value(Value::from("ABC-1234"), Value::U64(1)), &ctx),
+            Err(SchemaErr::Obj(BTreeMap::from([(
+                "address".into(),
+                SchemaErr::Obj(BTreeMap::from([
+                    ("".into(), SchemaErr::Validation(vec![ValidationErr::Custom("address_number".into(), "address_number_missing".into())])),
+                    ("cep".into(), SchemaErr::Validation(vec![ValidationErr::Str])),
+                ]))
+            )])))
+        );
+    }
+
+    #[test]
+    fn merge_schema_err_shapes() {
+        assert_eq!(
+            merge_schema_err(SchemaErr::Validation(vec![ValidationErr::Str]), SchemaErr::Validation(vec![CEP_ERR.clone()])),
+            SchemaErr::Validation(vec![ValidationErr::Str, CEP_ERR.clone()])
+        );
+        assert_eq!(
+            merge_schema_err(
+                SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![ValidationErr::Str]))])),
+                SchemaErr::Obj(BTreeMap::from([
+                    ("a".into(), SchemaErr::Validation(vec![CEP_ERR.clone()])),
+                    ("b".into(), SchemaErr::Validation(vec![SKU_ERR.clone()]))
+                ]))
+            ),
+            SchemaErr::Obj(BTreeMap::from([
+                ("a".into(), SchemaErr::Validation(vec![ValidationErr::Str, CEP_ERR.clone()])),
+                ("b".into(), SchemaErr::Validation(vec![SKU_ERR.clone()])),
+            ]))
+        );
+        assert_eq!(
+            merge_schema_err(
+                SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![ValidationErr::Str]))])),
+                SchemaErr::Validation(vec![SKU_ERR.clone()])
+            ),
+            SchemaErr::Obj(BTreeMap::from([
+                ("".into(), SchemaErr::Validation(vec![SKU_ERR.clone()])),
+                ("a".into(), SchemaErr::Validation(vec![ValidationErr::Str])),
+            ]))
+        );
+        assert_eq!(
+            merge_schema_err(
+                SchemaErr::Validation(vec![ValidationErr::Str]),
+                SchemaErr::Arr(vec![SchemaErr::Validation(vec![]), SchemaErr::Validation(vec![CEP_ERR.clone()])])
+            ),
+            SchemaErr::Obj(BTreeMap::from([
+                ("".into(), SchemaErr::Validation(vec![ValidationErr::Str])),
+                ("0".into(), SchemaErr::Validation(vec![])),
+                ("1".into(), SchemaErr::Validation(vec![CEP_ERR.clone()])),
+            ]))
+        );
+        assert_eq!(
+            merge_schema_err(
+                SchemaErr::Validation(vec![]),
+                SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![ValidationErr::Str]))]))
+            ),
+            SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![ValidationErr::Str]))]))
+        );
+        assert_eq!(
+            merge_schema_err(
+                SchemaErr::Arr(vec![SchemaErr::Validation(vec![ValidationErr::Str]), SchemaErr::Validation(vec![])]),
+                SchemaErr::Arr(vec![
+                    SchemaErr::Validation(vec![CEP_ERR.clone()]),
+                    SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![SKU_ERR.clone()]))])),
+                    SchemaErr::Validation(vec![SKU_ERR.clone()])
+                ])
+            ),
+            SchemaErr::Arr(vec![
+                SchemaErr::Validation(vec![ValidationErr::Str, CEP_ERR.clone()]),
+                SchemaErr::Obj(BTreeMap::from([("a".into(), SchemaErr::Validation(vec![SKU_ERR.clone()]))])),
+                SchemaErr::Validation(vec![SKU_ERR.clone()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn validate_rules_custom() {
+        let registry = ValidatorRegistry::default().register(SkuExists(vec!["ABC-1234".into(), "XYZ-0001".into()]));
+        let ctx = ValidateCtx::new().registry(&registry);
+        let rules = RULES.clone().add("sku", Rule::Custom("sku_exists".into()));
+        let sku_unknown = ValidationErr::Custom("sku_exists".into(), "sku_unknown".into());
+        assert_eq!(validate_rules(&rules, &value(Value::from("XYZ-0001"), Value::None), &ctx), Ok(()));
+        assert_eq!(
+            validate_rules(&rules, &value(Value::from("XYZ-0002"), Value::None), &ctx),
+            Err(SchemaErr::Obj(BTreeMap::from([("sku".into(), SchemaErr::Validation(vec![sku_unknown.clone()]))])))
+        );
+        assert_eq!(
+            validate_rules(&rules, &value(Value::from("xyz"), Value::None), &ctx),
+            Err(SchemaErr::Obj(BTreeMap::from([("sku".into(), SchemaErr::Validation(vec![SKU_ERR.clone(), sku_unknown.clone()]))])))
+        );
+        assert_eq!(
+            validate_with_rules(&SCHEMA, &rules, &value(Value::from("XYZ-0002"), Value::from("0")), &ctx),
+            Err(SchemaErr::Obj(BTreeMap::from([
+                ("sku".into(), SchemaErr::Validation(vec![sku_unknown])),
+                ("address".into(), SchemaErr::Obj(BTreeMap::from([("cep".into(), SchemaErr::Validation(vec![CEP_ERR.clone()]))]))),
+            ])))
+        );
+    }
+}
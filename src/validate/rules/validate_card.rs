@@ -0,0 +1,66 @@
+use araucaria::value::Value;
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    rule::CardRule,
+    serialize::ValidationErr,
+    utils::card::{parse_card_expiry, parse_card_number},
+};
+
+pub fn validate_card_number(rule: &CardRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let (_, brand) = parse_card_number(str_value).map_err(|()| ValidationErr::CardNumber)?;
+    if !rule.brands.is_empty() && !rule.brands.contains(&brand) {
+        return Err(ValidationErr::CardBrand(rule.brands.clone()));
+    }
+    Ok(())
+}
+
+pub fn validate_card_expiry(value: &Value, today: NaiveDate) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let (year, month) = parse_card_expiry(str_value).map_err(|()| ValidationErr::CardExpiry)?;
+    if (year as i32, month) < (today.year(), today.month()) {
+        return Err(ValidationErr::CardExpired);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+    use chrono::NaiveDate;
+
+    use crate::{
+        rule::{CardBrand, CardRule},
+        serialize::ValidationErr,
+    };
+
+    use super::{validate_card_expiry, validate_card_number};
+
+    #[test]
+    fn validate_card_number_ok() {
+        assert_eq!(validate_card_number(&CardRule::default(), &Value::from("4111 1111 1111 1111")), Ok(()));
+        assert_eq!(validate_card_number(&CardRule::default().brands([CardBrand::Elo, CardBrand::Visa]), &Value::from("6362970000457013")), Ok(()));
+        assert_eq!(validate_card_number(&CardRule::default(), &Value::U64(4111111111111111)), Ok(()));
+    }
+
+    #[test]
+    fn validate_card_number_err() {
+        assert_eq!(validate_card_number(&CardRule::default(), &Value::from("4111 1111 1111 1112")), Err(ValidationErr::CardNumber));
+        assert_eq!(
+            validate_card_number(&CardRule::default().brands([CardBrand::Visa, CardBrand::Mastercard]), &Value::from("378282246310005")),
+            Err(ValidationErr::CardBrand(vec![CardBrand::Visa, CardBrand::Mastercard]))
+        );
+    }
+
+    #[test]
+    fn validate_card_expiry_today() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        assert_eq!(validate_card_expiry(&Value::from("10/26"), today), Ok(()));
+        assert_eq!(validate_card_expiry(&Value::from("01/2027"), today), Ok(()));
+        assert_eq!(validate_card_expiry(&Value::from("09/26"), today), Err(ValidationErr::CardExpired));
+        assert_eq!(validate_card_expiry(&Value::from("12/2025"), today), Err(ValidationErr::CardExpired));
+        assert_eq!(validate_card_expiry(&Value::from("13/26"), today), Err(ValidationErr::CardExpiry));
+        assert_eq!(validate_card_expiry(&Value::U64(1026), today), Ok(()));
+    }
+}
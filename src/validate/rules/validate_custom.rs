@@ -0,0 +1,57 @@
+use araucaria::value::Value;
+
+use crate::{rule::ValidatorRegistry, serialize::ValidationErr};
+
+pub fn validate_custom(name: &str, registry: Option<&ValidatorRegistry>, value: &Value, root: &Value) -> Result<(), ValidationErr> {
+    let Some(validator) = registry.and_then(|registry| registry.get(name)) else { return Ok(()) };
+    if validator.validate(value, root) { Ok(()) } else { Err(ValidationErr::Custom(name.into(), validator.message_key().into())) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::LazyLock};
+
+    use araucaria::value::Value;
+
+    use crate::{
+        rule::{CustomValidator, ValidatorRegistry},
+        serialize::ValidationErr,
+    };
+
+    use super::validate_custom;
+
+    struct GreaterThanMin;
+
+    impl CustomValidator for GreaterThanMin {
+        fn name(&self) -> &str {
+            "greater_than_min"
+        }
+
+        fn message_key(&self) -> &str {
+            "greater_than_min"
+        }
+
+        fn validate(&self, value: &Value, root: &Value) -> bool {
+            let Value::Obj(root) = root else { return false };
+            matches!((value, root.get("min")), (Value::U64(value), Some(Value::U64(min))) if value > min)
+        }
+    }
+
+    static REGISTRY: LazyLock<ValidatorRegistry> = LazyLock::new(|| ValidatorRegistry::default().register(GreaterThanMin));
+
+    #[test]
+    fn validate_custom_root() {
+        let root = Value::Obj(BTreeMap::from([("min".into(), Value::U64(10)), ("max".into(), Value::U64(20))]));
+        assert_eq!(validate_custom("greater_than_min", Some(&REGISTRY), &Value::U64(20), &root), Ok(()));
+        assert_eq!(
+            validate_custom("greater_than_min", Some(&REGISTRY), &Value::U64(5), &root),
+            Err(ValidationErr::Custom("greater_than_min".into(), "greater_than_min".into()))
+        );
+    }
+
+    #[test]
+    fn validate_custom_unregistered() {
+        assert_eq!(validate_custom("greater_than_min", None, &Value::U64(5), &Value::None), Ok(()));
+        assert_eq!(validate_custom("unknown", Some(&REGISTRY), &Value::U64(5), &Value::None), Ok(()));
+    }
+}
@@ -0,0 +1,31 @@
+use araucaria::value::Value;
+
+use crate::{serialize::ValidationErr, utils::hostname::hostname_is_valid};
+
+pub fn validate_hostname(value: &Value) -> Result<(), ValidationErr> {
+    match value {
+        Value::Str(str_value) if !hostname_is_valid(str_value) => Err(ValidationErr::Hostname),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::serialize::ValidationErr;
+
+    use super::validate_hostname;
+
+    #[test]
+    fn validate_hostname_ok() {
+        assert_eq!(validate_hostname(&Value::from("api.example.com")), Ok(()));
+        assert_eq!(validate_hostname(&Value::U64(1)), Ok(()));
+    }
+
+    #[test]
+    fn validate_hostname_err() {
+        assert_eq!(validate_hostname(&Value::from("api_example.com")), Err(ValidationErr::Hostname));
+        assert_eq!(validate_hostname(&Value::from("https://example.com")), Err(ValidationErr::Hostname));
+    }
+}
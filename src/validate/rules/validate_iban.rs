@@ -0,0 +1,40 @@
+use araucaria::value::Value;
+
+use crate::{
+    serialize::ValidationErr,
+    utils::iban::{IbanErr, normalize_iban},
+};
+
+pub fn validate_iban(value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    normalize_iban(str_value).map(|_| ()).map_err(|err| match err {
+        IbanErr::Format => ValidationErr::Iban,
+        IbanErr::Country => ValidationErr::IbanCountry,
+        IbanErr::Length(len) => ValidationErr::IbanLength(len),
+        IbanErr::Checksum => ValidationErr::IbanChecksum,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::serialize::ValidationErr;
+
+    use super::validate_iban;
+
+    #[test]
+    fn validate_iban_ok() {
+        assert_eq!(validate_iban(&Value::from("DE89 3704 0044 0532 0130 00")), Ok(()));
+        assert_eq!(validate_iban(&Value::from("br1800360305000010009795493c1")), Ok(()));
+        assert_eq!(validate_iban(&Value::Bool(false)), Ok(()));
+    }
+
+    #[test]
+    fn validate_iban_err() {
+        assert_eq!(validate_iban(&Value::from("DE89_3704_0044_0532_0130_00")), Err(ValidationErr::Iban));
+        assert_eq!(validate_iban(&Value::from("US64 SVBK US6S 3300 9673 8637")), Err(ValidationErr::IbanCountry));
+        assert_eq!(validate_iban(&Value::from("DE89 3704 0044 0532 0130")), Err(ValidationErr::IbanLength(22)));
+        assert_eq!(validate_iban(&Value::from("DE89 3704 0044 0532 0130 01")), Err(ValidationErr::IbanChecksum));
+    }
+}
@@ -0,0 +1,89 @@
+use araucaria::value::Value;
+
+use crate::{
+    rule::{NanoIdRule, UuidHyphens, UuidRule},
+    serialize::ValidationErr,
+    utils::{nano_id::nano_id_is_valid, ulid::normalize_ulid, uuid::parse_uuid},
+};
+
+pub fn validate_uuid(rule: &UuidRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let uuid = parse_uuid(str_value).map_err(|()| ValidationErr::Uuid)?;
+    match (rule.hyphens, uuid.hyphenated) {
+        (UuidHyphens::Required, false) | (UuidHyphens::Forbidden, true) => return Err(ValidationErr::Uuid),
+        _ => {}
+    }
+    if !rule.versions.is_empty() && !rule.versions.contains(&uuid.version()) {
+        return Err(ValidationErr::UuidVersion(rule.versions.clone()));
+    }
+    Ok(())
+}
+
+pub fn validate_ulid(value: &Value) -> Result<(), ValidationErr> {
+    match value {
+        Value::Str(str_value) if normalize_ulid(str_value).is_err() => Err(ValidationErr::Ulid),
+        _ => Ok(()),
+    }
+}
+
+pub fn validate_nano_id(rule: &NanoIdRule, value: &Value) -> Result<(), ValidationErr> {
+    match value {
+        Value::Str(str_value) if !nano_id_is_valid(str_value, &rule.alphabet, rule.len) => Err(ValidationErr::NanoId(rule.len)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::{
+        rule::{NanoIdRule, UuidHyphens, UuidRule},
+        serialize::ValidationErr,
+    };
+
+    use super::{validate_nano_id, validate_ulid, validate_uuid};
+
+    #[test]
+    fn validate_uuid_format() {
+        assert_eq!(validate_uuid(&UuidRule::default(), &Value::from("f47ac10b-58cc-4372-a567-0e02b2c3d479")), Ok(()));
+        assert_eq!(validate_uuid(&UuidRule::default(), &Value::from("f47ac10b58cc4372a5670e02b2c3d479")), Ok(()));
+        assert_eq!(validate_uuid(&UuidRule::default(), &Value::from("f47ac10b-58cc-4372-a567")), Err(ValidationErr::Uuid));
+        assert_eq!(validate_uuid(&UuidRule::default(), &Value::U64(1)), Ok(()));
+    }
+
+    #[test]
+    fn validate_uuid_hyphens() {
+        let required = UuidRule::default().hyphens(UuidHyphens::Required);
+        let forbidden = UuidRule::default().hyphens(UuidHyphens::Forbidden);
+        assert_eq!(validate_uuid(&required, &Value::from("f47ac10b-58cc-4372-a567-0e02b2c3d479")), Ok(()));
+        assert_eq!(validate_uuid(&required, &Value::from("f47ac10b58cc4372a5670e02b2c3d479")), Err(ValidationErr::Uuid));
+        assert_eq!(validate_uuid(&forbidden, &Value::from("f47ac10b58cc4372a5670e02b2c3d479")), Ok(()));
+        assert_eq!(validate_uuid(&forbidden, &Value::from("f47ac10b-58cc-4372-a567-0e02b2c3d479")), Err(ValidationErr::Uuid));
+    }
+
+    #[test]
+    fn validate_uuid_versions() {
+        let rule = UuidRule::default().versions([4, 7]);
+        assert_eq!(validate_uuid(&rule, &Value::from("f47ac10b-58cc-4372-a567-0e02b2c3d479")), Ok(()));
+        assert_eq!(validate_uuid(&rule, &Value::from("01890a5d-ac96-774b-bcce-b302099a8057")), Ok(()));
+        assert_eq!(validate_uuid(&rule, &Value::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8")), Err(ValidationErr::UuidVersion(vec![4, 7])));
+        assert_eq!(validate_uuid(&rule, &Value::from("00000000-0000-0000-0000-000000000000")), Err(ValidationErr::UuidVersion(vec![4, 7])));
+    }
+
+    #[test]
+    fn validate_ulid_format() {
+        assert_eq!(validate_ulid(&Value::from("01ARZ3NDEKTSV4RRFFQ69G5FAV")), Ok(()));
+        assert_eq!(validate_ulid(&Value::from("01arz3ndektsv4rrffq69g5fav")), Ok(()));
+        assert_eq!(validate_ulid(&Value::from("f47ac10b-58cc-4372-a567-0e02b2c3d479")), Err(ValidationErr::Ulid));
+    }
+
+    #[test]
+    fn validate_nano_id_format() {
+        assert_eq!(validate_nano_id(&NanoIdRule::default(), &Value::from("V1StGXR8_Z5jdHi6B-myT")), Ok(()));
+        assert_eq!(validate_nano_id(&NanoIdRule::default(), &Value::from("V1StGXR8_Z5jdHi6B")), Err(ValidationErr::NanoId(21)));
+        let hex = NanoIdRule::default().alphabet("0123456789abcdef").len(10);
+        assert_eq!(validate_nano_id(&hex, &Value::from("4f90d13a42")), Ok(()));
+        assert_eq!(validate_nano_id(&hex, &Value::from("4f90d13a4z")), Err(ValidationErr::NanoId(10)));
+    }
+}
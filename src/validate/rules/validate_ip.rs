@@ -0,0 +1,66 @@
+use araucaria::value::Value;
+
+use crate::{
+    rule::IpVersion,
+    serialize::ValidationErr,
+    utils::ip::{parse_cidr, parse_ipv4, parse_ipv6},
+};
+
+pub fn validate_ip(version: IpVersion, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let valid = match version {
+        IpVersion::V4 => parse_ipv4(str_value).is_ok(),
+        IpVersion::V6 => parse_ipv6(str_value).is_ok(),
+        IpVersion::Any => parse_ipv4(str_value).is_ok() || parse_ipv6(str_value).is_ok(),
+    };
+    if !valid {
+        return Err(ValidationErr::Ip(version));
+    }
+    Ok(())
+}
+
+pub fn validate_cidr(version: IpVersion, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let valid = match (version, parse_cidr(str_value)) {
+        (_, Err(())) => false,
+        (IpVersion::V4, Ok((addr, _))) => addr.is_ipv4(),
+        (IpVersion::V6, Ok((addr, _))) => addr.is_ipv6(),
+        (IpVersion::Any, Ok(_)) => true,
+    };
+    if !valid {
+        return Err(ValidationErr::Cidr(version));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::{rule::IpVersion, serialize::ValidationErr};
+
+    use super::{validate_cidr, validate_ip};
+
+    #[test]
+    fn validate_ip_versions() {
+        assert_eq!(validate_ip(IpVersion::V4, &Value::from("192.168.0.1")), Ok(()));
+        assert_eq!(validate_ip(IpVersion::V4, &Value::from("::1")), Err(ValidationErr::Ip(IpVersion::V4)));
+        assert_eq!(validate_ip(IpVersion::V6, &Value::from("::1")), Ok(()));
+        assert_eq!(validate_ip(IpVersion::V6, &Value::from("192.168.0.1")), Err(ValidationErr::Ip(IpVersion::V6)));
+        assert_eq!(validate_ip(IpVersion::Any, &Value::from("192.168.0.1")), Ok(()));
+        assert_eq!(validate_ip(IpVersion::Any, &Value::from("2001:db8::1")), Ok(()));
+        assert_eq!(validate_ip(IpVersion::Any, &Value::from("example.com")), Err(ValidationErr::Ip(IpVersion::Any)));
+        assert_eq!(validate_ip(IpVersion::Any, &Value::Bool(true)), Ok(()));
+    }
+
+    #[test]
+    fn validate_cidr_versions() {
+        assert_eq!(validate_cidr(IpVersion::V4, &Value::from("10.0.0.0/8")), Ok(()));
+        assert_eq!(validate_cidr(IpVersion::V4, &Value::from("2001:db8::/32")), Err(ValidationErr::Cidr(IpVersion::V4)));
+        assert_eq!(validate_cidr(IpVersion::V6, &Value::from("2001:db8::/32")), Ok(()));
+        assert_eq!(validate_cidr(IpVersion::V6, &Value::from("10.0.0.0/8")), Err(ValidationErr::Cidr(IpVersion::V6)));
+        assert_eq!(validate_cidr(IpVersion::Any, &Value::from("10.0.0.0/8")), Ok(()));
+        assert_eq!(validate_cidr(IpVersion::Any, &Value::from("10.0.0.0")), Err(ValidationErr::Cidr(IpVersion::Any)));
+        assert_eq!(validate_cidr(IpVersion::Any, &Value::from("10.0.0.0/33")), Err(ValidationErr::Cidr(IpVersion::Any)));
+    }
+}
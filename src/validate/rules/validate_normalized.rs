@@ -0,0 +1,48 @@
+use araucaria::value::Value;
+
+use crate::{rule::Rule, serialize::ValidationErr};
+
+pub fn validate_normalized(rule: &Rule, value: &Value, err: ValidationErr) -> Result<(), ValidationErr> {
+    match value {
+        Value::Str(str_value) if rule.normalize(str_value).is_none() => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::{
+        rule::{CountryFormat, Rule},
+        serialize::ValidationErr,
+    };
+
+    use super::validate_normalized;
+
+    #[test]
+    fn validate_normalized_ok() {
+        assert_eq!(validate_normalized(&Rule::Cpf, &Value::from("529.982.247-25"), ValidationErr::Cpf), Ok(()));
+        assert_eq!(validate_normalized(&Rule::Cnpj, &Value::from("12ABC34501DE35"), ValidationErr::Cnpj), Ok(()));
+        assert_eq!(validate_normalized(&Rule::Cep, &Value::from("01310100"), ValidationErr::Cep), Ok(()));
+        assert_eq!(validate_normalized(&Rule::Cep, &Value::U64(1310100), ValidationErr::Cep), Ok(()));
+        assert_eq!(
+            validate_normalized(&Rule::Country(CountryFormat::Alpha2), &Value::from("br"), ValidationErr::Country(CountryFormat::Alpha2)),
+            Ok(())
+        );
+        assert_eq!(validate_normalized(&Rule::Timezone, &Value::from("Europe/Lisbon"), ValidationErr::Timezone), Ok(()));
+    }
+
+    #[test]
+    fn validate_normalized_err() {
+        assert_eq!(validate_normalized(&Rule::Cpf, &Value::from("111.111.111-11"), ValidationErr::Cpf), Err(ValidationErr::Cpf));
+        assert_eq!(validate_normalized(&Rule::Cnpj, &Value::from("11.222.333/0001-80"), ValidationErr::Cnpj), Err(ValidationErr::Cnpj));
+        assert_eq!(validate_normalized(&Rule::Cep, &Value::from("01310 100"), ValidationErr::Cep), Err(ValidationErr::Cep));
+        assert_eq!(
+            validate_normalized(&Rule::Country(CountryFormat::Alpha3), &Value::from("BR"), ValidationErr::Country(CountryFormat::Alpha3)),
+            Err(ValidationErr::Country(CountryFormat::Alpha3))
+        );
+        assert_eq!(validate_normalized(&Rule::Currency, &Value::from("BTC"), ValidationErr::Currency), Err(ValidationErr::Currency));
+        assert_eq!(validate_normalized(&Rule::Language, &Value::from("pt_BR"), ValidationErr::Language), Err(ValidationErr::Language));
+    }
+}
@@ -0,0 +1,49 @@
+use araucaria::value::Value;
+
+use crate::{rule::PatternRule, serialize::ValidationErr};
+
+pub fn validate_pattern(rule: &PatternRule, value: &Value) -> Result<(), ValidationErr> {
+    match value {
+        Value::Str(str_value) if !rule.is_match(str_value) => Err(ValidationErr::Pattern(rule.pattern.clone(), rule.description.clone())),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::LazyLock;
+
+    use araucaria::value::Value;
+
+    use crate::{rule::PatternRule, serialize::ValidationErr};
+
+    use super::validate_pattern;
+
+    static SKU: LazyLock<PatternRule> = LazyLock::new(|| PatternRule::new("[A-Z]{3}-[0-9]{4}").unwrap().description("ABC-1234"));
+    static CEP: LazyLock<PatternRule> = LazyLock::new(|| PatternRule::new("[0-9]{5}-[0-9]{3}").unwrap());
+
+    #[test]
+    fn validate_pattern_ok() {
+        assert_eq!(validate_pattern(&SKU, &Value::from("ABC-1234")), Ok(()));
+        assert_eq!(validate_pattern(&CEP, &Value::from("01310-100")), Ok(()));
+    }
+
+    #[test]
+    fn validate_pattern_err() {
+        assert_eq!(
+            validate_pattern(&SKU, &Value::from("abc-1234")),
+            Err(ValidationErr::Pattern("[A-Z]{3}-[0-9]{4}".into(), Some("ABC-1234".into())))
+        );
+        assert_eq!(
+            validate_pattern(&SKU, &Value::from(" ABC-1234")),
+            Err(ValidationErr::Pattern("[A-Z]{3}-[0-9]{4}".into(), Some("ABC-1234".into())))
+        );
+        assert_eq!(validate_pattern(&CEP, &Value::from("01310100")), Err(ValidationErr::Pattern("[0-9]{5}-[0-9]{3}".into(), None)));
+    }
+
+    #[test]
+    fn validate_pattern_other_types() {
+        assert_eq!(validate_pattern(&CEP, &Value::None), Ok(()));
+        assert_eq!(validate_pattern(&CEP, &Value::U64(1310100)), Ok(()));
+    }
+}
@@ -0,0 +1,44 @@
+use araucaria::value::Value;
+
+use crate::{rule::PhoneRule, serialize::ValidationErr, utils::phone::parse_phone};
+
+pub fn validate_phone(rule: &PhoneRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let phone = parse_phone(str_value, rule.default_country.as_deref()).map_err(|()| ValidationErr::Phone)?;
+    if !rule.countries.is_empty() && !phone.countries.iter().any(|country| rule.countries.iter().any(|allowed| allowed == country)) {
+        return Err(ValidationErr::PhoneCountry(rule.countries.clone()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+
+    use crate::{rule::PhoneRule, serialize::ValidationErr};
+
+    use super::validate_phone;
+
+    #[test]
+    fn validate_phone_ok() {
+        assert_eq!(validate_phone(&PhoneRule::default(), &Value::from("+55 11 91234-5678")), Ok(()));
+        assert_eq!(validate_phone(&PhoneRule::default().default_country("BR"), &Value::from("(11) 91234-5678")), Ok(()));
+        assert_eq!(validate_phone(&PhoneRule::default().countries(["BR", "PT"]), &Value::from("+351 912 345 678")), Ok(()));
+        assert_eq!(validate_phone(&PhoneRule::default().countries(["CA"]), &Value::from("+1 415 555 2671")), Ok(()));
+        assert_eq!(validate_phone(&PhoneRule::default(), &Value::U64(5511912345678)), Ok(()));
+    }
+
+    #[test]
+    fn validate_phone_err() {
+        assert_eq!(validate_phone(&PhoneRule::default(), &Value::from("(11) 91234-5678")), Err(ValidationErr::Phone));
+        assert_eq!(validate_phone(&PhoneRule::default(), &Value::from("+55 11 9123")), Err(ValidationErr::Phone));
+        assert_eq!(
+            validate_phone(&PhoneRule::default().countries(["BR", "PT"]), &Value::from("+34 612 345 678")),
+            Err(ValidationErr::PhoneCountry(vec!["BR".into(), "PT".into()]))
+        );
+        assert_eq!(
+            validate_phone(&PhoneRule::default().countries(["PT"]).default_country("BR"), &Value::from("(11) 91234-5678")),
+            Err(ValidationErr::PhoneCountry(vec!["PT".into()]))
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use araucaria::value::Value;
+use chrono::{DateTime, FixedOffset, NaiveDateTime, NaiveTime};
+
+use crate::{
+    rule::{DateTimeRule, DurationRule, OffsetPolicy, Precision, RelativeRule, TimeRule},
+    serialize::ValidationErr,
+    utils::{
+        date::parse_date,
+        date_time::{DateTimeErr, parse_date_time_with, shift_date_time},
+        duration::{parse_duration, shift_by_duration},
+        interval::{IntervalErr, parse_interval},
+        time::{TimeErr, parse_time, parse_time_with},
+    },
+};
+
+pub fn validate_date_time(rule: &DateTimeRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    parse_date_time_with(str_value, rule.precision, rule.offset).map(|_| ()).map_err(|err| match err {
+        DateTimeErr::Format => ValidationErr::DateTime,
+        DateTimeErr::Precision => ValidationErr::Precision(rule.precision),
+        DateTimeErr::Offset => ValidationErr::DateTimeOffset(rule.offset),
+    })
+}
+
+pub fn validate_time(rule: &TimeRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    parse_time_with(str_value, rule.precision, rule.require_seconds).map(|_| ()).map_err(|err| match err {
+        TimeErr::Format => ValidationErr::Time,
+        TimeErr::Precision => ValidationErr::Precision(rule.precision),
+        TimeErr::Seconds => ValidationErr::TimeSeconds,
+    })
+}
+
+pub fn validate_relative(rule: &RelativeRule, value: &Value, now: DateTime<FixedOffset>) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let local = now.naive_local();
+    let (instant, now, by_date) = if let Ok(date) = parse_date(str_value) {
+        (date.0.and_time(NaiveTime::MIN), local, true)
+    } else if let Ok(date_time) = parse_date_time_with(str_value, Precision::Nanos, OffsetPolicy::Any) {
+        match date_time.offset {
+            Some(_) => (date_time.utc(), now.naive_utc(), false),
+            None => (date_time.local, local, false),
+        }
+    } else if let Ok(time) = parse_time(str_value) {
+        (local.date().and_time(time.time), local, false)
+    } else {
+        return Ok(());
+    };
+    let cmp = |boundary: NaiveDateTime| if by_date { instant.date().cmp(&boundary.date()) } else { instant.cmp(&boundary) };
+    let valid = match rule {
+        RelativeRule::Past => cmp(now).is_lt(),
+        RelativeRule::Future => cmp(now).is_gt(),
+        RelativeRule::AtLeastAgo(span) => shift_date_time(now, *span, false).is_some_and(|boundary| cmp(boundary).is_le()),
+        RelativeRule::WithinLast(span) => shift_date_time(now, *span, false).is_some_and(|boundary| cmp(boundary).is_ge()) && cmp(now).is_le(),
+        RelativeRule::WithinNext(span) => cmp(now).is_ge() && shift_date_time(now, *span, true).is_some_and(|boundary| cmp(boundary).is_le()),
+        RelativeRule::AtLeastAhead(span) => shift_date_time(now, *span, true).is_some_and(|boundary| cmp(boundary).is_ge()),
+    };
+    if valid { Ok(()) } else { Err(ValidationErr::Relative(*rule)) }
+}
+
+pub fn validate_duration(rule: &DurationRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let duration = parse_duration(str_value).map_err(|()| ValidationErr::Duration)?;
+    if let Some(min) = rule.min.filter(|min| duration.nominal_seconds() < min.nominal_seconds()) {
+        return Err(ValidationErr::DurationMin(min));
+    }
+    if let Some(max) = rule.max.filter(|max| duration.nominal_seconds() > max.nominal_seconds()) {
+        return Err(ValidationErr::DurationMax(max));
+    }
+    Ok(())
+}
+
+pub fn validate_interval(rule: &DurationRule, value: &Value) -> Result<(), ValidationErr> {
+    let Value::Str(str_value) = value else { return Ok(()) };
+    let interval = parse_interval(str_value).map_err(|err| match err {
+        IntervalErr::Format => ValidationErr::Interval,
+        IntervalErr::Order => ValidationErr::IntervalOrder,
+    })?;
+    if let Some(min) = rule.min.filter(|min| shift_by_duration(interval.start, min, true).is_none_or(|boundary| interval.end < boundary)) {
+        return Err(ValidationErr::IntervalMin(min));
+    }
+    if let Some(max) = rule.max.filter(|max| shift_by_duration(interval.start, max, true).is_some_and(|boundary| interval.end > boundary)) {
+        return Err(ValidationErr::IntervalMax(max));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use araucaria::value::Value;
+    use chrono::{DateTime, FixedOffset};
+
+    use crate::{
+        rule::{DateTimeRule, DurationRule, IsoDuration, OffsetPolicy, Precision, RelativeRule, TimeRule, TimeSpan},
+        serialize::ValidationErr,
+    };
+
+    use super::{validate_date_time, validate_duration, validate_interval, validate_relative, validate_time};
+
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-10-18T12:30:00Z").unwrap()
+    }
+
+    #[test]
+    fn validate_date_time_ok() {
+        assert_eq!(validate_date_time(&DateTimeRule::default(), &Value::from("2026-10-28T10:27:29.973-03:00")), Ok(()));
+        assert_eq!(validate_date_time(&DateTimeRule::default().precision(Precision::Seconds), &Value::from("2026-10-28T10:27:29Z")), Ok(()));
+        assert_eq!(validate_date_time(&DateTimeRule::default().offset(OffsetPolicy::Utc), &Value::from("2026-10-28T10:27Z")), Ok(()));
+        assert_eq!(validate_date_time(&DateTimeRule::default().offset(OffsetPolicy::Any), &Value::from("2026-10-28T10:27:29")), Ok(()));
+        assert_eq!(validate_date_time(&DateTimeRule::default(), &Value::Bool(false)), Ok(()));
+    }
+
+    #[test]
+    fn validate_date_time_err() {
+        assert_eq!(validate_date_time(&DateTimeRule::default(), &Value::from("2026-10-28 10:27:29Z")), Err(ValidationErr::DateTime));
+        assert_eq!(
+            validate_date_time(&DateTimeRule::default().precision(Precision::Minutes), &Value::from("2026-10-28T10:27:29Z")),
+            Err(ValidationErr::Precision(Precision::Minutes))
+        );
+        assert_eq!(
+            validate_date_time(&DateTimeRule::default().precision(Precision::Millis), &Value::from("2026-10-28T10:27:29.973123Z")),
+            Err(ValidationErr::Precision(Precision::Millis))
+        );
+        assert_eq!(
+            validate_date_time(&DateTimeRule::default().offset(OffsetPolicy::Utc), &Value::from("2026-10-28T10:27:29-03:00")),
+            Err(ValidationErr::DateTimeOffset(OffsetPolicy::Utc))
+        );
+        assert_eq!(
+            validate_date_time(&DateTimeRule::default(), &Value::from("2026-10-28T10:27:29")),
+            Err(ValidationErr::DateTimeOffset(OffsetPolicy::Required))
+        );
+    }
+
+    #[test]
+    fn validate_time_ok() {
+        assert_eq!(validate_time(&TimeRule::default(), &Value::from("08:30")), Ok(()));
+        assert_eq!(validate_time(&TimeRule::default(), &Value::from("08:30:00.250")), Ok(()));
+        assert_eq!(validate_time(&TimeRule::default().precision(Precision::Seconds), &Value::from("08:30:00")), Ok(()));
+        assert_eq!(validate_time(&TimeRule::default().require_seconds(), &Value::from("08:30:00")), Ok(()));
+        assert_eq!(validate_time(&TimeRule::default(), &Value::Bool(false)), Ok(()));
+    }
+
+    #[test]
+    fn validate_time_err() {
+        assert_eq!(validate_time(&TimeRule::default(), &Value::from("8h30")), Err(ValidationErr::Time));
+        assert_eq!(
+            validate_time(&TimeRule::default().precision(Precision::Minutes), &Value::from("08:30:00")),
+            Err(ValidationErr::Precision(Precision::Minutes))
+        );
+        assert_eq!(
+            validate_time(&TimeRule::default().precision(Precision::Seconds), &Value::from("08:30:00.250")),
+            Err(ValidationErr::Precision(Precision::Seconds))
+        );
+        assert_eq!(validate_time(&TimeRule::default().require_seconds(), &Value::from("08:30")), Err(ValidationErr::TimeSeconds));
+    }
+
+    #[test]
+    fn validate_relative_past_future() {
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-17"), now()), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-18"), now()), Err(ValidationErr::Relative(RelativeRule::Past)));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-18T12:29:59Z"), now()), Ok(()));
+        assert_eq!(
+            validate_relative(&RelativeRule::Past, &Value::from("2026-10-18T10:00-03:00"), now()),
+            Err(ValidationErr::Relative(RelativeRule::Past))
+        );
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("12:00"), now()), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("2026-10-19"), now()), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("12:00"), now()), Err(ValidationErr::Relative(RelativeRule::Future)));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("not a date"), now()), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::U64(1), now()), Ok(()));
+    }
+
+    #[test]
+    fn validate_relative_clock_offset() {
+        let now = DateTime::parse_from_rfc3339("2026-10-17T22:00:00-03:00").unwrap();
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-17"), now), Err(ValidationErr::Relative(RelativeRule::Past)));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-16"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-18T00:59:59Z"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("2026-10-18T01:00:01Z"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("21:00"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("23:00"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Past, &Value::from("2026-10-17T21:00:00"), now), Ok(()));
+        assert_eq!(validate_relative(&RelativeRule::Future, &Value::from("2026-10-17T23:00:00"), now), Ok(()));
+        assert_eq!(
+            validate_relative(&RelativeRule::Past, &Value::from("2026-10-17T23:00:00"), now),
+            Err(ValidationErr::Relative(RelativeRule::Past))
+        );
+    }
+
+    #[test]
+    fn validate_relative_spans() {
+        let adult = RelativeRule::AtLeastAgo(TimeSpan::years(18));
+        assert_eq!(validate_relative(&adult, &Value::from("2008-10-18"), now()), Ok(()));
+        assert_eq!(validate_relative(&adult, &Value::from("2008-10-19"), now()), Err(ValidationErr::Relative(adult)));
+        let recent = RelativeRule::WithinLast(TimeSpan::hours(24));
+        assert_eq!(validate_relative(&recent, &Value::from("2026-10-17T12:30:00Z"), now()), Ok(()));
+        assert_eq!(validate_relative(&recent, &Value::from("2026-10-17T12:29:59Z"), now()), Err(ValidationErr::Relative(recent)));
+        assert_eq!(validate_relative(&recent, &Value::from("2026-10-18T12:30:01Z"), now()), Err(ValidationErr::Relative(recent)));
+        let booking = RelativeRule::WithinNext(TimeSpan::days(90));
+        assert_eq!(validate_relative(&booking, &Value::from("2026-10-18"), now()), Ok(()));
+        assert_eq!(validate_relative(&booking, &Value::from("2027-01-16"), now()), Ok(()));
+        assert_eq!(validate_relative(&booking, &Value::from("2027-01-17"), now()), Err(ValidationErr::Relative(booking)));
+        assert_eq!(validate_relative(&booking, &Value::from("2026-10-17"), now()), Err(ValidationErr::Relative(booking)));
+        let notice = RelativeRule::AtLeastAhead(TimeSpan::weeks(1));
+        assert_eq!(validate_relative(&notice, &Value::from("2026-10-25"), now()), Ok(()));
+        assert_eq!(validate_relative(&notice, &Value::from("2026-10-24"), now()), Err(ValidationErr::Relative(notice)));
+    }
+
+    fn duration(s: &str) -> IsoDuration {
+        IsoDuration::try_from(s).unwrap()
+    }
+
+    #[test]
+    fn validate_duration_bounds() {
+        let rule = DurationRule::default().min(duration("PT5M")).max(duration("P7D"));
+        assert_eq!(validate_duration(&rule, &Value::from("PT5M")), Ok(()));
+        assert_eq!(validate_duration(&rule, &Value::from("P1DT12H")), Ok(()));
+        assert_eq!(validate_duration(&rule, &Value::from("P1W")), Ok(()));
+        assert_eq!(validate_duration(&rule, &Value::from("PT4M59S")), Err(ValidationErr::DurationMin(duration("PT5M"))));
+        assert_eq!(validate_duration(&rule, &Value::from("P7DT1S")), Err(ValidationErr::DurationMax(duration("P7D"))));
+        assert_eq!(validate_duration(&rule, &Value::from("P1M")), Err(ValidationErr::DurationMax(duration("P7D"))));
+        assert_eq!(validate_duration(&DurationRule::default(), &Value::from("P1Y2M")), Ok(()));
+        let month = DurationRule::default().min(duration("P31D"));
+        assert_eq!(validate_duration(&month, &Value::from("P1M")), Err(ValidationErr::DurationMin(duration("P31D"))));
+        assert_eq!(validate_duration(&month, &Value::from("P1M1D")), Ok(()));
+        assert_eq!(validate_duration(&DurationRule::default(), &Value::from("1 hour")), Err(ValidationErr::Duration));
+        assert_eq!(validate_duration(&DurationRule::default(), &Value::U64(1)), Ok(()));
+    }
+
+    #[test]
+    fn validate_interval_bounds() {
+        let rule = DurationRule::default().min(duration("P1D")).max(duration("P3M"));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01/2026-03-31")), Ok(()));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01/2026-04-01")), Ok(()));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01/P1D")), Ok(()));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01/2026-04-02")), Err(ValidationErr::IntervalMax(duration("P3M"))));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01/PT23H")), Err(ValidationErr::IntervalMin(duration("P1D"))));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-03-31/2026-01-01")), Err(ValidationErr::IntervalOrder));
+        assert_eq!(validate_interval(&rule, &Value::from("2026-01-01")), Err(ValidationErr::Interval));
+        assert_eq!(validate_interval(&rule, &Value::Bool(true)), Ok(()));
+    }
+}